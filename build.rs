@@ -157,11 +157,16 @@ fn generate_species_data(
         f,
         "pub fn get_compiled_species_data() -> &'static [Option<schema::PokemonSpecies>] {{"
     )?;
-    writeln!(f, "    let bytes = include_bytes!(concat!(env!(\"OUT_DIR\"), \"/compiled_species.postcard\"));")?;
-    // This pattern deserializes into an owned Box, then "leaks" it to get a 'static reference.
-    // This is the standard, safe way to initialize static data from bytes.
-    writeln!(f, "    let boxed_slice: Box<[Option<schema::PokemonSpecies>]> = postcard::from_bytes(bytes).expect(\"Failed to deserialize species data\");")?;
-    writeln!(f, "    Box::leak(boxed_slice)")?;
+    // Deserialize once into a static, so every later call hands out the same slice
+    // instead of decoding the whole table again.
+    writeln!(f, "    static SPECIES: std::sync::OnceLock<Box<[Option<schema::PokemonSpecies>]>> = std::sync::OnceLock::new();")?;
+    writeln!(f, "    SPECIES.get_or_init(|| {{")?;
+    writeln!(f, "        let bytes = include_bytes!(concat!(env!(\"OUT_DIR\"), \"/compiled_species.postcard\"));")?;
+    writeln!(
+        f,
+        "        postcard::from_bytes(bytes).expect(\"Failed to deserialize species data\")"
+    )?;
+    writeln!(f, "    }})")?;
     writeln!(f, "}}")?;
     writeln!(f)?;
     Ok(())
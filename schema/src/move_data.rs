@@ -1,4 +1,4 @@
-use crate::{MoveCategory, PokemonType, StatType, StatusType, Target, TeamCondition};
+use crate::{MoveCategory, MoveRange, PokemonType, StatType, StatusType, Target, TeamCondition};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -10,6 +10,114 @@ pub struct MoveData {
     pub accuracy: Option<u8>, // None for sure-hit moves
     pub max_pp: u8,
     pub effects: Vec<MoveEffect>,
+    #[serde(default)]
+    pub thaws_target: bool, // Clears Freeze on the target when it hits
+    #[serde(default)]
+    pub thaws_user: bool, // Lets a frozen user thaw out and act
+    #[serde(default)]
+    pub makes_contact: bool, // The user physically touches the target
+    #[serde(default)]
+    pub is_sound: bool, // Sound-based, e.g. Growl or Sing
+    #[serde(default)]
+    pub is_punch: bool, // A punching move, e.g. Fire Punch
+    #[serde(default)]
+    pub range: MoveRange, // Who it hits in a double battle
+}
+
+impl MoveData {
+    /// Base power of the move, or None for moves that don't deal direct damage.
+    pub fn base_power(&self) -> Option<u16> {
+        self.power.map(u16::from)
+    }
+
+    /// Accuracy of the move, or None for sure-hit moves.
+    pub fn accuracy(&self) -> Option<u8> {
+        self.accuracy
+    }
+
+    /// Priority modifier from the move's effects (0 if it has none).
+    pub fn priority(&self) -> i8 {
+        self.effects
+            .iter()
+            .find_map(|effect| match effect {
+                MoveEffect::Priority(p) => Some(*p),
+                _ => None,
+            })
+            .unwrap_or(0)
+    }
+
+    /// Whether the move strikes more than once per use.
+    pub fn is_multi_hit(&self) -> bool {
+        self.effects
+            .iter()
+            .any(|effect| matches!(effect, MoveEffect::MultiHit(_, _)))
+    }
+
+    /// Whether a hit from this move thaws a frozen target. Damaging Fire moves
+    /// always do; other moves need the `thaws_target` flag.
+    pub fn thaws_frozen_target(&self) -> bool {
+        self.thaws_target || (self.move_type == PokemonType::Fire && self.power.is_some())
+    }
+
+    /// Whether the user makes physical contact with the target.
+    /// Unused by Gen 1 rules, but available to mods and later mechanics.
+    pub fn makes_contact(&self) -> bool {
+        self.makes_contact
+    }
+
+    /// Whether the move is sound-based.
+    pub fn is_sound(&self) -> bool {
+        self.is_sound
+    }
+
+    /// Whether the move is a punch.
+    pub fn is_punch(&self) -> bool {
+        self.is_punch
+    }
+
+    /// Whether the move hits more than one Pokémon in a double battle.
+    pub fn is_spread(&self) -> bool {
+        self.range != MoveRange::Single
+    }
+
+    /// Whether Mirror Move can copy this move. Moves that depend on the user's own
+    /// multi-turn or reactive state (charging, semi-invulnerable turns, Bide, Counter)
+    /// and Mirror Move itself can't be mirrored.
+    pub fn mirrorable(&self) -> bool {
+        !self.effects.iter().any(|effect| {
+            matches!(
+                effect,
+                MoveEffect::MirrorMove
+                    | MoveEffect::Counter
+                    | MoveEffect::Bide(_)
+                    | MoveEffect::ChargeUp
+                    | MoveEffect::InAir
+                    | MoveEffect::Underground
+            )
+        })
+    }
+
+    /// The major status this move can inflict on the target, if any.
+    pub fn inflicts_status(&self) -> Option<StatusType> {
+        self.effects.iter().find_map(|effect| match effect {
+            MoveEffect::Sedate(_) => Some(StatusType::Sleep),
+            MoveEffect::Poison(_) => Some(StatusType::Poison),
+            MoveEffect::Burn(_) => Some(StatusType::Burn),
+            MoveEffect::Freeze(_) => Some(StatusType::Freeze),
+            MoveEffect::Paralyze(_) => Some(StatusType::Paralysis),
+            _ => None,
+        })
+    }
+
+    /// Every effect of the move paired with its activation chance, for display.
+    /// The chance is `Some(100)` for guaranteed effects that carry one (e.g. Swords Dance's
+    /// Attack boost) and `None` for effects that don't roll at all (e.g. priority).
+    pub fn secondary_effects_summary(&self) -> Vec<(MoveEffect, Option<u8>)> {
+        self.effects
+            .iter()
+            .map(|effect| (effect.clone(), effect.chance()))
+            .collect()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -55,6 +163,7 @@ pub enum MoveEffect {
     Transform,    // copy target's appearance/stats
     Conversion,   // change user's type
     Disable(u8),  // disable target's last move, chance %
+    Cooldown(u8), // user can't reuse this move for X turns
     Counter,      // return double physical damage
     MirrorMove,   // copy target's last move
     Metronome,    // random move
@@ -76,6 +185,35 @@ pub enum MoveEffect {
     Ante(u8), // percent chance to gain money equal to 2x level (Pay Day effect)
 }
 
+impl MoveEffect {
+    /// The percent chance this effect triggers, for effects that carry one.
+    pub fn chance(&self) -> Option<u8> {
+        match self {
+            MoveEffect::Flinch(chance)
+            | MoveEffect::Burn(chance)
+            | MoveEffect::Freeze(chance)
+            | MoveEffect::Paralyze(chance)
+            | MoveEffect::Poison(chance)
+            | MoveEffect::Sedate(chance)
+            | MoveEffect::Confuse(chance)
+            | MoveEffect::StatChange(_, _, _, chance)
+            | MoveEffect::RaiseAllStats(chance)
+            | MoveEffect::IgnoreDef(chance)
+            | MoveEffect::SuperFang(chance)
+            | MoveEffect::Trap(chance)
+            | MoveEffect::Exhaust(chance)
+            | MoveEffect::Teleport(chance)
+            | MoveEffect::Reckless(chance)
+            | MoveEffect::Disable(chance)
+            | MoveEffect::Rage(chance)
+            | MoveEffect::Haze(chance)
+            | MoveEffect::Seed(chance)
+            | MoveEffect::Ante(chance) => Some(*chance),
+            _ => None,
+        }
+    }
+}
+
 impl fmt::Display for MoveEffect {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -122,6 +260,10 @@ impl fmt::Display for MoveEffect {
                 "Has a {}% chance to disable the target's last move.",
                 chance
             ),
+            MoveEffect::Cooldown(1) => write!(f, "Can't be used again on the next turn."),
+            MoveEffect::Cooldown(turns) => {
+                write!(f, "Can't be used again for the next {} turns.", turns)
+            }
 
             // --- STAT CHANGES ---
             MoveEffect::StatChange(target, stat, stages, chance) => {
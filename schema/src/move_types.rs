@@ -62,6 +62,29 @@ impl fmt::Display for Target {
     }
 }
 
+/// Which Pokémon a move hits when more than one is on the field, as in a double battle.
+/// In a single battle every range comes down to the one opponent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum MoveRange {
+    /// One chosen Pokémon.
+    #[default]
+    Single,
+    /// Both opposing Pokémon.
+    AllFoes,
+    /// Every other Pokémon on the field, the user's ally included.
+    AllOthers,
+}
+
+impl fmt::Display for MoveRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MoveRange::Single => write!(f, "Single"),
+            MoveRange::AllFoes => write!(f, "All Foes"),
+            MoveRange::AllOthers => write!(f, "All Others"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum StatusType {
     Sleep,
@@ -28,6 +28,25 @@ impl fmt::Display for PokemonType {
 }
 
 impl PokemonType {
+    /// Every type a Pokemon can have. `Typeless` is excluded since it only exists on moves.
+    pub const ALL: [PokemonType; 15] = [
+        PokemonType::Normal,
+        PokemonType::Fighting,
+        PokemonType::Flying,
+        PokemonType::Poison,
+        PokemonType::Ground,
+        PokemonType::Rock,
+        PokemonType::Bug,
+        PokemonType::Ghost,
+        PokemonType::Fire,
+        PokemonType::Water,
+        PokemonType::Grass,
+        PokemonType::Electric,
+        PokemonType::Psychic,
+        PokemonType::Ice,
+        PokemonType::Dragon,
+    ];
+
     /// Calculate type effectiveness multiplier for attacking type vs defending type
     /// Returns: 2.0 = Super Effective, 1.0 = Normal, 0.5 = Not Very Effective, 0.0 = No Effect
     pub fn type_effectiveness(attacking: PokemonType, defending: PokemonType) -> f32 {
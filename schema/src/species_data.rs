@@ -2,7 +2,7 @@ use crate::{Move, PokemonType, Species};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Item {
     // Evolution stones
     FireStone,
@@ -10,9 +10,22 @@ pub enum Item {
     ThunderStone,
     LeafStone,
     MoonStone,
+    // Held items
+    OranBerry,
     // Add more items as needed
 }
 
+impl Item {
+    /// HP restored when this item is held and its holder drops to half HP or less.
+    /// None for items that don't trigger that way.
+    pub fn held_heal_amount(self) -> Option<u16> {
+        match self {
+            Item::OranBerry => Some(10),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BaseStats {
     pub hp: u8,
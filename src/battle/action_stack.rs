@@ -1,10 +1,10 @@
 use std::collections::VecDeque;
 
-use schema::MoveEffect;
 use serde::{Deserialize, Serialize};
 
-use crate::battle::state::BattleState;
-use crate::player::PlayerAction;
+use crate::battle::commands::PlayerTarget;
+use crate::battle::state::{BattleState, TurnRng};
+use crate::player::{Battler, PlayerAction};
 use crate::{battle::stats::effective_speed, move_data::get_move_data, Move};
 /// Internal action types for the action stack
 /// These represent atomic actions that can be executed during battle resolution
@@ -24,8 +24,8 @@ pub enum BattleAction {
 
     /// Execute a single hit of a move (for multi-hit moves, multiple actions are pushed)
     AttackHit {
-        attacker_index: usize,
-        defender_index: usize,
+        attacker: PlayerTarget,
+        defender: PlayerTarget,
         move_used: Move,
         hit_number: u8, // 0 for single hit, 0,1,2... for multi-hit
     },
@@ -36,7 +36,7 @@ pub struct ActionStack {
 }
 
 // A helper struct local to this implementation detail.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 struct ActionPriority {
     action_priority: i8, // Forfeit: 10, Switch: 6, Move: 0
     move_priority: i8,   // Priority from move data (e.g., Quick Attack)
@@ -54,22 +54,16 @@ impl ActionStack {
     /// Builds the initial action stack for a turn based on the queued actions in the BattleState.
     /// This is the primary "smart constructor" for creating an ordered list of turn actions.
     /// It consumes the state of the `action_queue` and produces a ready-to-execute stack.
-    pub fn build_initial(battle_state: &BattleState) -> Self {
+    /// The RNG is only consulted to break exact ties in priority and speed.
+    pub fn build_initial(battle_state: &BattleState, rng: &mut TurnRng) -> Self {
         // 1. Collect the actions that have been submitted into the queue.
         // It's assumed the queue has been pre-filled by player input, AI, and/or
         // the "End-of-Turn Injection" of forced moves.
-        let actions_to_prioritize: Vec<(usize, PlayerAction)> = battle_state
-            .action_queue
-            .iter()
-            .enumerate()
-            .filter_map(|(index, action_opt)| {
-                action_opt.as_ref().map(|action| (index, action.clone()))
-            })
-            .collect();
+        let actions_to_prioritize = Self::queued_actions(battle_state);
 
         // 2. Determine the execution order based on game rules (priority, speed).
         // We call our own private helper function for this, keeping the logic encapsulated.
-        let action_order = Self::determine_action_order(battle_state, &actions_to_prioritize);
+        let action_order = Self::determine_action_order(battle_state, &actions_to_prioritize, rng);
 
         // 3. Convert the sorted PlayerActions into executable BattleActions and build the stack.
         let mut new_stack = Self::new();
@@ -101,23 +95,88 @@ impl ActionStack {
         self.actions.pop_front()
     }
 
+    /// Predict the order the queued actions will run in, without consuming any RNG.
+    /// Returns the order (an exact tie is listed player 0 first) and whether such a tie
+    /// means the real order will be decided by a roll.
+    pub fn predicted_action_order(
+        battle_state: &BattleState,
+    ) -> (Vec<(usize, PlayerAction)>, bool) {
+        let actions = Self::queued_actions(battle_state);
+        let player_priorities = Self::sort_by_priority(battle_state, &actions);
+        let rng_decided = Self::is_random_tie(&player_priorities);
+        let order = player_priorities
+            .into_iter()
+            .map(|(player_index, action, _)| (player_index, action))
+            .collect();
+        (order, rng_decided)
+    }
+
+    /// The ordering key of one action: action priority, then move priority, then speed,
+    /// each higher-goes-first. Lets formats with more than two actions sort them the same way.
+    pub(crate) fn priority_key(
+        actor: PlayerTarget,
+        action: &PlayerAction,
+        battle_state: &BattleState,
+    ) -> (i8, i8, u16) {
+        let priority = Self::calculate_action_priority(actor, action, battle_state);
+        (
+            priority.action_priority,
+            priority.move_priority,
+            priority.speed,
+        )
+    }
+
     // --- Private Helper Functions ---
     // These functions are implementation details of `build_initial`.
 
     /// A private helper that sorts a list of player actions based on priority and speed.
+    /// Exact ties are resolved randomly, as in Gen 1.
     fn determine_action_order<'a>(
         battle_state: &'a BattleState,
         actions: &'a [(usize, PlayerAction)],
+        rng: &mut TurnRng,
     ) -> Vec<(usize, PlayerAction)> {
+        let mut player_priorities = Self::sort_by_priority(battle_state, actions);
+
+        // A full tie would otherwise fall back to insertion order (player 0 first).
+        // Roll for it instead so the result is fair but still reproducible under a seed.
+        if Self::is_random_tie(&player_priorities) && rng.next_outcome("Speed Tie") > 50 {
+            player_priorities.swap(0, 1);
+        }
+
+        // Return just the sorted (player_index, PlayerAction) tuples.
+        player_priorities
+            .into_iter()
+            .map(|(player_index, action, _)| (player_index, action))
+            .collect()
+    }
+
+    /// The actions currently submitted in the queue, tagged with their player index.
+    fn queued_actions(battle_state: &BattleState) -> Vec<(usize, PlayerAction)> {
+        battle_state
+            .action_queue
+            .iter()
+            .enumerate()
+            .filter_map(|(index, action_opt)| {
+                action_opt.as_ref().map(|action| (index, action.clone()))
+            })
+            .collect()
+    }
+
+    /// Sort actions by action priority (highest first), then move priority, then speed.
+    fn sort_by_priority(
+        battle_state: &BattleState,
+        actions: &[(usize, PlayerAction)],
+    ) -> Vec<(usize, PlayerAction, ActionPriority)> {
         let mut player_priorities = Vec::new();
 
         // Calculate priority for each player's action.
         for (player_index, action) in actions {
-            let priority = Self::calculate_action_priority(*player_index, action, battle_state);
+            let actor = PlayerTarget::from_index(*player_index);
+            let priority = Self::calculate_action_priority(actor, action, battle_state);
             player_priorities.push((*player_index, action.clone(), priority));
         }
 
-        // Sort by action priority (highest first), then move priority, then speed.
         player_priorities.sort_by(|a, b| {
             let priority_cmp = b.2.action_priority.cmp(&a.2.action_priority);
             if priority_cmp != std::cmp::Ordering::Equal {
@@ -132,16 +191,27 @@ impl ActionStack {
             b.2.speed.cmp(&a.2.speed)
         });
 
-        // Return just the sorted (player_index, PlayerAction) tuples.
         player_priorities
-            .into_iter()
-            .map(|(player_index, action, _)| (player_index, action))
-            .collect()
+    }
+
+    /// Whether two sorted actions are exactly tied, so a roll decides who goes first.
+    /// A double forfeit is a draw either way, so it doesn't need a roll.
+    fn is_random_tie(player_priorities: &[(usize, PlayerAction, ActionPriority)]) -> bool {
+        match player_priorities {
+            [first, second] => {
+                let both_forfeit = matches!(
+                    (&first.1, &second.1),
+                    (PlayerAction::Forfeit, PlayerAction::Forfeit)
+                );
+                !both_forfeit && first.2 == second.2
+            }
+            _ => false,
+        }
     }
 
     /// A private helper to calculate the priority values for a single action.
     fn calculate_action_priority(
-        player_index: usize,
+        actor: PlayerTarget,
         action: &PlayerAction,
         battle_state: &BattleState,
     ) -> ActionPriority {
@@ -150,7 +220,7 @@ impl ActionStack {
                 ActionPriority {
                     action_priority: 6,
                     move_priority: 0,
-                    speed: player_index as u16, // Stable sort for dual switches
+                    speed: actor.to_index() as u16, // Stable sort for dual switches
                 }
             }
             PlayerAction::Forfeit => {
@@ -161,7 +231,7 @@ impl ActionStack {
                 }
             }
             PlayerAction::Catch => {
-                let player = &battle_state.players[player_index];
+                let player = battle_state.battler(actor);
                 let speed = if let Some(active_pokemon) = player.active_pokemon() {
                     effective_speed(active_pokemon, player)
                 } else {
@@ -175,35 +245,38 @@ impl ActionStack {
                 }
             }
             PlayerAction::UseMove { move_index } => {
-                let player = &battle_state.players[player_index];
+                let player = battle_state.battler(actor);
                 let active_pokemon = player.active_pokemon().expect("Active pokemon must exist");
 
-                let move_instance = active_pokemon.moves[*move_index]
-                    .as_ref()
-                    .expect("Move must exist in queue");
-
-                let move_data = get_move_data(move_instance.move_).expect("Move data must exist");
+                let move_used = Self::resolve_chosen_move(player, *move_index);
+                let move_data = get_move_data(move_used).expect("Move data must exist");
 
                 let speed = effective_speed(active_pokemon, player);
 
-                let move_priority = move_data
-                    .effects
-                    .iter()
-                    .find_map(|effect| match effect {
-                        MoveEffect::Priority(p) => Some(*p),
-                        _ => None,
-                    })
-                    .unwrap_or(0);
-
                 ActionPriority {
                     action_priority: 0, // Moves are lowest action priority
-                    move_priority,
+                    move_priority: move_data.priority(),
                     speed,
                 }
             }
         }
     }
 
+    /// Resolve the move a `UseMove` choice will actually execute. This is the single place
+    /// where out-of-PP choices become Struggle, so human and NPC actions are treated alike.
+    pub(crate) fn resolve_chosen_move(player: Battler, move_index: usize) -> Move {
+        if player.must_struggle() {
+            return Move::Struggle;
+        }
+        player
+            .active_pokemon()
+            .and_then(|pokemon| pokemon.moves.get(move_index))
+            .and_then(|slot| slot.as_ref())
+            .filter(|inst| inst.pp > 0)
+            .map(|inst| inst.move_)
+            .unwrap_or(Move::Struggle)
+    }
+
     /// A private helper to convert a PlayerAction into an executable BattleAction.
     fn convert_player_action_to_battle_action(
         player_action: &PlayerAction,
@@ -218,26 +291,13 @@ impl ActionStack {
             },
             PlayerAction::Catch => BattleAction::CatchAttempt { player_index },
             PlayerAction::UseMove { move_index } => {
-                let player = &battle_state.players[player_index];
-                let active_pokemon = player
-                    .active_pokemon()
-                    .expect("Active pokemon should exist");
-
-                // Determine if the move should become Struggle due to 0 PP.
-                let final_move = active_pokemon.moves[*move_index]
-                    .as_ref()
-                    .map(|inst| {
-                        if inst.pp > 0 {
-                            inst.move_
-                        } else {
-                            Move::Struggle
-                        }
-                    })
-                    .unwrap_or(Move::Struggle);
+                let attacker = PlayerTarget::from_index(player_index);
+                let final_move =
+                    Self::resolve_chosen_move(battle_state.battler(attacker), *move_index);
 
                 BattleAction::AttackHit {
-                    attacker_index: player_index,
-                    defender_index: 1 - player_index,
+                    attacker,
+                    defender: attacker.opponent(),
                     move_used: final_move,
                     hit_number: 0,
                 }
@@ -1,11 +1,16 @@
 //! A module for defining AI behaviors for battle opponents.
 
-use schema::{MoveCategory, MoveEffect, Target};
+use std::collections::HashMap;
 
-use crate::battle::state::BattleState;
+use schema::{Move, MoveCategory, MoveEffect, PokemonType, Target};
+use serde::{Deserialize, Serialize};
+
+use crate::battle::commands::PlayerTarget;
+use crate::battle::state::{BattleEvent, BattleState, TurnRng};
 use crate::errors::BattleResult;
 use crate::move_data::get_move_data;
 use crate::player::PlayerAction;
+use crate::species::Species;
 
 /// A trait for any system that can decide on a battle action.
 /// This provides a common interface for different AI difficulties or strategies.
@@ -14,6 +19,120 @@ pub trait Behavior {
     fn decide_action(&self, player_index: usize, battle_state: &BattleState) -> PlayerAction;
 }
 
+/// Pick uniformly among the player's legal actions, drawing the choice from `rng`.
+///
+/// Unlike `ScoringAI` this has no strategy: it is for headless simulations that
+/// want broad coverage and reproducible results under a seeded `TurnRng`.
+/// Moves that are disabled or out of PP are never picked (Struggle is used when
+/// nothing else is left), and switches are only offered when not trapped and only
+/// to slots within the battle's format.
+pub fn random_legal_action(
+    player_index: usize,
+    battle_state: &BattleState,
+    rng: &mut TurnRng,
+) -> PlayerAction {
+    let player = &battle_state.players[player_index];
+    let must_struggle = player.must_struggle();
+    let mut options: Vec<PlayerAction> = player
+        .get_valid_moves()
+        .into_iter()
+        .filter(|action| match action {
+            PlayerAction::UseMove { move_index } => {
+                must_struggle
+                    || player
+                        .active_pokemon()
+                        .and_then(|pokemon| pokemon.moves[*move_index].as_ref())
+                        .is_some_and(|move_instance| move_instance.pp > 0)
+            }
+            _ => true,
+        })
+        .collect();
+    options.extend(
+        player
+            .legal_switch_targets(battle_state)
+            .into_iter()
+            .map(|team_index| PlayerAction::SwitchPokemon { team_index }),
+    );
+
+    if options.is_empty() {
+        // A healthy Pokemon that can't pick a move (e.g. recharging) still has to
+        // submit one; the engine turns it into the appropriate failure.
+        return match player.active_pokemon() {
+            Some(pokemon) if !pokemon.is_fainted() => PlayerAction::UseMove { move_index: 0 },
+            _ => PlayerAction::Forfeit,
+        };
+    }
+
+    // Rolls are 1-100, so map them evenly onto the option indices.
+    let roll = rng.next_outcome("Random Action Choice").saturating_sub(1) as usize;
+    let index = (roll * options.len() / 100).min(options.len() - 1);
+    options.swap_remove(index)
+}
+
+/// The moves one side's Pokemon have been seen using during a battle.
+///
+/// This is all an opponent can fairly know about a moveset, so it starts empty and
+/// anything consulting it must cope with having seen nothing yet.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct RevealedMoves {
+    by_species: HashMap<Species, Vec<Move>>,
+}
+
+impl RevealedMoves {
+    pub fn record(&mut self, pokemon: Species, move_used: Move) {
+        let moves = self.by_species.entry(pokemon).or_default();
+        if !moves.contains(&move_used) {
+            moves.push(move_used);
+        }
+    }
+
+    /// Record every move `player_index` was seen using in `events`.
+    pub fn observe(&mut self, player_index: usize, events: &[BattleEvent]) {
+        for event in events {
+            if let BattleEvent::MoveUsed {
+                player_index: user,
+                pokemon,
+                move_used,
+            } = event
+            {
+                if *user == player_index {
+                    self.record(*pokemon, *move_used);
+                }
+            }
+        }
+    }
+
+    /// The moves seen from `pokemon`, in the order they were first used.
+    pub fn moves_of(&self, pokemon: Species) -> &[Move] {
+        self.by_species
+            .get(&pokemon)
+            .map_or(&[], |moves| moves.as_slice())
+    }
+}
+
+/// How hard the opponent's revealed damaging moves hit the given types: the best
+/// type multiplier among them, or `None` if no damaging move has been seen.
+fn revealed_threat(
+    foe: PlayerTarget,
+    state: &BattleState,
+    defender_types: &[PokemonType],
+) -> Option<f32> {
+    let opponent = state.battler(foe).active_pokemon()?;
+    state.revealed_moves[foe.to_index()]
+        .moves_of(opponent.species)
+        .iter()
+        .filter_map(|move_| get_move_data(*move_).ok())
+        .filter(|move_data| move_data.power.is_some())
+        .map(|move_data| {
+            crate::battle::stats::get_type_effectiveness(move_data.move_type, defender_types) as f32
+        })
+        .max_by(|a, b| a.total_cmp(b))
+}
+
+/// Score bonus for switching, per point of type multiplier taken off the opponent's
+/// best revealed move. Escaping a super-effective hit into an immunity beats most attacks.
+const SWITCH_THREAT_WEIGHT: f32 = 100.0;
+
 pub struct ScoringAI;
 
 impl ScoringAI {
@@ -22,15 +141,19 @@ impl ScoringAI {
     }
 
     /// The core scoring logic. Assigns a floating-point value to a given action.
-    fn score_action(&self, action: &PlayerAction, player_index: usize, state: &BattleState) -> f32 {
-        let opponent_index = 1 - player_index;
-
+    fn score_action(
+        &self,
+        action: &PlayerAction,
+        actor: PlayerTarget,
+        foe: PlayerTarget,
+        state: &BattleState,
+    ) -> f32 {
         match action {
             PlayerAction::UseMove { move_index } => self
-                .score_move(*move_index, player_index, opponent_index, state)
+                .score_move(*move_index, actor, foe, state)
                 .unwrap_or(0.0),
             PlayerAction::SwitchPokemon { team_index } => {
-                self.score_switch(*team_index, player_index, opponent_index, state)
+                self.score_switch(*team_index, actor, foe, state)
             }
             PlayerAction::Catch => -2000.0, // AI should never try to catch - this is for player only.
             PlayerAction::Forfeit => -1000.0, // Never choose to forfeit unless it's the only option.
@@ -40,12 +163,12 @@ impl ScoringAI {
     fn score_move(
         &self,
         move_index: usize,
-        player_index: usize,
-        opponent_index: usize,
+        actor: PlayerTarget,
+        foe: PlayerTarget,
         state: &BattleState,
     ) -> BattleResult<f32> {
-        let player = &state.players[player_index];
-        let opponent = &state.players[opponent_index];
+        let player = state.battler(actor);
+        let opponent = state.battler(foe);
         let attacker = match player.active_pokemon() {
             Some(p) => p,
             None => return Ok(0.0), // Cannot score if there is no attacker.
@@ -103,6 +226,10 @@ impl ScoringAI {
 
         // --- Step 2: Calculate the Utility Score ---
         // This score is based on beneficial secondary effects, regardless of move category.
+        // Setting up is a poor idea in front of a revealed super-effective move.
+        let threatened = revealed_threat(foe, state, &attacker.get_current_types(player))
+            .is_some_and(|threat| threat > 1.0);
+        let setup_factor = if threatened { 0.25 } else { 1.0 };
         let mut utility_score = 0.0;
         for effect in &move_data.effects {
             match effect {
@@ -113,8 +240,11 @@ impl ScoringAI {
                     let current_stage = player.get_stat_stage(stat_type.clone().into());
                     if current_stage < 6 {
                         let potential_gain = 1.0 - (current_stage as f32 / 6.0); // Value diminishes as stat rises
-                        utility_score +=
-                            20.0 * (*stages as f32) * potential_gain * (*chance as f32 / 100.0);
+                        utility_score += 20.0
+                            * (*stages as f32)
+                            * potential_gain
+                            * (*chance as f32 / 100.0)
+                            * setup_factor;
                     }
                 }
                 // Opponent debuffs are valuable if the stat isn't minimized.
@@ -167,14 +297,34 @@ impl ScoringAI {
 
     fn score_switch(
         &self,
-        _team_index: usize,
-        _player_index: usize,
-        _opponent_index: usize,
-        _state: &BattleState,
+        team_index: usize,
+        actor: PlayerTarget,
+        foe: PlayerTarget,
+        state: &BattleState,
     ) -> f32 {
         // A small, positive baseline score. It's better than doing nothing or using
         // a move that's immune, but worse than almost any decent damaging move.
-        let base_score = 1.0;
+        let mut base_score = 1.0;
+
+        // If the opponent has revealed a move that hits the active Pokemon harder than
+        // it would hit this candidate, switching dodges that much of the threat.
+        let player = state.battler(actor);
+        if let (Some(active), Some(candidate)) = (
+            player.active_pokemon(),
+            player.player.team[team_index].as_ref(),
+        ) {
+            let threat_now = revealed_threat(foe, state, &active.get_current_types(player));
+            let candidate_types = candidate
+                .get_species_data()
+                .map(|data| data.types.clone())
+                .unwrap_or_default();
+            let threat_after = revealed_threat(foe, state, &candidate_types);
+            if let (Some(now), Some(after)) = (threat_now, threat_after) {
+                if now > after {
+                    base_score += SWITCH_THREAT_WEIGHT * (now - after);
+                }
+            }
+        }
 
         // Add a tiny random value to break ties if multiple switch options exist.
         // This ensures that if the AI decides to switch, it won't always pick the
@@ -183,11 +333,17 @@ impl ScoringAI {
 
         base_score + random_tiebreaker
     }
-}
 
-impl Behavior for ScoringAI {
-    fn decide_action(&self, player_index: usize, battle_state: &BattleState) -> PlayerAction {
-        let player = &battle_state.players[player_index];
+    /// Decide the action for the Pokemon in `actor`'s slot, sizing it up against the
+    /// opposing Pokemon in `foe`'s slot. A double battle asks once for each slot.
+    pub fn decide_slot_action(
+        &self,
+        actor: PlayerTarget,
+        foe: PlayerTarget,
+        battle_state: &BattleState,
+    ) -> PlayerAction {
+        let player_index = actor.to_index();
+        let player = battle_state.battler(actor);
 
         // --- Phase 1: Handle Forced Replacements ---
         // If the game state requires a replacement, the only valid actions are switches.
@@ -200,7 +356,8 @@ impl Behavior for ScoringAI {
         };
 
         if is_replacement_phase {
-            let valid_switches = player.get_valid_switches();
+            let valid_switches =
+                player.get_valid_switches_in_format(battle_state.rules.format_team_size);
 
             // If there are no valid switches, the player has lost. Forfeit is the only option.
             if valid_switches.is_empty() {
@@ -211,7 +368,7 @@ impl Behavior for ScoringAI {
             return valid_switches
                 .into_iter()
                 .max_by_key(|action| {
-                    let score = self.score_action(action, player_index, battle_state);
+                    let score = self.score_action(action, actor, foe, battle_state);
                     ordered_float::OrderedFloat(score)
                 })
                 .unwrap_or(PlayerAction::Forfeit); // Failsafe
@@ -225,17 +382,18 @@ impl Behavior for ScoringAI {
         let best_move = valid_moves
             .into_iter()
             .map(|action| {
-                let score = self.score_action(&action, player_index, battle_state);
+                let score = self.score_action(&action, actor, foe, battle_state);
                 (action, score)
             })
             .max_by_key(|(_, score)| ordered_float::OrderedFloat(*score));
 
         // 2b. Get and score all possible switches.
-        let valid_switches = player.get_valid_switches();
+        let valid_switches =
+            player.get_valid_switches_in_format(battle_state.rules.format_team_size);
         let best_switch = valid_switches
             .into_iter()
             .map(|action| {
-                let score = self.score_action(&action, player_index, battle_state);
+                let score = self.score_action(&action, actor, foe, battle_state);
                 (action, score)
             })
             .max_by_key(|(_, score)| ordered_float::OrderedFloat(*score));
@@ -262,3 +420,10 @@ impl Behavior for ScoringAI {
         }
     }
 }
+
+impl Behavior for ScoringAI {
+    fn decide_action(&self, player_index: usize, battle_state: &BattleState) -> PlayerAction {
+        let actor = PlayerTarget::from_index(player_index);
+        self.decide_slot_action(actor, actor.opponent(), battle_state)
+    }
+}
@@ -1,30 +1,32 @@
 use crate::battle::commands::{BattleCommand, PlayerTarget};
 use crate::battle::conditions::{PokemonCondition, PokemonConditionType};
 use crate::battle::move_effects::{
-    BattleMoveDataExt, BattleMoveEffectExt, EffectContext, EffectResult,
+    settle_no_effect, BattleMoveDataExt, BattleMoveEffectExt, EffectContext, EffectResult,
 };
 use crate::battle::state::{ActionFailureReason, BattleEvent, BattleState, TurnRng};
 use crate::battle::stats::{move_hits, move_is_critical_hit};
 use crate::errors::BattleResult;
 use crate::move_data::get_move_data;
-use crate::player::PlayerAction;
+use crate::player::{Battler, DamageRecord, PlayerAction};
 use schema::{Move, MoveCategory, MoveData, MoveEffect};
 
 /// Calculate the outcome of an attack attempt
 ///
 /// This function coordinates the entire attack sequence through helper functions.
+/// `spread_hit` marks one of several targets of a spread move in a double battle.
 pub fn calculate_attack_outcome(
     state: &BattleState,
-    attacker_index: usize,
-    defender_index: usize,
+    attacker: PlayerTarget,
+    defender: PlayerTarget,
     move_used: Move,
     hit_number: u8,
+    spread_hit: bool,
     rng: &mut TurnRng,
 ) -> BattleResult<Vec<BattleCommand>> {
     let mut commands = Vec::new();
 
-    let attacker_player = &state.players[attacker_index];
-    let defender_player = &state.players[defender_index];
+    let attacker_player = state.battler(attacker);
+    let defender_player = state.battler(defender);
 
     let (attacker_pokemon, defender_pokemon) =
         match validate_pokemon_participation(attacker_player, defender_player) {
@@ -35,7 +37,7 @@ pub fn calculate_attack_outcome(
     // Emit MoveUsed event for the first hit of any move attempt.
     if hit_number == 0 {
         commands.push(BattleCommand::EmitEvent(BattleEvent::MoveUsed {
-            player_index: attacker_index,
+            player_index: attacker.to_index(),
             pokemon: attacker_pokemon.species,
             move_used,
         }));
@@ -44,7 +46,7 @@ pub fn calculate_attack_outcome(
     let move_data = get_move_data(move_used)?;
 
     // First, check for any special move effects that might skip the normal attack sequence.
-    let context = EffectContext::new(attacker_index, defender_index, move_used);
+    let context = EffectContext::new(attacker, defender, move_used);
     let mut regular_effect_commands = Vec::new();
     let mut ensured_effect_commands = Vec::new();
 
@@ -79,20 +81,24 @@ pub fn calculate_attack_outcome(
     }
 
     // If we've reached this point, no effect returned 'Skip', so we proceed with a normal attack.
+    settle_no_effect(
+        &context,
+        &mut regular_effect_commands,
+        &ensured_effect_commands,
+    );
 
     if hit_result {
-        let hit_commands = handle_successful_hit(
-            attacker_pokemon,
-            defender_pokemon,
-            attacker_player,
-            defender_player,
-            attacker_index,
-            defender_index,
-            move_used,
-            rng,
-        )?;
+        let hit_commands =
+            handle_successful_hit(state, attacker, defender, move_used, spread_hit, rng)?;
 
-        commands.extend(hit_commands.clone());
+        let damage = hit_commands
+            .iter()
+            .find_map(|cmd| match cmd {
+                BattleCommand::DealDamage { amount, .. } => Some(*amount),
+                _ => None,
+            })
+            .unwrap_or(0);
+        commands.extend(hit_commands);
 
         // Check if the target is immune to the move's type
         let defender_types = defender_pokemon.get_current_types(defender_player);
@@ -103,18 +109,25 @@ pub fn calculate_attack_outcome(
             commands.extend(regular_effect_commands);
         }
 
-        let damage = hit_commands
-            .iter()
-            .find_map(|cmd| match cmd {
-                BattleCommand::DealDamage { amount, .. } => Some(*amount),
-                _ => None,
-            })
-            .unwrap_or(0);
-
         if damage > 0 {
             let damage_commands = move_data.apply_damage_based_effects(&context, state, damage);
             commands.extend(damage_commands);
         }
+
+        // A frozen target that survives a thawing move (e.g. any damaging Fire move) thaws out.
+        if !is_immune
+            && move_data.thaws_frozen_target()
+            && matches!(
+                defender_pokemon.status,
+                Some(crate::pokemon::StatusCondition::Freeze)
+            )
+            && damage < defender_pokemon.current_hp()
+        {
+            commands.push(BattleCommand::CurePokemonStatus {
+                target: defender,
+                status: crate::pokemon::StatusCondition::Freeze,
+            });
+        }
     } else {
         commands.push(BattleCommand::EmitEvent(BattleEvent::MoveMissed {
             attacker: attacker_pokemon.species,
@@ -142,8 +155,8 @@ pub fn calculate_attack_outcome(
 
 /// Validate that both Pokemon can participate in the attack
 fn validate_pokemon_participation<'a>(
-    attacker_player: &'a crate::player::BattlePlayer,
-    defender_player: &'a crate::player::BattlePlayer,
+    attacker_player: Battler<'a>,
+    defender_player: Battler<'a>,
 ) -> Result<
     (
         &'a crate::pokemon::PokemonInst,
@@ -170,17 +183,23 @@ fn validate_pokemon_participation<'a>(
 
 /// Handle all logic for a successful hit
 fn handle_successful_hit(
-    attacker_pokemon: &crate::pokemon::PokemonInst,
-    defender_pokemon: &crate::pokemon::PokemonInst,
-    attacker_player: &crate::player::BattlePlayer,
-    defender_player: &crate::player::BattlePlayer,
-    attacker_index: usize,
-    defender_index: usize,
+    state: &BattleState,
+    attacker: PlayerTarget,
+    defender: PlayerTarget,
     move_used: Move,
+    spread_hit: bool,
     rng: &mut TurnRng,
 ) -> BattleResult<Vec<BattleCommand>> {
     let mut commands = Vec::new();
 
+    let attacker_player = state.battler(attacker);
+    let defender_player = state.battler(defender);
+    let (attacker_pokemon, defender_pokemon) =
+        match validate_pokemon_participation(attacker_player, defender_player) {
+            Ok(pokemon) => pokemon,
+            Err(error_command) => return Ok(vec![error_command]),
+        };
+
     // Emit hit event
     commands.push(BattleCommand::EmitEvent(BattleEvent::MoveHit {
         attacker: attacker_pokemon.species,
@@ -207,27 +226,40 @@ fn handle_successful_hit(
         rng,
         &mut commands,
     )?;
+    // A spread hit (one of several targets in a double battle) deals 3/4 damage.
+    let damage = if spread_hit {
+        (u32::from(damage) * 3 / 4) as u16
+    } else {
+        damage
+    };
+    // Cap damage to defender's current HP to get actual damage that will be dealt
+    let damage = damage.min(defender_pokemon.current_hp());
 
     // Handle damage application and conditions
     if damage > 0 {
-        handle_damage_application(
+        let hit = DamageRecord {
+            move_used,
+            category: move_data.category,
             damage,
+        };
+        handle_damage_application(
+            hit,
             defender_pokemon,
             defender_player,
-            defender_index,
+            attacker,
+            defender,
             &mut commands,
         );
 
         // Handle damage-triggered condition reactions
         handle_damage_triggered_conditions(
-            damage,
+            &hit,
             defender_pokemon,
             defender_player,
-            attacker_index,
-            defender_index,
-            move_used,
+            attacker,
+            defender,
             &mut commands,
-        )?;
+        );
     }
 
     Ok(commands)
@@ -237,7 +269,7 @@ fn handle_successful_hit(
 fn calculate_and_emit_type_effectiveness(
     move_data: &MoveData,
     defender_pokemon: &crate::pokemon::PokemonInst,
-    defender_player: &crate::player::BattlePlayer,
+    defender_player: Battler,
     commands: &mut Vec<BattleCommand>,
 ) -> f64 {
     // Status moves don't have type effectiveness
@@ -263,12 +295,12 @@ fn calculate_and_emit_type_effectiveness(
     type_adv_multiplier
 }
 
-/// Calculate damage for the move, handling both special and normal damage
+/// Calculate damage for the move, handling both special and normal damage.
 fn calculate_move_damage(
     attacker_pokemon: &crate::pokemon::PokemonInst,
     defender_pokemon: &crate::pokemon::PokemonInst,
-    attacker_player: &crate::player::BattlePlayer,
-    defender_player: &crate::player::BattlePlayer,
+    attacker_player: Battler,
+    defender_player: Battler,
     move_used: Move,
     type_adv_multiplier: f64,
     rng: &mut TurnRng,
@@ -287,8 +319,10 @@ fn calculate_move_damage(
             0
         }
     } else {
-        // Normal damage move - check for critical hit first
-        let is_critical = move_is_critical_hit(attacker_pokemon, attacker_player, move_used, rng)?;
+        // Normal damage move - check for critical hit first.
+        // A confusion self-hit can never be critical, so it doesn't roll for one.
+        let is_critical = move_used != Move::HittingItself
+            && move_is_critical_hit(attacker_pokemon, attacker_player, move_used, rng)?;
 
         if is_critical {
             commands.push(BattleCommand::EmitEvent(BattleEvent::CriticalHit {
@@ -310,50 +344,102 @@ fn calculate_move_damage(
         )?
     };
 
-    // Cap damage to defender's current HP to get actual damage that will be dealt
-    Ok(theoretical_damage.min(defender_pokemon.current_hp()))
+    Ok(theoretical_damage)
 }
 
-/// Handle damage application, including substitute protection
+/// Handle damage application, including substitute protection.
+/// A substitute only stands in for hits from the opponent; a Pokemon hurting itself
+/// (a confusion self-hit) takes the damage to its real HP.
 fn handle_damage_application(
-    damage: u16,
+    hit: DamageRecord,
     defender_pokemon: &crate::pokemon::PokemonInst,
-    defender_player: &crate::player::BattlePlayer,
-    defender_index: usize,
+    defender_player: Battler,
+    attacker: PlayerTarget,
+    defender: PlayerTarget,
     commands: &mut Vec<BattleCommand>,
 ) {
     // Check for Substitute protection
-    if let Some(substitute_hp) =
-        defender_player
-            .active_pokemon_conditions
-            .values()
-            .find_map(|condition| match condition {
-                PokemonCondition::Substitute { hp } => Some(hp),
-                _ => None,
-            })
-    {
+    if let Some(substitute_hp) = substitute_hp_against(defender_player, attacker, defender) {
         handle_substitute_damage_absorption(
-            damage,
-            *substitute_hp,
+            hit.damage,
+            substitute_hp,
             defender_pokemon,
-            defender_index,
+            defender,
             commands,
         );
     } else {
         // No substitute, normal damage to Pokemon
         commands.push(BattleCommand::DealDamage {
-            target: PlayerTarget::from_index(defender_index),
-            amount: damage,
+            target: defender,
+            amount: hit.damage,
+        });
+        let damage = hit.damage;
+        commands.push(BattleCommand::SetLastDamageTaken {
+            target: defender,
+            record: Some(hit),
         });
+        commands.extend(held_item_trigger_commands(
+            defender_pokemon,
+            damage,
+            defender,
+        ));
     }
 }
 
+/// The HP of the defender's substitute, if it would absorb a hit from `attacker`.
+/// Self-inflicted hits go around the substitute.
+fn substitute_hp_against(
+    defender_player: Battler,
+    attacker: PlayerTarget,
+    defender: PlayerTarget,
+) -> Option<u8> {
+    if attacker == defender {
+        return None;
+    }
+    defender_player
+        .conditions()
+        .values()
+        .find_map(|condition| match condition {
+            PokemonCondition::Substitute { hp } => Some(*hp),
+            _ => None,
+        })
+}
+
+/// A held berry triggers once a hit leaves its holder at half HP or less.
+/// A lethal hit isn't softened: the holder faints and keeps the berry.
+fn held_item_trigger_commands(
+    defender_pokemon: &crate::pokemon::PokemonInst,
+    damage: u16,
+    target: PlayerTarget,
+) -> Vec<BattleCommand> {
+    let Some(item) = defender_pokemon.held_item else {
+        return Vec::new();
+    };
+    let Some(heal_amount) = item.held_heal_amount() else {
+        return Vec::new();
+    };
+
+    let max_hp = defender_pokemon.max_hp();
+    let remaining_hp = defender_pokemon.current_hp().saturating_sub(damage);
+    if remaining_hp == 0 || remaining_hp > max_hp / 2 {
+        return Vec::new();
+    }
+
+    vec![
+        BattleCommand::ConsumeHeldItem { target, item },
+        BattleCommand::HealPokemon {
+            target,
+            amount: heal_amount.min(max_hp - remaining_hp),
+        },
+    ]
+}
+
 /// Handle substitute damage absorption and destruction
 fn handle_substitute_damage_absorption(
     damage: u16,
     substitute_hp: u8,
     defender_pokemon: &crate::pokemon::PokemonInst,
-    defender_index: usize,
+    defender: PlayerTarget,
     commands: &mut Vec<BattleCommand>,
 ) {
     let actual_damage = damage.min(substitute_hp as u16);
@@ -363,17 +449,17 @@ fn handle_substitute_damage_absorption(
     if substitute_destroyed {
         // Substitute is destroyed
         commands.push(BattleCommand::RemoveSpecificCondition {
-            target: PlayerTarget::from_index(defender_index),
+            target: defender,
             condition: PokemonCondition::Substitute { hp: substitute_hp },
         });
     } else {
         // Update substitute HP - remove old and add new
         commands.push(BattleCommand::RemoveCondition {
-            target: PlayerTarget::from_index(defender_index),
+            target: defender,
             condition_type: PokemonConditionType::Substitute,
         });
         commands.push(BattleCommand::AddCondition {
-            target: PlayerTarget::from_index(defender_index),
+            target: defender,
             condition: PokemonCondition::Substitute {
                 hp: remaining_substitute_hp,
             },
@@ -391,42 +477,28 @@ fn handle_substitute_damage_absorption(
 
 /// Handle conditions triggered by damage using the new condition method system
 fn handle_damage_triggered_conditions(
-    damage: u16,
+    hit: &DamageRecord,
     defender_pokemon: &crate::pokemon::PokemonInst,
-    defender_player: &crate::player::BattlePlayer,
-    attacker_index: usize,
-    defender_index: usize,
-    move_used: Move,
+    defender_player: Battler,
+    attacker: PlayerTarget,
+    defender: PlayerTarget,
     commands: &mut Vec<BattleCommand>,
-) -> BattleResult<()> {
+) {
     // Only trigger if damage wasn't absorbed by substitute
-    let damage_absorbed_by_substitute = defender_player
-        .active_pokemon_conditions
-        .values()
-        .any(|condition| matches!(condition, PokemonCondition::Substitute { .. }));
-
-    if damage_absorbed_by_substitute {
-        return Ok(());
+    if substitute_hp_against(defender_player, attacker, defender).is_some() {
+        return;
     }
 
-    let move_data = get_move_data(move_used)?;
-    let attacker_target = PlayerTarget::from_index(attacker_index);
-    let defender_target = PlayerTarget::from_index(defender_index);
-
     // Let each condition handle its own damage reaction
-    for condition in defender_player.active_pokemon_conditions.values() {
+    for condition in defender_player.conditions().values() {
         let condition_commands = condition.on_damage_taken(
-            damage,
-            attacker_target,
-            defender_target,
+            hit,
+            defender,
             defender_pokemon.species,
-            move_data.category,
-            defender_pokemon.current_hp(),
             defender_player.get_stat_stage(crate::player::StatType::Atk),
         );
         commands.extend(condition_commands);
     }
-    Ok(())
 }
 
 /// Other Calculations
@@ -436,12 +508,10 @@ fn handle_damage_triggered_conditions(
 pub fn calculate_condition_damage_commands(battle_state: &BattleState) -> Vec<BattleCommand> {
     let mut commands = Vec::new();
 
-    // Process each player's active Pokemon for condition effects
-    for player_index in 0..2 {
-        let opponent_index = 1 - player_index;
-
-        // Check if this player has an active, non-fainted Pokemon
-        let player = &battle_state.players[player_index];
+    // Process each active Pokemon on the field for condition effects
+    for target in battle_state.field_targets() {
+        // Check if this slot has an active, non-fainted Pokemon
+        let player = battle_state.battler(target);
         if let Some(pokemon) = player.active_pokemon() {
             if pokemon.is_fainted() {
                 continue;
@@ -449,7 +519,7 @@ pub fn calculate_condition_damage_commands(battle_state: &BattleState) -> Vec<Ba
 
             let max_hp = pokemon.max_hp();
             let has_trapped = player
-                .active_pokemon_conditions
+                .conditions()
                 .values()
                 .any(|condition| matches!(condition, PokemonCondition::Trapped { .. }));
             let has_seeded = player.has_condition_type(PokemonConditionType::Seeded);
@@ -459,7 +529,7 @@ pub fn calculate_condition_damage_commands(battle_state: &BattleState) -> Vec<Ba
                 let current_hp = pokemon.current_hp();
                 let condition_damage = (max_hp / 16).max(1).min(current_hp); // Cap to current HP
                 commands.push(BattleCommand::DealConditionDamage {
-                    target: PlayerTarget::from_index(player_index),
+                    target,
                     condition: PokemonCondition::Trapped { turns_remaining: 1 },
                     amount: condition_damage,
                 });
@@ -471,13 +541,13 @@ pub fn calculate_condition_damage_commands(battle_state: &BattleState) -> Vec<Ba
                 let actual_damage = (max_hp / 8).max(1).min(current_hp);
 
                 commands.push(BattleCommand::DealConditionDamage {
-                    target: PlayerTarget::from_index(player_index),
+                    target,
                     condition: PokemonCondition::Seeded,
                     amount: actual_damage,
                 });
 
                 // Heal the opponent if they have an active Pokemon
-                let opponent_player = &battle_state.players[opponent_index];
+                let opponent_player = battle_state.battler(target.opponent());
                 if let Some(opponent_pokemon) = opponent_player.active_pokemon() {
                     if !opponent_pokemon.is_fainted() {
                         let opponent_current_hp = opponent_pokemon.current_hp();
@@ -487,7 +557,7 @@ pub fn calculate_condition_damage_commands(battle_state: &BattleState) -> Vec<Ba
 
                         if actual_heal > 0 {
                             commands.push(BattleCommand::HealPokemon {
-                                target: PlayerTarget::from_index(opponent_index),
+                                target: target.opponent(),
                                 amount: actual_heal,
                             });
                         }
@@ -508,8 +578,8 @@ pub fn calculate_end_turn_commands(
 ) -> Vec<BattleCommand> {
     let mut commands = Vec::new();
 
-    for player_index in 0..2 {
-        let player = &battle_state.players[player_index];
+    for target in battle_state.field_targets() {
+        let player = battle_state.battler(target);
         if let Some(pokemon) = player.active_pokemon() {
             // Fainted Pokemon do not take end-of-turn damage or effects.
             if pokemon.is_fainted() {
@@ -522,7 +592,7 @@ pub fn calculate_end_turn_commands(
 
                 if status_damage > 0 {
                     commands.push(BattleCommand::DealStatusDamage {
-                        target: PlayerTarget::from_index(player_index),
+                        target,
                         status,
                         amount: status_damage,
                     });
@@ -530,8 +600,7 @@ pub fn calculate_end_turn_commands(
             }
 
             // 2. Process active Pokemon conditions - emit atomic commands for each condition
-            let target = PlayerTarget::from_index(player_index);
-            for (_condition_type, condition) in &player.active_pokemon_conditions {
+            for (_condition_type, condition) in player.conditions() {
                 // Tick the condition
                 commands.push(BattleCommand::TickPokemonCondition {
                     target,
@@ -543,16 +612,20 @@ pub fn calculate_end_turn_commands(
                     PokemonCondition::Confused { turns_remaining } => *turns_remaining <= 0,
                     PokemonCondition::Exhausted { turns_remaining } => *turns_remaining <= 0,
                     PokemonCondition::Trapped { turns_remaining } => *turns_remaining <= 0,
+                    PokemonCondition::SwitchLocked { turns_remaining } => *turns_remaining == 0,
+                    PokemonCondition::Trapping { turns_remaining } => *turns_remaining == 0,
                     PokemonCondition::Rampaging { turns_remaining } => *turns_remaining <= 0,
                     PokemonCondition::Disabled {
                         turns_remaining, ..
                     } => *turns_remaining <= 0,
+                    PokemonCondition::Cooldown {
+                        turns_remaining, ..
+                    } => *turns_remaining == 0,
                     PokemonCondition::Biding {
                         turns_remaining, ..
                     } => *turns_remaining <= 0,
                     PokemonCondition::Flinched => true, // Flinch always expires at end of turn
                     PokemonCondition::Teleported => true, // Teleported expires at end of turn
-                    PokemonCondition::Countering { .. } => true, // Counter expires at end of turn
                     // Charging does NOT expire at end of turn - it expires when the move executes
                     _ => false, // Other conditions don't expire automatically
                 };
@@ -596,12 +669,29 @@ pub fn calculate_end_turn_commands(
     commands
 }
 
+/// Emit `HandleFainted` for any active Pokemon at 0 HP whose faint hasn't been processed yet.
+/// Safe to call after every action: faints that were already handled are skipped.
+pub fn sweep_faints(battle_state: &BattleState) -> Vec<BattleCommand> {
+    battle_state
+        .field_targets()
+        .into_iter()
+        .filter(|&target| !battle_state.is_faint_handled(target))
+        .filter(|&target| {
+            battle_state
+                .battler(target)
+                .active_pokemon()
+                .is_some_and(|pokemon| pokemon.current_hp() == 0)
+        })
+        .map(|target| BattleCommand::HandleFainted { target })
+        .collect()
+}
+
 /// Calculate commands to queue forced actions for the next turn
 pub fn calculate_forced_action_commands(battle_state: &BattleState) -> Vec<BattleCommand> {
     let mut commands = Vec::new();
 
-    for player_index in 0..2 {
-        let player = &battle_state.players[player_index];
+    for target in battle_state.field_targets() {
+        let player = battle_state.battler(target);
 
         if let Some(forced_move) = player.forced_move() {
             if let Some(active_pokemon) = player.active_pokemon() {
@@ -611,7 +701,7 @@ pub fn calculate_forced_action_commands(battle_state: &BattleState) -> Vec<Battl
                     .position(|m| m.as_ref().map_or(false, |inst| inst.move_ == forced_move))
                 {
                     commands.push(BattleCommand::QueueForcedAction {
-                        target: PlayerTarget::from_index(player_index),
+                        target,
                         action: PlayerAction::UseMove { move_index: index },
                     });
                 }
@@ -622,11 +712,33 @@ pub fn calculate_forced_action_commands(battle_state: &BattleState) -> Vec<Battl
     commands
 }
 
+/// A Pokemon kept from acting by sleep, freeze or full paralysis while holding a
+/// two-turn move loses the charge, rather than being left locked into the move.
+fn lose_charge_commands(target: PlayerTarget, battle_state: &BattleState) -> Vec<BattleCommand> {
+    battle_state
+        .battler(target)
+        .conditions()
+        .values()
+        .filter(|condition| {
+            matches!(
+                condition,
+                PokemonCondition::Charging
+                    | PokemonCondition::InAir
+                    | PokemonCondition::Underground
+            )
+        })
+        .map(|condition| BattleCommand::ExpirePokemonCondition {
+            target,
+            condition: condition.clone(),
+        })
+        .collect()
+}
+
 /// Calculate all conditions that can prevent a Pokemon from taking action
 /// Returns (Option<ActionFailureReason>, Vec<BattleCommand>) where the commands handle
 /// status updates and condition changes that occur during the prevention check
 pub fn calculate_action_prevention(
-    player_index: usize,
+    target: PlayerTarget,
     battle_state: &BattleState,
     rng: &mut TurnRng,
     move_used: Move,
@@ -634,11 +746,8 @@ pub fn calculate_action_prevention(
     let mut commands = Vec::new();
 
     // Check Pokemon status conditions BEFORE updating counters
-    let (pokemon_status, pokemon_species) = if let Some(pokemon) = battle_state.players
-        [player_index]
-        .team[battle_state.players[player_index].active_pokemon_index]
-        .as_ref()
-    {
+    let player = battle_state.battler(target);
+    let (pokemon_status, pokemon_species) = if let Some(pokemon) = player.active_pokemon() {
         (pokemon.status, pokemon.species)
     } else {
         return (Some(ActionFailureReason::PokemonFainted), commands);
@@ -650,9 +759,8 @@ pub fn calculate_action_prevention(
             crate::pokemon::StatusCondition::Sleep(turns) => {
                 if turns > 0 {
                     // Pokemon is still asleep, update counters after determining failure
-                    commands.push(BattleCommand::UpdateStatusProgress {
-                        target: PlayerTarget::from_index(player_index),
-                    });
+                    commands.push(BattleCommand::UpdateStatusProgress { target });
+                    commands.extend(lose_charge_commands(target, battle_state));
                     return (
                         Some(ActionFailureReason::IsAsleep {
                             pokemon: pokemon_species,
@@ -662,16 +770,24 @@ pub fn calculate_action_prevention(
                 }
             }
             crate::pokemon::StatusCondition::Freeze => {
-                // 25% chance to thaw out when trying to act
-                let roll = rng.next_outcome("Defrost Check"); // 0-100
-                if roll < 25 {
+                // Moves flagged to thaw the user always let it break free.
+                // Otherwise, only the quality-of-life ruleset gives a 25% chance
+                // to thaw out when trying to act; in Gen 1 freeze is permanent.
+                let thaws_user = get_move_data(move_used)
+                    .map(|data| data.thaws_user)
+                    .unwrap_or(false);
+                if thaws_user
+                    || (battle_state.rules.gen_quirks.frozen_self_thaw()
+                        && rng.next_outcome("Defrost Check") < 25)
+                {
                     // Pokemon thaws out
                     commands.push(BattleCommand::CurePokemonStatus {
-                        target: PlayerTarget::from_index(player_index),
+                        target,
                         status: crate::pokemon::StatusCondition::Freeze,
                     });
                     // Pokemon can act this turn after thawing
                 } else {
+                    commands.extend(lose_charge_commands(target, battle_state));
                     return (
                         Some(ActionFailureReason::IsFrozen {
                             pokemon: pokemon_species,
@@ -685,10 +801,7 @@ pub fn calculate_action_prevention(
     }
 
     // Update status counters for Pokemon that are not asleep with turns > 0 (they were handled above)
-    let current_status = if let Some(pokemon) = battle_state.players[player_index].team
-        [battle_state.players[player_index].active_pokemon_index]
-        .as_ref()
-    {
+    let current_status = if let Some(pokemon) = player.active_pokemon() {
         pokemon.status
     } else {
         return (Some(ActionFailureReason::PokemonFainted), commands);
@@ -701,13 +814,9 @@ pub fn calculate_action_prevention(
     };
 
     if should_update_counters {
-        commands.push(BattleCommand::UpdateStatusProgress {
-            target: PlayerTarget::from_index(player_index),
-        });
+        commands.push(BattleCommand::UpdateStatusProgress { target });
     }
 
-    let player = &battle_state.players[player_index];
-
     // Check active Pokemon conditions
     if player.has_condition_type(PokemonConditionType::Flinched) {
         return (
@@ -719,7 +828,7 @@ pub fn calculate_action_prevention(
     }
 
     // Check for exhausted condition (any turns_remaining > 0 means still exhausted)
-    for condition in player.active_pokemon_conditions.values() {
+    for condition in player.conditions().values() {
         if let PokemonCondition::Exhausted { turns_remaining } = condition {
             if *turns_remaining > 0 {
                 return (
@@ -732,10 +841,26 @@ pub fn calculate_action_prevention(
         }
     }
 
+    // Check for partial trapping (Wrap, Bind, etc.) - as in Gen 1, the trapped Pokemon
+    // loses its turn while the trap holds, not just the ability to switch
+    for condition in player.conditions().values() {
+        if let PokemonCondition::Trapped { turns_remaining } = condition {
+            if *turns_remaining > 0 {
+                return (
+                    Some(ActionFailureReason::IsTrapped {
+                        pokemon: pokemon_species,
+                    }),
+                    commands,
+                );
+            }
+        }
+    }
+
     // Check paralysis - 25% chance to be fully paralyzed
     if let Some(crate::pokemon::StatusCondition::Paralysis) = pokemon_status {
         let roll = rng.next_outcome("Immobilized by Paralysis Check"); // 0-100
         if roll < 25 {
+            commands.extend(lose_charge_commands(target, battle_state));
             return (
                 Some(ActionFailureReason::IsParalyzed {
                     pokemon: pokemon_species,
@@ -747,12 +872,12 @@ pub fn calculate_action_prevention(
 
     // Check confusion - 50% chance to hit self instead
     // Confusion ticks at end of turn, but expires when Pokemon tries to act with 0 turns remaining
-    for condition in player.active_pokemon_conditions.values() {
+    for condition in player.conditions().values() {
         if let PokemonCondition::Confused { turns_remaining } = condition {
             if *turns_remaining == 0 {
                 // This is the last turn of confusion - confusion ends, no self-hit check
                 commands.push(BattleCommand::ExpirePokemonCondition {
-                    target: PlayerTarget::from_index(player_index),
+                    target,
                     condition: condition.clone(),
                 });
                 // Confusion has ended, so no chance to hit self - action proceeds normally
@@ -775,11 +900,15 @@ pub fn calculate_action_prevention(
         }
     }
 
-    // Check for disabled moves
-    for condition in player.active_pokemon_conditions.values() {
+    // Check for disabled moves and moves still cooling down
+    for condition in player.conditions().values() {
         if let PokemonCondition::Disabled {
             pokemon_move,
             turns_remaining,
+        }
+        | PokemonCondition::Cooldown {
+            pokemon_move,
+            turns_remaining,
         } = condition
         {
             if *turns_remaining > 0 && *pokemon_move == move_used {
@@ -797,9 +926,8 @@ pub fn calculate_action_prevention(
     if let Ok(move_data) = get_move_data(move_used) {
         for effect in &move_data.effects {
             if matches!(effect, MoveEffect::Nightmare) {
-                // Get the target (enemy) index - if we're player 0, target is 1, and vice versa
-                let target_index = if player_index == 0 { 1 } else { 0 };
-                let target_player = &battle_state.players[target_index];
+                // The target is the opposing Pokemon facing this one
+                let target_player = battle_state.battler(target.opponent());
 
                 if let Some(target_pokemon) = target_player.active_pokemon() {
                     // Check if target is asleep
@@ -826,30 +954,36 @@ pub fn calculate_action_prevention(
 
 /// Calculate commands for a Pokemon switch action
 pub fn calculate_switch_commands(
-    player_index: usize,
+    target: PlayerTarget,
     target_pokemon_index: usize,
     battle_state: &BattleState,
 ) -> Vec<BattleCommand> {
-    let target = PlayerTarget::from_index(player_index);
-    let player = &battle_state.players[player_index];
+    let player = battle_state.battler(target);
 
     // Capture the old and new Pokemon info before the state change
-    let old_pokemon = player.team[player.active_pokemon_index]
-        .as_ref()
-        .map(|p| p.species);
-    let new_pokemon = player.team[target_pokemon_index]
+    let old_pokemon = player.active_pokemon().map(|p| p.species);
+    let new_pokemon = player.player.team[target_pokemon_index]
         .as_ref()
         .map(|p| p.species);
 
-    let mut commands = vec![
-        // 1. Command to clear the old state.
-        BattleCommand::ClearPlayerState { target },
-    ];
+    let mut commands = Vec::new();
+
+    // Under the natural-cure ruleset, the outgoing Pokemon sheds its status while still active.
+    if battle_state.rules.natural_cure_on_switch {
+        if let Some(status) = player.active_pokemon().and_then(|p| p.status) {
+            if !matches!(status, crate::pokemon::StatusCondition::Faint) {
+                commands.push(BattleCommand::CurePokemonStatus { target, status });
+            }
+        }
+    }
+
+    // 1. Command to clear the old state.
+    commands.push(BattleCommand::ClearPlayerState { target });
 
     // 2. Emit the switch event with correct old/new Pokemon info
     if let (Some(old), Some(new)) = (old_pokemon, new_pokemon) {
         commands.push(BattleCommand::EmitEvent(BattleEvent::PokemonSwitched {
-            player_index,
+            player_index: target.to_index(),
             old_pokemon: old,
             new_pokemon: new,
         }));
@@ -861,6 +995,30 @@ pub fn calculate_switch_commands(
         new_pokemon_index: target_pokemon_index,
     });
 
+    // 4. Entry effects for the incoming Pokemon, which is now the active one.
+    if let Some(incoming) = player.player.team[target_pokemon_index].as_ref() {
+        commands.extend(calculate_switch_in_commands(target, incoming));
+    }
+
+    commands
+}
+
+/// Calculate the "on switch in" effects for a Pokemon entering the field.
+/// This is the place for entry behaviors (e.g. hazards or abilities); the
+/// commands run after the switch, so they target the incoming Pokemon.
+fn calculate_switch_in_commands(
+    target: PlayerTarget,
+    incoming: &crate::pokemon::PokemonInst,
+) -> Vec<BattleCommand> {
+    let mut commands = Vec::new();
+
+    // Gen 1: a badly poisoned Pokemon reverts to regular poison once it has been switched out.
+    if let Some(crate::pokemon::StatusCondition::Poison(severity)) = incoming.status {
+        if severity > 0 {
+            commands.push(BattleCommand::ResetToxicCounter { target });
+        }
+    }
+
     commands
 }
 
@@ -881,6 +1039,15 @@ pub fn calculate_forfeit_commands(player_index: usize) -> Vec<BattleCommand> {
     ]
 }
 
+/// Calculate commands for both players forfeiting in the same turn.
+/// Neither side can claim the win, so the battle ends in a draw.
+pub fn calculate_double_forfeit_commands() -> Vec<BattleCommand> {
+    vec![
+        BattleCommand::SetGameState(crate::battle::state::GameState::Draw),
+        BattleCommand::EmitEvent(BattleEvent::BattleEnded { winner: None }),
+    ]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -934,7 +1101,10 @@ mod tests {
             team_conditions: HashMap::new(),
             active_pokemon_conditions: HashMap::new(),
             last_move: None,
+            last_damage_taken: None,
+            partner: None,
             ante: 200,
+            bag: HashMap::new(),
         };
 
         let player2 = BattlePlayer {
@@ -954,7 +1124,10 @@ mod tests {
             team_conditions: HashMap::new(),
             active_pokemon_conditions: HashMap::new(),
             last_move: None,
+            last_damage_taken: None,
+            partner: None,
             ante: 200,
+            bag: HashMap::new(),
         };
 
         BattleState::new("test_battle".to_string(), player1, player2)
@@ -967,10 +1140,11 @@ mod tests {
 
         let commands = assert_ok(calculate_attack_outcome(
             &state,
-            0,
-            1,
+            PlayerTarget::from_index(0),
+            PlayerTarget::from_index(1),
             Move::Tackle,
             0,
+            false,
             &mut rng,
         ));
 
@@ -1001,10 +1175,11 @@ mod tests {
 
         let commands = assert_ok(calculate_attack_outcome(
             &state,
-            0,
-            1,
+            PlayerTarget::from_index(0),
+            PlayerTarget::from_index(1),
             Move::Tackle,
             0,
+            false,
             &mut rng,
         ));
 
@@ -1031,10 +1206,11 @@ mod tests {
 
         let commands = assert_ok(calculate_attack_outcome(
             &state,
-            0,
-            1,
+            PlayerTarget::from_index(0),
+            PlayerTarget::from_index(1),
             Move::Tackle,
             0,
+            false,
             &mut rng,
         ));
 
@@ -1058,10 +1234,11 @@ mod tests {
 
         let commands = assert_ok(calculate_attack_outcome(
             &state,
-            0,
-            1,
+            PlayerTarget::from_index(0),
+            PlayerTarget::from_index(1),
             Move::Tackle,
             0,
+            false,
             &mut rng,
         ));
 
@@ -1085,10 +1262,11 @@ mod tests {
 
         let commands = assert_ok(calculate_attack_outcome(
             &state,
-            0,
-            1,
+            PlayerTarget::from_index(0),
+            PlayerTarget::from_index(1),
             Move::Tackle,
             0,
+            false,
             &mut rng,
         ));
 
@@ -1126,10 +1304,11 @@ mod tests {
 
         let commands = assert_ok(calculate_attack_outcome(
             &state,
-            0,
-            1,
+            PlayerTarget::from_index(0),
+            PlayerTarget::from_index(1),
             Move::Tackle,
             0,
+            false,
             &mut rng,
         ));
 
@@ -47,6 +47,16 @@ pub fn roll_catch_success(catch_rate: f32, rng: &mut TurnRng) -> bool {
     roll < catch_rate
 }
 
+/// How many times the ball shakes before the Pokemon breaks free (0-3).
+/// Each shake is rolled against the catch rate, so near misses wobble longer.
+pub fn roll_shake_count(catch_rate: f32, rng: &mut TurnRng) -> u8 {
+    let mut shakes = 0;
+    while shakes < 3 && (rng.next_outcome("Catch Shake") as f32) < catch_rate {
+        shakes += 1;
+    }
+    shakes
+}
+
 /// Get a descriptive catch rate category for display purposes
 pub fn get_catch_rate_description(catch_rate: f32) -> &'static str {
     match catch_rate {
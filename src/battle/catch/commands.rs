@@ -1,7 +1,7 @@
 use crate::battle::catch::{
-    calculate_catch_rate, can_attempt_catch, roll_catch_success, CatchError,
+    calculate_catch_rate, can_attempt_catch_at, roll_catch_success, roll_shake_count, CatchError,
 };
-use crate::battle::commands::BattleCommand;
+use crate::battle::commands::{BattleCommand, PlayerTarget};
 use crate::battle::state::{BattleEvent, BattleState, CatchFailureReason, TurnRng};
 use crate::species::Species;
 
@@ -12,11 +12,23 @@ pub fn calculate_catch_commands(
     target_species: Species,
     battle_state: &BattleState,
     rng: &mut TurnRng,
+) -> Vec<BattleCommand> {
+    let target = PlayerTarget::from_index(player_index).opponent();
+    calculate_catch_commands_at(player_index, target, target_species, battle_state, rng)
+}
+
+/// `calculate_catch_commands` aimed at the Pokemon in a given slot of the opposing side.
+pub fn calculate_catch_commands_at(
+    player_index: usize,
+    target: PlayerTarget,
+    target_species: Species,
+    battle_state: &BattleState,
+    rng: &mut TurnRng,
 ) -> Vec<BattleCommand> {
     let mut commands = vec![];
 
     // First, validate the catch attempt
-    match can_attempt_catch(battle_state, player_index) {
+    match can_attempt_catch_at(battle_state, player_index, target) {
         Ok(validated_species) => {
             // Double check that the validated species matches what we're trying to catch
             if validated_species != target_species {
@@ -29,10 +41,7 @@ pub fn calculate_catch_commands(
             }
 
             // Get the target Pokemon for catch rate calculation
-            let opponent_index = 1 - player_index;
-            let opponent = &battle_state.players[opponent_index];
-
-            if let Some(target_pokemon) = opponent.active_pokemon() {
+            if let Some(target_pokemon) = battle_state.battler(target).active_pokemon() {
                 // Calculate catch rate
                 let catch_rate = calculate_catch_rate(target_pokemon, 1.0); // 1.0 for regular Pokeball
 
@@ -45,6 +54,11 @@ pub fn calculate_catch_commands(
 
                 // Roll for success
                 if roll_catch_success(catch_rate, rng) {
+                    // A successful catch always shakes three times before clicking shut
+                    commands.push(BattleCommand::EmitEvent(BattleEvent::BallShook {
+                        player_index,
+                        count: 3,
+                    }));
                     // Success! Add the Pokemon to the player's team
                     commands.push(BattleCommand::AttemptCatch {
                         player_index,
@@ -52,7 +66,14 @@ pub fn calculate_catch_commands(
                     });
                     // Note: BattleEvent::CatchSucceeded is emitted by the command's emit_events()
                 } else {
-                    // Failed catch
+                    // Failed catch: the ball may wobble a few times before the Pokemon breaks out
+                    let shakes = roll_shake_count(catch_rate, rng);
+                    if shakes > 0 {
+                        commands.push(BattleCommand::EmitEvent(BattleEvent::BallShook {
+                            player_index,
+                            count: shakes,
+                        }));
+                    }
                     commands.push(BattleCommand::EmitEvent(BattleEvent::CatchFailed {
                         player_index,
                         pokemon: target_species,
@@ -174,8 +195,8 @@ mod tests {
 
         let commands = calculate_catch_commands(0, Species::Charmander, &battle_state, &mut rng);
 
-        // Should have: CatchAttempted event, then AttemptCatch command
-        assert_eq!(commands.len(), 2);
+        // Should have: CatchAttempted event, three shakes, then AttemptCatch command
+        assert_eq!(commands.len(), 3);
 
         match &commands[0] {
             BattleCommand::EmitEvent(BattleEvent::CatchAttempted {
@@ -189,7 +210,12 @@ mod tests {
             _ => panic!("Expected CatchAttempted event"),
         }
 
-        match &commands[1] {
+        assert!(matches!(
+            &commands[1],
+            BattleCommand::EmitEvent(BattleEvent::BallShook { count: 3, .. })
+        ));
+
+        match &commands[2] {
             BattleCommand::AttemptCatch {
                 player_index,
                 target_pokemon,
@@ -204,7 +230,7 @@ mod tests {
     #[test]
     fn test_catch_commands_failure() {
         let battle_state = create_test_battle_state(BattleType::Wild, 1);
-        let mut rng = TurnRng::new_for_test(vec![255]); // Max roll, should fail
+        let mut rng = TurnRng::new_for_test(vec![255, 100]); // Max roll, should fail; no shake
 
         let commands = calculate_catch_commands(0, Species::Charmander, &battle_state, &mut rng);
 
@@ -223,4 +249,26 @@ mod tests {
             _ => panic!("Expected CatchFailed event"),
         }
     }
+
+    #[test]
+    fn test_catch_commands_break_out_after_one_shake() {
+        let battle_state = create_test_battle_state(BattleType::Wild, 1);
+        // Capture roll fails, the first shake passes, the second does not.
+        let mut rng = TurnRng::new_for_test(vec![100, 1, 100]);
+
+        let commands = calculate_catch_commands(0, Species::Charmander, &battle_state, &mut rng);
+
+        assert_eq!(commands.len(), 3);
+        assert!(matches!(
+            &commands[1],
+            BattleCommand::EmitEvent(BattleEvent::BallShook { count: 1, .. })
+        ));
+        assert!(matches!(
+            &commands[2],
+            BattleCommand::EmitEvent(BattleEvent::CatchFailed {
+                reason: CatchFailureReason::RollFailed { .. },
+                ..
+            })
+        ));
+    }
 }
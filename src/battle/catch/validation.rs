@@ -1,3 +1,4 @@
+use crate::battle::commands::PlayerTarget;
 use crate::battle::state::{BattleState, BattleType};
 use crate::species::Species;
 use serde::{Deserialize, Serialize};
@@ -23,6 +24,16 @@ pub fn is_catch_allowed(battle_type: BattleType) -> bool {
 pub fn can_attempt_catch(
     battle_state: &BattleState,
     player_index: usize,
+) -> Result<Species, CatchError> {
+    let target = PlayerTarget::from_index(player_index).opponent();
+    can_attempt_catch_at(battle_state, player_index, target)
+}
+
+/// `can_attempt_catch` aimed at the Pokemon in a given slot of the opposing side.
+pub fn can_attempt_catch_at(
+    battle_state: &BattleState,
+    player_index: usize,
+    target: PlayerTarget,
 ) -> Result<Species, CatchError> {
     // Check battle type
     if !is_catch_allowed(battle_state.battle_type) {
@@ -38,11 +49,8 @@ pub fn can_attempt_catch(
         return Err(CatchError::TeamFull);
     }
 
-    // Get opponent's active Pokemon
-    let opponent_index = 1 - player_index;
-    let opponent = &battle_state.players[opponent_index];
-
-    match opponent.active_pokemon() {
+    // Get the targeted opposing Pokemon
+    match battle_state.battler(target).active_pokemon() {
         Some(target_pokemon) => {
             if target_pokemon.is_fainted() {
                 Err(CatchError::TargetFainted {
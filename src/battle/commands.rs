@@ -1,9 +1,9 @@
 use crate::battle::action_stack::ActionStack;
 use crate::battle::conditions::{PokemonCondition, PokemonConditionType};
-use crate::battle::state::{BattleEvent, BattleState, EventBus};
-use crate::player::{PlayerAction, StatType, TeamCondition};
-use crate::pokemon::StatusCondition;
-use schema::Move;
+use crate::battle::state::{BattleEvent, BattleFormat, BattleState, EventBus, SlotAction};
+use crate::player::{DamageRecord, PlayerAction, StatType, TeamCondition};
+use crate::pokemon::{MoveInstance, StatusCondition};
+use schema::{Item, Move};
 use serde::{Deserialize, Serialize};
 
 /// Source of fainting for context-aware handling
@@ -14,35 +14,39 @@ pub enum FaintingSource {
     ConditionDamage, // Damage from active conditions (Leech Seed)
 }
 
-/// Player target for commands - provides type safety over raw indices
+/// Which Pokemon on the field a command acts on: a side (the player index) and one of
+/// its active slots. A single battle only has slot 0; a double battle also has slot 1.
+/// Side-wide state (team conditions, the bag, the ante) is addressed through `side`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-pub enum PlayerTarget {
-    Player1,
-    Player2,
+pub struct PlayerTarget {
+    pub side: usize,
+    pub slot: usize,
 }
 
 impl PlayerTarget {
+    pub fn new(side: usize, slot: usize) -> Self {
+        Self { side, slot }
+    }
+
+    /// The player index of this target's side.
     pub fn to_index(self) -> usize {
-        match self {
-            PlayerTarget::Player1 => 0,
-            PlayerTarget::Player2 => 1,
-        }
+        self.side
     }
 
-    #[allow(dead_code)]
+    /// The Pokemon facing this one: the same slot on the other side.
     pub fn opponent(self) -> PlayerTarget {
-        match self {
-            PlayerTarget::Player1 => PlayerTarget::Player2,
-            PlayerTarget::Player2 => PlayerTarget::Player1,
-        }
+        PlayerTarget::new(1 - self.side, self.slot)
+    }
+
+    /// The other slot on the same side.
+    pub fn ally(self) -> PlayerTarget {
+        PlayerTarget::new(self.side, 1 - self.slot)
     }
 
+    /// Slot 0 of player `index`, the only slot in a single battle.
     pub fn from_index(index: usize) -> PlayerTarget {
-        match index {
-            0 => PlayerTarget::Player1,
-            1 => PlayerTarget::Player2,
-            _ => panic!("Invalid player index: {}", index),
-        }
+        assert!(index < 2, "Invalid player index: {}", index);
+        PlayerTarget::new(index, 0)
     }
 }
 
@@ -59,6 +63,10 @@ pub enum BattleCommand {
         target: PlayerTarget,
         amount: u16,
     },
+    ConsumeHeldItem {
+        target: PlayerTarget,
+        item: Item,
+    },
     SetPokemonStatus {
         target: PlayerTarget,
         status: StatusCondition,
@@ -71,6 +79,11 @@ pub enum BattleCommand {
         target: PlayerTarget,
         move_used: Move,
     },
+    /// Swap out the active Pokemon's whole moveset, e.g. for Transform's copies.
+    ReplaceMoveset {
+        target: PlayerTarget,
+        moves: [Option<MoveInstance>; 4],
+    },
     DealStatusDamage {
         target: PlayerTarget,
         status: StatusCondition,
@@ -92,6 +105,9 @@ pub enum BattleCommand {
         target: PlayerTarget,
         condition: PokemonCondition,
     },
+    ResetToxicCounter {
+        target: PlayerTarget,
+    },
 
     // === PLAYER COMMANDS ===
     // Commands that modify player/team state - minimal data packets
@@ -133,6 +149,10 @@ pub enum BattleCommand {
         target: PlayerTarget,
         amount: u32,
     },
+    AwardPrizeMoney {
+        target: PlayerTarget,
+        amount: u32,
+    },
     SetLastMove {
         target: PlayerTarget,
         move_used: Move,
@@ -140,10 +160,16 @@ pub enum BattleCommand {
     ClearPlayerState {
         target: PlayerTarget,
     },
+    SetLastDamageTaken {
+        target: PlayerTarget,
+        record: Option<DamageRecord>,
+    },
     AttemptCatch {
         player_index: usize,
         target_pokemon: crate::species::Species,
     },
+    /// Put an action in the target's queue slot: a forced move, or an action held
+    /// through a replacement phase.
     QueueForcedAction {
         target: PlayerTarget,
         action: PlayerAction,
@@ -158,6 +184,14 @@ pub enum BattleCommand {
     HandleFainted {
         target: PlayerTarget,
     },
+    /// Note that the target's active Pokemon was put to sleep by the opponent.
+    RecordInducedSleep {
+        target: PlayerTarget,
+    },
+    SetActedThisTurn {
+        target: PlayerTarget,
+        acted: bool,
+    },
     PushAction(crate::battle::action_stack::BattleAction),
 
     // === PROGRESSION COMMANDS ===
@@ -213,9 +247,8 @@ impl BattleCommand {
                 amount,
             } => emit_damage_events(*target, *amount, state, None, Some(condition.clone())),
             BattleCommand::HealPokemon { target, amount } => {
-                let player_index = target.to_index();
-                let player = &state.players[player_index];
-                if let Some(pokemon) = player.team[player.active_pokemon_index].as_ref() {
+                let battler = state.battler(*target);
+                if let Some(pokemon) = battler.active_pokemon() {
                     if *amount > 0 {
                         vec![BattleEvent::PokemonHealed {
                             target: pokemon.species,
@@ -230,9 +263,8 @@ impl BattleCommand {
                 }
             }
             BattleCommand::SetPokemonStatus { target, status } => {
-                let player_index = target.to_index();
-                let player = &state.players[player_index];
-                if let Some(pokemon) = player.team[player.active_pokemon_index].as_ref() {
+                let battler = state.battler(*target);
+                if let Some(pokemon) = battler.active_pokemon() {
                     // Don't emit status application events for fainted Pokemon
                     if pokemon.is_fainted() {
                         vec![]
@@ -247,9 +279,8 @@ impl BattleCommand {
                 }
             }
             BattleCommand::CurePokemonStatus { target, status } => {
-                let player_index = target.to_index();
-                let player = &state.players[player_index];
-                if let Some(pokemon) = player.team[player.active_pokemon_index].as_ref() {
+                let battler = state.battler(*target);
+                if let Some(pokemon) = battler.active_pokemon() {
                     vec![BattleEvent::PokemonStatusRemoved {
                         target: pokemon.species,
                         status: *status,
@@ -258,8 +289,8 @@ impl BattleCommand {
                     vec![]
                 }
             }
-            BattleCommand::UsePP { .. } => {
-                // PP usage is silent - no events emitted
+            BattleCommand::UsePP { .. } | BattleCommand::ReplaceMoveset { .. } => {
+                // PP usage and moveset swaps are silent - no events emitted
                 vec![]
             }
             BattleCommand::ModifyStatStage {
@@ -267,10 +298,9 @@ impl BattleCommand {
                 stat,
                 delta,
             } => {
-                let player_index = target.to_index();
-                let player = &state.players[player_index];
-                if let Some(pokemon) = player.team[player.active_pokemon_index].as_ref() {
-                    let new_stage = player.get_stat_stage(*stat);
+                let battler = state.battler(*target);
+                if let Some(pokemon) = battler.active_pokemon() {
+                    let new_stage = battler.get_stat_stage(*stat);
                     vec![BattleEvent::StatStageChanged {
                         target: pokemon.species,
                         stat: *stat,
@@ -282,9 +312,8 @@ impl BattleCommand {
                 }
             }
             BattleCommand::AddCondition { target, condition } => {
-                let player_index = target.to_index();
-                let player = &state.players[player_index];
-                if let Some(pokemon) = player.team[player.active_pokemon_index].as_ref() {
+                let battler = state.battler(*target);
+                if let Some(pokemon) = battler.active_pokemon() {
                     // Don't emit condition application events for fainted Pokemon
                     if pokemon.is_fainted() {
                         vec![]
@@ -302,13 +331,10 @@ impl BattleCommand {
                 target,
                 condition_type,
             } => {
-                let player_index = target.to_index();
-                let player = &state.players[player_index];
-                if let Some(pokemon) = player.team[player.active_pokemon_index].as_ref() {
+                let battler = state.battler(*target);
+                if let Some(pokemon) = battler.active_pokemon() {
                     // Find the actual condition being removed
-                    if let Some(actual_condition) =
-                        player.active_pokemon_conditions.get(condition_type)
-                    {
+                    if let Some(actual_condition) = battler.conditions().get(condition_type) {
                         vec![BattleEvent::StatusRemoved {
                             target: pokemon.species,
                             status: actual_condition.clone(),
@@ -321,9 +347,8 @@ impl BattleCommand {
                 }
             }
             BattleCommand::RemoveSpecificCondition { target, condition } => {
-                let player_index = target.to_index();
-                let player = &state.players[player_index];
-                if let Some(pokemon) = player.team[player.active_pokemon_index].as_ref() {
+                let battler = state.battler(*target);
+                if let Some(pokemon) = battler.active_pokemon() {
                     vec![BattleEvent::StatusRemoved {
                         target: pokemon.species,
                         status: condition.clone(),
@@ -374,9 +399,8 @@ impl BattleCommand {
                 vec![]
             }
             BattleCommand::ExpirePokemonCondition { target, condition } => {
-                let player_index = target.to_index();
-                let player = &state.players[player_index];
-                if let Some(pokemon) = player.team[player.active_pokemon_index].as_ref() {
+                let battler = state.battler(*target);
+                if let Some(pokemon) = battler.active_pokemon() {
                     vec![BattleEvent::ConditionExpired {
                         target: pokemon.species,
                         condition: condition.clone(),
@@ -414,17 +438,35 @@ impl BattleCommand {
                     new_total: player.get_ante(),
                 }]
             }
+            BattleCommand::ConsumeHeldItem { target, item } => {
+                match state.battler(*target).active_pokemon() {
+                    Some(pokemon) => vec![BattleEvent::HeldItemConsumed {
+                        target: pokemon.species,
+                        item: *item,
+                    }],
+                    None => vec![],
+                }
+            }
+            BattleCommand::AwardPrizeMoney { target, amount } => {
+                vec![BattleEvent::PrizeMoneyAwarded {
+                    player_index: target.to_index(),
+                    amount: *amount,
+                }]
+            }
             BattleCommand::SetGameState(_)
             | BattleCommand::IncrementTurnNumber
             | BattleCommand::ClearActionQueue
             | BattleCommand::SetLastMove { .. }
             | BattleCommand::ClearPlayerState { .. }
+            | BattleCommand::SetLastDamageTaken { .. }
+            | BattleCommand::RecordInducedSleep { .. }
+            | BattleCommand::SetActedThisTurn { .. }
+            | BattleCommand::ResetToxicCounter { .. }
             | BattleCommand::PushAction(_) => vec![],
             BattleCommand::HandleFainted { target } => {
-                let player_index = target.to_index();
-                let pokemon = state.players[player_index].active_pokemon();
+                let pokemon = state.battler(*target).active_pokemon();
                 vec![BattleEvent::PokemonFainted {
-                    player_index: player_index,
+                    player_index: target.to_index(),
                     pokemon: pokemon.unwrap().species,
                 }]
             }
@@ -529,7 +571,11 @@ impl BattleCommand {
     }
 }
 
-/// Centralized function to emit damage events and check for fainting.
+/// Centralized function to emit damage events.
+///
+/// Fainting is never reported here. A damage command that knocks a Pokemon out
+/// returns `HandleFainted` as a follow-up, and follow-ups only run after the command's
+/// own events are on the bus, so damage always comes before `PokemonFainted`.
 fn emit_damage_events(
     target: PlayerTarget,
     amount: u16,
@@ -537,9 +583,7 @@ fn emit_damage_events(
     status: Option<StatusCondition>,
     condition: Option<PokemonCondition>,
 ) -> Vec<BattleEvent> {
-    let player_index = target.to_index();
-    let player = &state.players[player_index];
-    if let Some(pokemon) = player.active_pokemon() {
+    if let Some(pokemon) = state.battler(target).active_pokemon() {
         let mut events = Vec::new();
 
         // 1. Create the appropriate primary damage event.
@@ -568,25 +612,38 @@ fn emit_damage_events(
     vec![]
 }
 
-/// Execute a batch of commands atomically
+/// Execute commands in order, running any follow-up commands a command produces
+/// before moving on to the rest of the batch.
+///
+/// The pending stack lives in `state.command_stack`, so its allocation is reused from
+/// one batch to the next rather than building (and reversing) a fresh vector each time.
+/// A batch started from inside another simply gets an empty buffer of its own.
 pub fn execute_command_batch(
     commands: Vec<BattleCommand>,
     state: &mut BattleState,
     bus: &mut EventBus,
     action_stack: &mut ActionStack,
 ) -> Result<(), ExecutionError> {
-    let mut pending_commands = commands;
-    pending_commands.reverse(); // Reverse for LIFO processing order
+    let mut pending_commands = std::mem::take(&mut state.command_stack);
+    // It is a stack, so commands go on in reverse for LIFO processing order.
+    pending_commands.extend(commands.into_iter().rev());
+
+    let mut result = Ok(());
     while let Some(command) = pending_commands.pop() {
-        let mut additional_commands = execute_command(command, state, bus, action_stack)?;
-        // Reverse any new commands before pushing them onto the stack.
-        additional_commands.reverse();
-        // Add new commands to the end for LIFO processing order
-        // It is a stack.
-        pending_commands.extend(additional_commands);
+        match execute_command(command, state, bus, action_stack) {
+            Ok(additional_commands) => {
+                pending_commands.extend(additional_commands.into_iter().rev());
+            }
+            Err(error) => {
+                result = Err(error);
+                break;
+            }
+        }
     }
 
-    Ok(())
+    pending_commands.clear();
+    state.command_stack = pending_commands;
+    result
 }
 
 /// Helper function to execute commands that operate on the active Pokemon
@@ -598,10 +655,8 @@ fn execute_pokemon_command<F>(
 where
     F: FnOnce(&mut crate::pokemon::PokemonInst, usize) -> Result<(), ExecutionError>,
 {
-    let player_index = target.to_index();
-    let player = &mut state.players[player_index];
-    if let Some(pokemon) = player.team[player.active_pokemon_index].as_mut() {
-        operation(pokemon, player_index)?;
+    if let Some(pokemon) = state.battler_mut(target).active_pokemon_mut() {
+        operation(pokemon, target.to_index())?;
         Ok(vec![])
     } else {
         Err(ExecutionError::NoPokemon)
@@ -614,9 +669,7 @@ fn execute_deal_damage_command(
     amount: u16,
     state: &mut BattleState,
 ) -> Result<Vec<BattleCommand>, ExecutionError> {
-    let player_index = target.to_index();
-    let player = &mut state.players[player_index];
-    if let Some(pokemon) = player.team[player.active_pokemon_index].as_mut() {
+    if let Some(pokemon) = state.battler_mut(target).active_pokemon_mut() {
         let did_faint = pokemon.take_damage(amount);
         if did_faint {
             Ok(vec![BattleCommand::HandleFainted { target }])
@@ -640,8 +693,16 @@ pub fn execute_command(
         return Ok(vec![]);
     }
 
+    // A faint is processed once, however many paths report it.
+    if let BattleCommand::HandleFainted { target } = &command {
+        if state.is_faint_handled(*target) {
+            return Ok(vec![]);
+        }
+    }
+
     // Execute the state change
     let additional_commands = execute_state_change(&command, state, action_stack)?;
+    state.forget_ended_induced_sleep();
 
     // Auto-emit events
     for event in command.emit_events(state) {
@@ -664,14 +725,17 @@ fn execute_state_change(
             unreachable!("EmitEvent should be handled before execute_state_change")
         }
         BattleCommand::HandleFainted { target } => {
+            state.set_faint_handled(*target, true);
+            if let Some(fainted_pokemon) = state.battler_mut(*target).active_pokemon_mut() {
+                fainted_pokemon.times_fainted += 1;
+            }
             let mut commands = vec![];
 
             // Clear conditions for fainted Pokemon
             commands.push(BattleCommand::ClearPlayerState { target: *target });
 
             // Calculate progression rewards
-            let player_index = target.to_index();
-            if let Some(fainted_pokemon) = state.players[player_index].active_pokemon() {
+            if let Some(fainted_pokemon) = state.battler(*target).active_pokemon() {
                 let fainted_species = fainted_pokemon.species;
 
                 let progression_commands =
@@ -694,6 +758,12 @@ fn execute_state_change(
                 Ok(())
             })
         }
+        BattleCommand::ConsumeHeldItem { target, .. } => {
+            return execute_pokemon_command(*target, state, |pokemon, _| {
+                pokemon.held_item = None;
+                Ok(())
+            })
+        }
         BattleCommand::SetPokemonStatus { target, status } => {
             return execute_pokemon_command(*target, state, |pokemon, _| {
                 // Don't apply status to Pokemon that already have a status
@@ -711,6 +781,14 @@ fn execute_state_change(
                 Ok(())
             })
         }
+        BattleCommand::ResetToxicCounter { target } => {
+            return execute_pokemon_command(*target, state, |pokemon, _| {
+                if let Some(StatusCondition::Poison(severity)) = pokemon.status.as_mut() {
+                    *severity = 0;
+                }
+                Ok(())
+            });
+        }
         BattleCommand::UsePP { target, move_used } => {
             return execute_pokemon_command(*target, state, |pokemon, _| {
                 pokemon
@@ -718,40 +796,40 @@ fn execute_state_change(
                     .map_err(|_| ExecutionError::NoPokemon)
             })
         }
+        BattleCommand::ReplaceMoveset { target, moves } => {
+            return execute_pokemon_command(*target, state, |pokemon, _| {
+                pokemon.moves = moves.clone();
+                Ok(())
+            });
+        }
         BattleCommand::ModifyStatStage {
             target,
             stat,
             delta,
         } => {
-            let player_index = target.to_index();
-            let player = &mut state.players[player_index];
-            let current_stage = player.get_stat_stage(*stat);
-            let new_stage = (current_stage + delta).clamp(-6, 6);
-            player.set_stat_stage(*stat, new_stage);
+            state.battler_mut(*target).adjust_stat_stage(*stat, *delta);
         }
         BattleCommand::AddCondition { target, condition } => {
-            let player_index = target.to_index();
-            let player = &mut state.players[player_index];
+            let mut battler = state.battler_mut(*target);
             // Don't apply conditions to fainted Pokemon
-            if let Some(pokemon) = player.active_pokemon() {
-                if !pokemon.is_fainted() {
-                    player.add_condition(condition.clone());
-                }
+            let standing = battler
+                .as_battler()
+                .active_pokemon()
+                .is_some_and(|pokemon| !pokemon.is_fainted());
+            if standing {
+                battler.add_condition(condition.clone());
             }
         }
         BattleCommand::RemoveCondition {
             target,
             condition_type,
         } => {
-            let player_index = target.to_index();
-            let player = &mut state.players[player_index];
-            player.active_pokemon_conditions.remove(condition_type);
+            state.battler_mut(*target).cure_condition(*condition_type);
         }
         BattleCommand::RemoveSpecificCondition { target, condition } => {
-            let player_index = target.to_index();
-            let player = &mut state.players[player_index];
-            player
-                .active_pokemon_conditions
+            state
+                .battler_mut(*target)
+                .conditions_mut()
                 .remove(&condition.get_type());
         }
         BattleCommand::AddTeamCondition {
@@ -764,9 +842,10 @@ fn execute_state_change(
             player.add_team_condition(*condition, *turns);
         }
         BattleCommand::SetLastMove { target, move_used } => {
-            let player_index = target.to_index();
-            let player = &mut state.players[player_index];
-            player.last_move = Some(*move_used);
+            state.battler_mut(*target).set_last_move(Some(*move_used));
+        }
+        BattleCommand::SetLastDamageTaken { target, record } => {
+            state.battler_mut(*target).set_last_damage_taken(*record);
         }
         BattleCommand::SwitchPokemon {
             target,
@@ -782,16 +861,14 @@ fn execute_state_change(
             }
 
             // --- State Mutation ---
-            state.players[player_index].active_pokemon_index = *new_pokemon_index;
-
-            // --- Determine Final Field State & Record Participation ---
-            // Determine the final indices for P0 and P1
-            let p0_active_index = state.players[0].active_pokemon_index;
-            let p1_active_index = state.players[1].active_pokemon_index;
-            // Record participation in the tracker
             state
-                .participation_tracker
-                .record_participation(p0_active_index, p1_active_index);
+                .battler_mut(*target)
+                .set_pokemon_index(*new_pokemon_index);
+            state.set_faint_handled(*target, false);
+
+            // --- Record Participation ---
+            // Everyone now on the field has faced everyone on the other side.
+            state.record_field_participation();
 
             // --- Final Return ---
             return Ok(vec![]);
@@ -804,22 +881,37 @@ fn execute_state_change(
             let player_index = target.to_index();
             state.players[player_index].add_ante(*amount);
         }
+        BattleCommand::AwardPrizeMoney { target, amount } => {
+            // Prize money goes into the same pot as Pay Day coins.
+            let player_index = target.to_index();
+            state.players[player_index].add_ante(*amount);
+        }
         BattleCommand::SetGameState(new_state) => {
+            let was_over = state.is_over();
             state.game_state = *new_state;
+            if !was_over && state.is_over() {
+                state.record_participation_totals();
+            }
         }
         BattleCommand::IncrementTurnNumber => {
             state.turn_number += 1;
         }
+        BattleCommand::RecordInducedSleep { target } => {
+            state.opponent_induced_sleep[target.to_index()] =
+                Some(state.battler(*target).pokemon_index());
+        }
+        BattleCommand::SetActedThisTurn { target, acted } => {
+            state.set_acted_this_turn(*target, *acted);
+        }
         BattleCommand::ClearActionQueue => {
             state.action_queue = [None, None];
+            state.double_action_queue = Default::default();
         }
         BattleCommand::PushAction(action) => {
             action_stack.push_front(action.clone());
         }
         BattleCommand::ClearPlayerState { target } => {
-            let player_index = target.to_index();
-            let player = &mut state.players[player_index];
-            player.clear_active_pokemon_state();
+            state.battler_mut(*target).clear_state();
         }
         BattleCommand::DealStatusDamage {
             target,
@@ -841,13 +933,11 @@ fn execute_state_change(
             });
         }
         BattleCommand::TickPokemonCondition { target, condition } => {
-            let player_index = target.to_index();
-            let player = &mut state.players[player_index];
+            let mut battler = state.battler_mut(*target);
 
             // Apply tick effect for this specific condition
-            if let Some(existing_condition) = player
-                .active_pokemon_conditions
-                .get_mut(&condition.get_type())
+            if let Some(existing_condition) =
+                battler.conditions_mut().get_mut(&condition.get_type())
             {
                 // Apply condition-specific tick behavior
                 match existing_condition {
@@ -860,6 +950,14 @@ fn execute_state_change(
                     crate::battle::conditions::PokemonCondition::Trapped { turns_remaining } => {
                         *turns_remaining = turns_remaining.saturating_sub(1);
                     }
+                    crate::battle::conditions::PokemonCondition::SwitchLocked {
+                        turns_remaining,
+                    } => {
+                        *turns_remaining = turns_remaining.saturating_sub(1);
+                    }
+                    crate::battle::conditions::PokemonCondition::Trapping { turns_remaining } => {
+                        *turns_remaining = turns_remaining.saturating_sub(1);
+                    }
                     crate::battle::conditions::PokemonCondition::Rampaging { turns_remaining } => {
                         *turns_remaining = turns_remaining.saturating_sub(1);
                     }
@@ -869,6 +967,12 @@ fn execute_state_change(
                     } => {
                         *turns_remaining = turns_remaining.saturating_sub(1);
                     }
+                    crate::battle::conditions::PokemonCondition::Cooldown {
+                        turns_remaining,
+                        ..
+                    } => {
+                        *turns_remaining = turns_remaining.saturating_sub(1);
+                    }
                     crate::battle::conditions::PokemonCondition::Biding {
                         turns_remaining, ..
                     } => {
@@ -879,10 +983,9 @@ fn execute_state_change(
             }
         }
         BattleCommand::ExpirePokemonCondition { target, condition } => {
-            let player_index = target.to_index();
-            let player = &mut state.players[player_index];
-            player
-                .active_pokemon_conditions
+            state
+                .battler_mut(*target)
+                .conditions_mut()
                 .remove(&condition.get_type());
         }
         BattleCommand::TickTeamCondition { target, condition } => {
@@ -899,10 +1002,17 @@ fn execute_state_change(
             let player = &mut state.players[player_index];
             player.team_conditions.remove(condition);
         }
-        BattleCommand::QueueForcedAction { target, action } => {
-            let player_index = target.to_index();
-            state.action_queue[player_index] = Some(action.clone());
-        }
+        BattleCommand::QueueForcedAction { target, action } => match state.format {
+            BattleFormat::Single => {
+                state.action_queue[target.to_index()] = Some(action.clone());
+            }
+            BattleFormat::Double => {
+                state.double_action_queue[target.side][target.slot] = Some(SlotAction {
+                    action: action.clone(),
+                    target: None,
+                });
+            }
+        },
         BattleCommand::AwardExperience { recipients } => {
             return crate::battle::progression::execute_award_experience(recipients, state);
         }
@@ -1062,7 +1172,10 @@ mod tests {
             team_conditions: HashMap::new(),
             active_pokemon_conditions: HashMap::new(),
             last_move: None,
+            last_damage_taken: None,
+            partner: None,
             ante: 200,
+            bag: HashMap::new(),
         };
 
         let player2 = BattlePlayer {
@@ -1082,7 +1195,10 @@ mod tests {
             team_conditions: HashMap::new(),
             active_pokemon_conditions: HashMap::new(),
             last_move: None,
+            last_damage_taken: None,
+            partner: None,
             ante: 200,
+            bag: HashMap::new(),
         };
 
         BattleState::new("test_battle".to_string(), player1, player2)
@@ -1090,12 +1206,11 @@ mod tests {
 
     #[test]
     fn test_player_target_conversion() {
-        assert_eq!(PlayerTarget::Player1.to_index(), 0);
-        assert_eq!(PlayerTarget::Player2.to_index(), 1);
-        assert_eq!(PlayerTarget::from_index(0), PlayerTarget::Player1);
-        assert_eq!(PlayerTarget::from_index(1), PlayerTarget::Player2);
-        assert_eq!(PlayerTarget::Player1.opponent(), PlayerTarget::Player2);
-        assert_eq!(PlayerTarget::Player2.opponent(), PlayerTarget::Player1);
+        assert_eq!(PlayerTarget::from_index(0), PlayerTarget::new(0, 0));
+        assert_eq!(PlayerTarget::from_index(1), PlayerTarget::new(1, 0));
+        assert_eq!(PlayerTarget::new(1, 1).to_index(), 1);
+        assert_eq!(PlayerTarget::new(0, 1).opponent(), PlayerTarget::new(1, 1));
+        assert_eq!(PlayerTarget::new(1, 0).ally(), PlayerTarget::new(1, 1));
     }
 
     #[test]
@@ -1108,7 +1223,7 @@ mod tests {
 
         let result = execute_command_batch(
             vec![BattleCommand::DealDamage {
-                target: PlayerTarget::Player1,
+                target: PlayerTarget::from_index(0),
                 amount: 20,
             }],
             &mut state,
@@ -1132,7 +1247,7 @@ mod tests {
         // First damage the Pokemon
         execute_command_batch(
             vec![BattleCommand::DealDamage {
-                target: PlayerTarget::Player1,
+                target: PlayerTarget::from_index(0),
                 amount: 30,
             }],
             &mut state,
@@ -1146,7 +1261,7 @@ mod tests {
         // Then heal it
         let result = execute_command_batch(
             vec![BattleCommand::HealPokemon {
-                target: PlayerTarget::Player1,
+                target: PlayerTarget::from_index(0),
                 amount: 10,
             }],
             &mut state,
@@ -1192,7 +1307,7 @@ mod tests {
 
         let result = execute_command_batch(
             vec![BattleCommand::ModifyStatStage {
-                target: PlayerTarget::Player1,
+                target: PlayerTarget::from_index(0),
                 stat: StatType::Atk,
                 delta: 2,
             }],
@@ -1221,4 +1336,50 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(state.game_state, GameState::TurnInProgress);
     }
+
+    #[test]
+    fn test_command_batch_runs_follow_ups_first_and_reuses_buffer() {
+        let mut state = create_test_battle_state();
+        let mut bus = EventBus::new();
+        let mut action_stack = ActionStack::new();
+
+        // The faint's follow-up commands must all run before the trailing event.
+        execute_command_batch(
+            vec![
+                BattleCommand::DealDamage {
+                    target: PlayerTarget::from_index(1),
+                    amount: 1000,
+                },
+                BattleCommand::EmitEvent(BattleEvent::TurnStarted { turn_number: 2 }),
+            ],
+            &mut state,
+            &mut bus,
+            &mut action_stack,
+        )
+        .unwrap();
+
+        let events = bus.events();
+        assert!(matches!(events[0], BattleEvent::DamageDealt { .. }));
+        assert!(matches!(events[1], BattleEvent::PokemonFainted { .. }));
+        assert!(matches!(
+            events.last(),
+            Some(BattleEvent::TurnStarted { turn_number: 2 })
+        ));
+
+        // The stack is left empty but keeps its capacity for the next batch.
+        assert!(state.command_stack.is_empty());
+        let capacity = state.command_stack.capacity();
+        assert!(capacity > 0);
+
+        execute_command_batch(
+            vec![BattleCommand::EmitEvent(BattleEvent::TurnStarted {
+                turn_number: 3,
+            })],
+            &mut state,
+            &mut bus,
+            &mut action_stack,
+        )
+        .unwrap();
+        assert_eq!(state.command_stack.capacity(), capacity);
+    }
 }
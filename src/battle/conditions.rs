@@ -4,10 +4,14 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     battle::commands::{BattleCommand, PlayerTarget},
-    player::StatType,
-    pokemon::PokemonInst,
+    player::{Battler, DamageRecord, StatType},
+    pokemon::{MoveInstance, PokemonInst},
 };
-use schema::{Move, MoveCategory, PokemonType};
+use schema::{Move, PokemonType};
+
+/// PP given to each move a transformed Pokemon copies from its target.
+pub const TRANSFORM_MOVE_PP: u8 = 5;
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum PokemonCondition {
     Flinched,
@@ -24,13 +28,23 @@ pub enum PokemonCondition {
     }, // Prevents acting for specified turns
     Trapped {
         turns_remaining: u8,
-    },
+    }, // Partial trapping: blocks switching, loses the turn and takes chip damage
+    SwitchLocked {
+        turns_remaining: u8,
+    }, // Blocks switching only, with no damage
+    Trapping {
+        turns_remaining: u8,
+    }, // The partial trapper's side of the lock: repeats its move and can't switch
     Charging,
     Rampaging {
         turns_remaining: u8,
     },
     Transformed {
         target: PokemonInst,
+        /// The user's own moveset, restored when the transformation ends. Empty for
+        /// conditions saved before Transform copied moves.
+        #[serde(default)]
+        original_moves: [Option<MoveInstance>; 4],
     },
     Converted {
         pokemon_type: PokemonType,
@@ -39,6 +53,10 @@ pub enum PokemonCondition {
         pokemon_move: Move,
         turns_remaining: u8,
     }, // Counts down each turn
+    Cooldown {
+        pokemon_move: Move,
+        turns_remaining: u8,
+    }, // The user can't reuse this move until it counts down
     Substitute {
         hp: u8,
     },
@@ -46,9 +64,6 @@ pub enum PokemonCondition {
         turns_remaining: u8,
         damage: u16,
     },
-    Countering {
-        damage: u16,
-    },
 }
 
 /// Condition type without data payload for RemoveCondition commands
@@ -63,14 +78,16 @@ pub enum PokemonConditionType {
     Enraged,
     Exhausted,
     Trapped,
+    SwitchLocked,
+    Trapping,
     Charging,
     Rampaging,
     Transformed,
     Converted,
     Biding,
-    Countering,
     Substitute,
     Disabled,
+    Cooldown,
 }
 
 impl fmt::Display for PokemonConditionType {
@@ -85,14 +102,16 @@ impl fmt::Display for PokemonConditionType {
             PokemonConditionType::Enraged => "Enraged",
             PokemonConditionType::Exhausted => "Exhausted",
             PokemonConditionType::Trapped => "Trapped",
+            PokemonConditionType::SwitchLocked => "Switch Locked",
+            PokemonConditionType::Trapping => "Trapping",
             PokemonConditionType::Charging => "Charging Attack",
             PokemonConditionType::Rampaging => "Rampaging",
             PokemonConditionType::Transformed => "Transformed",
             PokemonConditionType::Converted => "Converted",
             PokemonConditionType::Biding => "Biding",
-            PokemonConditionType::Countering => "Countering",
             PokemonConditionType::Substitute => "Substitute",
             PokemonConditionType::Disabled => "Disabled",
+            PokemonConditionType::Cooldown => "Cooling Down",
         };
 
         write!(f, "{}", display_name)
@@ -111,44 +130,64 @@ impl PokemonCondition {
             PokemonCondition::Enraged => PokemonConditionType::Enraged,
             PokemonCondition::Exhausted { .. } => PokemonConditionType::Exhausted,
             PokemonCondition::Trapped { .. } => PokemonConditionType::Trapped,
+            PokemonCondition::SwitchLocked { .. } => PokemonConditionType::SwitchLocked,
+            PokemonCondition::Trapping { .. } => PokemonConditionType::Trapping,
             PokemonCondition::Charging => PokemonConditionType::Charging,
             PokemonCondition::Rampaging { .. } => PokemonConditionType::Rampaging,
             PokemonCondition::Transformed { .. } => PokemonConditionType::Transformed,
             PokemonCondition::Converted { .. } => PokemonConditionType::Converted,
             PokemonCondition::Biding { .. } => PokemonConditionType::Biding,
-            PokemonCondition::Countering { .. } => PokemonConditionType::Countering,
             PokemonCondition::Substitute { .. } => PokemonConditionType::Substitute,
             PokemonCondition::Disabled { .. } => PokemonConditionType::Disabled,
+            PokemonCondition::Cooldown { .. } => PokemonConditionType::Cooldown,
+        }
+    }
+
+    /// Whether this condition can be inflicted on the target Pokemon.
+    ///
+    /// This is the single place for condition immunities: a substitute blocks
+    /// conditions inflicted by the opponent, Grass types can't be seeded, and a
+    /// condition that is already present can't be stacked.
+    pub fn can_apply_to(&self, target_pokemon: &PokemonInst, target: Battler) -> bool {
+        let inflicted_by_opponent = matches!(
+            self,
+            PokemonCondition::Flinched
+                | PokemonCondition::Confused { .. }
+                | PokemonCondition::Seeded
+                | PokemonCondition::Trapped { .. }
+        );
+        if inflicted_by_opponent && target.has_condition_type(PokemonConditionType::Substitute) {
+            return false;
+        }
+
+        match self {
+            PokemonCondition::Seeded => {
+                !target_pokemon
+                    .get_current_types(target)
+                    .contains(&PokemonType::Grass)
+                    && !target.has_condition_type(PokemonConditionType::Seeded)
+            }
+            PokemonCondition::Confused { .. } => {
+                !target.has_condition_type(PokemonConditionType::Confused)
+            }
+            PokemonCondition::Trapped { .. } => {
+                !target.has_condition_type(PokemonConditionType::Trapped)
+            }
+            _ => true,
         }
     }
 
     /// Handle reactions when this condition's pokemon takes damage
     pub fn on_damage_taken(
         &self,
-        damage: u16,
-        attacker_target: PlayerTarget,
+        hit: &DamageRecord,
         defender_target: PlayerTarget,
         _defender_pokemon_species: crate::species::Species,
-        move_category: MoveCategory,
-        defender_current_hp: u16,
         defender_stat_stage: i8,
     ) -> Vec<BattleCommand> {
         let mut commands = Vec::new();
 
         match self {
-            // Counter: Retaliate with 2x physical damage if defender survives
-            PokemonCondition::Countering { .. } => {
-                let defender_will_faint = damage >= defender_current_hp;
-
-                if matches!(move_category, MoveCategory::Physical) && !defender_will_faint {
-                    let counter_damage = damage * 2;
-                    commands.push(BattleCommand::DealDamage {
-                        target: attacker_target,
-                        amount: counter_damage,
-                    });
-                }
-            }
-
             // Bide: Accumulate damage for future release
             PokemonCondition::Biding {
                 turns_remaining,
@@ -164,7 +203,7 @@ impl PokemonCondition {
                     target: defender_target,
                     condition: PokemonCondition::Biding {
                         turns_remaining: *turns_remaining,
-                        damage: stored_damage + damage,
+                        damage: stored_damage + hit.damage,
                     },
                 });
             }
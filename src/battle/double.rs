@@ -0,0 +1,473 @@
+//! Double battles: two active Pokémon per side.
+//!
+//! Each side keeps its second Pokémon in `BattlePlayer::partner`, and every Pokémon on
+//! the field is addressed by its `PlayerTarget` (side and slot). The move, damage and
+//! condition machinery is shared with single battles; this module orders the four
+//! choices, works out who each move hits and drives the turn.
+
+use crate::battle::action_stack::{ActionStack, BattleAction};
+use crate::battle::ai::ScoringAI;
+use crate::battle::calculators::{
+    calculate_attack_outcome, calculate_end_turn_commands, calculate_forced_action_commands,
+    sweep_faints,
+};
+use crate::battle::catch::calculate_catch_commands_at;
+use crate::battle::commands::{execute_command_batch, BattleCommand, PlayerTarget};
+use crate::battle::engine::{
+    begin_move, check_turn_limit, check_win_conditions, execute_battle_action, execute_switch,
+    initialize_turn,
+};
+use crate::battle::move_effects::BattleMoveDataExt;
+use crate::battle::state::{
+    ActionFailureReason, BattleEvent, BattleState, EventBus, GameState, SlotAction, TurnRng,
+};
+use crate::move_data::get_move_data;
+use crate::player::{PlayerAction, PlayerType};
+use schema::{Move, MoveRange, Target};
+
+/// Whether a slot holds a Pokémon that can still fight.
+fn is_standing(state: &BattleState, slot: PlayerTarget) -> bool {
+    state
+        .battler(slot)
+        .active_pokemon()
+        .is_some_and(|pokemon| !pokemon.is_fainted())
+}
+
+/// The slots still in the fight on `side`, in slot order.
+fn standing_slots(state: &BattleState, side: usize) -> Vec<PlayerTarget> {
+    (0..2)
+        .map(|slot| PlayerTarget::new(side, slot))
+        .filter(|&slot| is_standing(state, slot))
+        .collect()
+}
+
+/// Who a move from `attacker` hits. A single-target move goes where it was aimed, or
+/// to the first foe still standing if it was aimed nowhere or at a foe that has fallen.
+fn move_targets(
+    state: &BattleState,
+    attacker: PlayerTarget,
+    move_used: Move,
+    aimed_at: Option<PlayerTarget>,
+) -> Vec<PlayerTarget> {
+    let foes = standing_slots(state, 1 - attacker.side);
+    let Ok(move_data) = get_move_data(move_used) else {
+        return foes.into_iter().take(1).collect();
+    };
+
+    // Moves aimed at the user still face an opponent, as in a single battle.
+    if move_data.target() == Target::User {
+        return foes.into_iter().take(1).collect();
+    }
+
+    match move_data.range {
+        MoveRange::Single => match aimed_at {
+            Some(target) if target != attacker && is_standing(state, target) => vec![target],
+            _ => foes.into_iter().take(1).collect(),
+        },
+        MoveRange::AllFoes => foes,
+        MoveRange::AllOthers => {
+            let mut targets = foes;
+            if is_standing(state, attacker.ally()) {
+                targets.push(attacker.ally());
+            }
+            targets
+        }
+    }
+}
+
+/// Run every action queued behind one, e.g. the later hits of a multi-hit move.
+fn drain_action_stack(
+    state: &mut BattleState,
+    action_stack: &mut ActionStack,
+    bus: &mut EventBus,
+    rng: &mut TurnRng,
+) {
+    while let Some(action) = action_stack.pop_front() {
+        execute_battle_action(action, state, action_stack, bus, rng);
+    }
+}
+
+/// Process any faint not yet handled, in every slot.
+fn sweep_all_faints(state: &mut BattleState, bus: &mut EventBus) {
+    let commands = sweep_faints(state);
+    let _ = execute_command_batch(commands, state, bus, &mut ActionStack::new());
+}
+
+/// The queued choices, in the order they run: by action priority, then move priority,
+/// then speed. Exact ties are settled by rolls, as in a single battle.
+fn action_order(state: &BattleState, rng: &mut TurnRng) -> Vec<(PlayerTarget, SlotAction)> {
+    let mut ordered = Vec::new();
+    for actor in state.field_targets() {
+        let Some(choice) = state.double_action_queue[actor.side][actor.slot].clone() else {
+            continue;
+        };
+        if !is_standing(state, actor) && choice.action != PlayerAction::Forfeit {
+            continue;
+        }
+        let key = ActionStack::priority_key(actor, &choice.action, state);
+        ordered.push((key, actor, choice));
+    }
+    ordered.sort_by_key(|(key, _, _)| std::cmp::Reverse(*key));
+
+    // Within each run of exact ties, roll for who goes next until one is left.
+    let mut start = 0;
+    while start < ordered.len() {
+        let key = ordered[start].0;
+        let end = start
+            + ordered[start..]
+                .iter()
+                .take_while(|(k, _, _)| *k == key)
+                .count();
+        for next in start..end - 1 {
+            let outcome = rng.next_outcome("Speed Tie").saturating_sub(1) as usize;
+            ordered.swap(next, next + outcome * (end - next) / 100);
+        }
+        start = end;
+    }
+
+    ordered
+        .into_iter()
+        .map(|(_, actor, choice)| (actor, choice))
+        .collect()
+}
+
+fn execute_slot_action(
+    state: &mut BattleState,
+    actor: PlayerTarget,
+    choice: SlotAction,
+    bus: &mut EventBus,
+    rng: &mut TurnRng,
+) {
+    let side = actor.side;
+    if choice.action != PlayerAction::Forfeit {
+        let commands = vec![BattleCommand::SetActedThisTurn {
+            target: actor,
+            acted: true,
+        }];
+        let _ = execute_command_batch(commands, state, bus, &mut ActionStack::new());
+    }
+
+    match choice.action {
+        PlayerAction::Forfeit => {
+            let action = BattleAction::Forfeit { player_index: side };
+            execute_battle_action(action, state, &mut ActionStack::new(), bus, rng);
+        }
+        PlayerAction::SwitchPokemon { team_index } => {
+            execute_switch(actor, team_index, state, bus);
+        }
+        PlayerAction::Catch => {
+            let target = choice
+                .target
+                .filter(|target| target.side != side && is_standing(state, *target))
+                .or_else(|| standing_slots(state, 1 - side).into_iter().next());
+            let Some((target, species)) = target.and_then(|target| {
+                let pokemon = state.battler(target).active_pokemon()?;
+                Some((target, pokemon.species))
+            }) else {
+                bus.push(BattleEvent::ActionFailed {
+                    reason: ActionFailureReason::NoEnemyPresent,
+                });
+                return;
+            };
+            let commands = calculate_catch_commands_at(side, target, species, state, rng);
+            let _ = execute_command_batch(commands, state, bus, &mut ActionStack::new());
+        }
+        PlayerAction::UseMove { move_index } => {
+            let move_used = ActionStack::resolve_chosen_move(state.battler(actor), move_index);
+            execute_move(state, actor, move_used, choice.target, bus, rng);
+        }
+    }
+}
+
+/// Use a move against each of its targets. The user pays PP and rolls for anything
+/// that stops it acting once; if the move doesn't go off, no target is touched.
+fn execute_move(
+    state: &mut BattleState,
+    attacker: PlayerTarget,
+    move_used: Move,
+    aimed_at: Option<PlayerTarget>,
+    bus: &mut EventBus,
+    rng: &mut TurnRng,
+) {
+    let targets = move_targets(state, attacker, move_used, aimed_at);
+    if targets.is_empty() {
+        bus.push(BattleEvent::ActionFailed {
+            reason: ActionFailureReason::NoEnemyPresent,
+        });
+        return;
+    }
+
+    let mut action_stack = ActionStack::new();
+    if !begin_move(attacker, move_used, state, &mut action_stack, bus, rng) {
+        // A confused Pokémon may still have hurt itself.
+        drain_action_stack(state, &mut action_stack, bus, rng);
+        return;
+    }
+
+    let spread_hit = targets.len() > 1;
+    for (target_number, &defender) in targets.iter().enumerate() {
+        if !is_standing(state, defender) {
+            continue;
+        }
+        // Only the first target sees the move announced.
+        let hit_number = if target_number == 0 { 0 } else { 1 };
+        match calculate_attack_outcome(
+            state, attacker, defender, move_used, hit_number, spread_hit, rng,
+        ) {
+            Ok(commands) => {
+                let _ = execute_command_batch(commands, state, bus, &mut action_stack);
+            }
+            Err(e) => eprintln!("Error calculating attack outcome: {:?}", e),
+        }
+        drain_action_stack(state, &mut action_stack, bus, rng);
+    }
+}
+
+/// Resolve one turn, or one replacement phase, of a double battle.
+pub fn resolve_double_turn(battle_state: &mut BattleState, bus: &mut EventBus, rng: &mut TurnRng) {
+    let is_replacement_phase = matches!(
+        battle_state.game_state,
+        GameState::WaitingForPlayer1Replacement
+            | GameState::WaitingForPlayer2Replacement
+            | GameState::WaitingForBothReplacements
+    );
+    if is_replacement_phase {
+        resolve_replacements(battle_state, bus, rng);
+        return;
+    }
+
+    // Each slot starts the turn with a clean slate; `initialize_turn` sees to slot 0.
+    let commands = battle_state
+        .field_targets()
+        .into_iter()
+        .filter(|target| target.slot == 1)
+        .flat_map(|target| {
+            [
+                BattleCommand::SetLastDamageTaken {
+                    target,
+                    record: None,
+                },
+                BattleCommand::SetActedThisTurn {
+                    target,
+                    acted: false,
+                },
+            ]
+        })
+        .collect();
+    let _ = execute_command_batch(commands, battle_state, bus, &mut ActionStack::new());
+    initialize_turn(battle_state, bus);
+
+    for (actor, choice) in action_order(battle_state, rng) {
+        if battle_state.game_state != GameState::TurnInProgress {
+            break;
+        }
+        // A Pokémon knocked out earlier in the turn loses its action.
+        if !is_standing(battle_state, actor) && choice.action != PlayerAction::Forfeit {
+            continue;
+        }
+        execute_slot_action(battle_state, actor, choice, bus, rng);
+        sweep_all_faints(battle_state, bus);
+    }
+
+    if battle_state.game_state == GameState::TurnInProgress {
+        let commands = calculate_end_turn_commands(battle_state, rng);
+        let _ = execute_command_batch(commands, battle_state, bus, &mut ActionStack::new());
+        sweep_all_faints(battle_state, bus);
+    }
+
+    finalize_double_turn(battle_state, bus);
+}
+
+fn finalize_double_turn(battle_state: &mut BattleState, bus: &mut EventBus) {
+    check_win_conditions(battle_state, bus);
+    check_turn_limit(battle_state, bus);
+
+    let mut commands = Vec::new();
+    if battle_state.game_state == GameState::TurnInProgress {
+        commands.push(BattleCommand::IncrementTurnNumber);
+    }
+    commands.push(BattleCommand::ClearActionQueue);
+    if !battle_state.is_over() {
+        commands.push(BattleCommand::SetGameState(GameState::WaitingForActions));
+    }
+    let _ = execute_command_batch(commands, battle_state, bus, &mut ActionStack::new());
+
+    set_pending_replacements(battle_state, bus);
+    queue_forced_actions(battle_state, bus);
+    bus.push(BattleEvent::TurnEnded);
+}
+
+/// Fainted slots that must be refilled before the battle goes on: on each side, as
+/// many of its fainted slots as it has Pokémon left on the bench.
+fn slots_needing_replacement(state: &BattleState) -> Vec<PlayerTarget> {
+    let mut slots = Vec::new();
+    for (side, player) in state.players.iter().enumerate() {
+        let bench = player
+            .team
+            .iter()
+            .enumerate()
+            .take(state.rules.format_team_size as usize)
+            .filter(|(team_index, pokemon)| {
+                !player.is_active(*team_index)
+                    && pokemon
+                        .as_ref()
+                        .is_some_and(|pokemon| !pokemon.is_fainted())
+            })
+            .count();
+        slots.extend(
+            (0..2)
+                .map(|slot| PlayerTarget::new(side, slot))
+                .filter(|&slot| {
+                    state
+                        .battler(slot)
+                        .active_pokemon()
+                        .is_some_and(|pokemon| pokemon.is_fainted())
+                })
+                .take(bench),
+        );
+    }
+    slots
+}
+
+fn set_pending_replacements(battle_state: &mut BattleState, bus: &mut EventBus) {
+    if battle_state.is_over() {
+        return;
+    }
+    let slots = slots_needing_replacement(battle_state);
+    let p1 = slots.iter().any(|slot| slot.side == 0);
+    let p2 = slots.iter().any(|slot| slot.side == 1);
+    let new_game_state = match (p1, p2) {
+        (true, true) => GameState::WaitingForBothReplacements,
+        (true, false) => GameState::WaitingForPlayer1Replacement,
+        (false, true) => GameState::WaitingForPlayer2Replacement,
+        (false, false) => return,
+    };
+    let commands = vec![BattleCommand::SetGameState(new_game_state)];
+    let _ = execute_command_batch(commands, battle_state, bus, &mut ActionStack::new());
+}
+
+/// Queue next turn's move for any Pokémon locked into one (charging, Thrash, Bide...).
+fn queue_forced_actions(battle_state: &mut BattleState, bus: &mut EventBus) {
+    if battle_state.is_over() {
+        return;
+    }
+    let commands = calculate_forced_action_commands(battle_state);
+    let _ = execute_command_batch(commands, battle_state, bus, &mut ActionStack::new());
+}
+
+fn resolve_replacements(battle_state: &mut BattleState, bus: &mut EventBus, rng: &mut TurnRng) {
+    for slot in slots_needing_replacement(battle_state) {
+        if let Some(choice) = battle_state.double_action_queue[slot.side][slot.slot].clone() {
+            if matches!(choice.action, PlayerAction::SwitchPokemon { .. }) {
+                execute_slot_action(battle_state, slot, choice, bus, rng);
+            }
+        }
+    }
+
+    check_win_conditions(battle_state, bus);
+    let mut commands = vec![BattleCommand::ClearActionQueue];
+    if !battle_state.is_over() {
+        commands.push(BattleCommand::SetGameState(GameState::WaitingForActions));
+    }
+    let _ = execute_command_batch(commands, battle_state, bus, &mut ActionStack::new());
+    queue_forced_actions(battle_state, bus);
+}
+
+/// The slots that still need a choice queued before the battle can go on.
+pub fn slots_awaiting_action(battle_state: &BattleState) -> Vec<PlayerTarget> {
+    let slots = match battle_state.game_state {
+        GameState::WaitingForActions => (0..2)
+            .flat_map(|side| standing_slots(battle_state, side))
+            .collect(),
+        GameState::WaitingForPlayer1Replacement
+        | GameState::WaitingForPlayer2Replacement
+        | GameState::WaitingForBothReplacements => slots_needing_replacement(battle_state),
+        _ => Vec::new(),
+    };
+    slots
+        .into_iter()
+        .filter(|slot| battle_state.double_action_queue[slot.side][slot.slot].is_none())
+        .collect()
+}
+
+/// Check a choice for one slot and queue it if it's legal.
+pub fn queue_slot_action(
+    battle_state: &mut BattleState,
+    slot: PlayerTarget,
+    choice: SlotAction,
+) -> Result<(), String> {
+    if slot.side > 1 || slot.slot > 1 {
+        return Err("There is no such slot on the field.".to_string());
+    }
+    if let Some(target) = choice.target {
+        if target.side > 1 || target.slot > 1 {
+            return Err("There is no such target on the field.".to_string());
+        }
+    }
+    let team_size = battle_state.rules.format_team_size;
+    battle_state
+        .battler(slot)
+        .validate_action_in_format(&choice.action, team_size)?;
+    battle_state.double_action_queue[slot.side][slot.slot] = Some(choice);
+    Ok(())
+}
+
+/// Choose actions for every NPC slot still waiting on one. Each Pokémon sizes up the
+/// first opponent still standing, and aims its moves there.
+pub fn collect_npc_slot_actions(battle_state: &BattleState) -> Vec<(PlayerTarget, SlotAction)> {
+    let ai_brain = ScoringAI::new();
+    slots_awaiting_action(battle_state)
+        .into_iter()
+        .filter(|slot| battle_state.players[slot.side].player_type == PlayerType::NPC)
+        .map(|slot| {
+            let target = standing_slots(battle_state, 1 - slot.side)
+                .into_iter()
+                .next();
+            let foe = target.unwrap_or(slot.opponent());
+            let action = ai_brain.decide_slot_action(slot, foe, battle_state);
+            (slot, SlotAction { action, target })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::tests::common::{create_test_player, TestPokemonBuilder};
+    use crate::species::Species;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_speed_ties_are_rolled_for() {
+        // Four identical Rattata, all Splashing: every choice ties with every other.
+        let rattata = || {
+            TestPokemonBuilder::new(Species::Rattata, 50)
+                .with_moves(vec![Move::Splash])
+                .build()
+        };
+        let player1 = create_test_player("p1", "Player 1", vec![rattata(), rattata()]);
+        let player2 = create_test_player("p2", "Player 2", vec![rattata(), rattata()]);
+        let mut battle_state = BattleState::new_double("test".to_string(), player1, player2);
+        for slot in battle_state.field_targets() {
+            let choice = SlotAction {
+                action: PlayerAction::UseMove { move_index: 0 },
+                target: None,
+            };
+            queue_slot_action(&mut battle_state, slot, choice).unwrap();
+        }
+        let order = |outcomes: Vec<u8>| {
+            let mut rng = TurnRng::new_for_test(outcomes);
+            action_order(&battle_state, &mut rng)
+                .into_iter()
+                .map(|(actor, _)| (actor.side, actor.slot))
+                .collect::<Vec<_>>()
+        };
+
+        // Low rolls keep side and slot order; high rolls send the last slot first each time.
+        assert_eq!(order(vec![1, 1, 1]), vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+        assert_eq!(
+            order(vec![100, 100, 100]),
+            vec![(1, 1), (0, 0), (0, 1), (1, 0)]
+        );
+    }
+}
@@ -3,20 +3,25 @@
 use crate::battle::action_stack::{ActionStack, BattleAction};
 use crate::battle::ai::{Behavior, ScoringAI};
 use crate::battle::calculators::{
-    calculate_action_prevention, calculate_attack_outcome, calculate_end_turn_commands,
-    calculate_forced_action_commands, calculate_forfeit_commands, calculate_switch_commands,
+    calculate_action_prevention, calculate_attack_outcome, calculate_double_forfeit_commands,
+    calculate_end_turn_commands, calculate_forced_action_commands, calculate_forfeit_commands,
+    calculate_switch_commands, sweep_faints,
 };
 use crate::battle::catch::calculate_catch_commands;
 use crate::battle::commands::{
     execute_command, execute_command_batch, BattleCommand, PlayerTarget,
 };
 use crate::battle::conditions::*;
+use crate::battle::double::{collect_npc_slot_actions, resolve_double_turn, slots_awaiting_action};
+use crate::battle::move_effects::BattleMoveDataExt;
+use crate::battle::progression::calculate_prize_commands;
 use crate::battle::state::{
-    ActionFailureReason, BattleEvent, BattleState, EventBus, GameState, TurnRng,
+    ActionFailureReason, BattleEvent, BattleFormat, BattleState, EventBus, GameState,
+    TimeoutResolution, TurnRng,
 };
 use crate::move_data::get_move_data;
 use crate::player::PlayerAction;
-use schema::{Move, MoveCategory};
+use schema::{Move, Target};
 
 pub fn collect_npc_actions(battle_state: &BattleState) -> Vec<(usize, PlayerAction)> {
     let ai_brain = ScoringAI::new();
@@ -46,6 +51,10 @@ pub fn collect_npc_actions(battle_state: &BattleState) -> Vec<(usize, PlayerActi
 
 /// Check if the battle is ready for turn resolution.
 pub fn ready_for_turn_resolution(battle_state: &BattleState) -> bool {
+    if battle_state.format == BattleFormat::Double {
+        // A double battle needs a choice for each of the Pokémon still waiting on one.
+        return slots_awaiting_action(battle_state).is_empty();
+    }
     match battle_state.game_state {
         GameState::WaitingForActions => {
             // The turn can start if and only if both players have a queued action.
@@ -60,6 +69,109 @@ pub fn ready_for_turn_resolution(battle_state: &BattleState) -> bool {
     }
 }
 
+/// Why `advance_battle` handed control back to the caller.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AdvanceOutcome {
+    /// The battle has reached a terminal state.
+    BattleOver,
+    /// These (human) players must queue an action before the battle can continue.
+    AwaitingInput(Vec<usize>),
+}
+
+/// Everything that happened during a call to `advance_battle`.
+#[derive(Debug, Clone)]
+pub struct AdvanceResult {
+    pub events: Vec<BattleEvent>,
+    pub outcome: AdvanceOutcome,
+}
+
+/// How many turns `advance_battle` resolves in one call, for a battle with no
+/// `max_turns` of its own, before the battle is decided by its timeout resolution.
+/// Only reachable when no human ever has to act, e.g. two NPCs that can't hurt each other.
+pub const UNATTENDED_TURN_CAP: u32 = 1000;
+
+/// Drive the battle forward as far as it can go without outside input.
+/// Repeatedly fills NPC action slots and resolves turns, drawing a fresh RNG
+/// from `rng_source` for each one, until a human must act or the battle ends.
+/// A battle without `max_turns` is ended after `UNATTENDED_TURN_CAP` turns in one call.
+pub fn advance_battle<F>(battle_state: &mut BattleState, rng_source: F) -> AdvanceResult
+where
+    F: FnMut() -> TurnRng,
+{
+    advance_battle_with_cap(battle_state, rng_source, UNATTENDED_TURN_CAP)
+}
+
+/// `advance_battle` with the unattended turn cap given explicitly.
+pub(crate) fn advance_battle_with_cap<F>(
+    battle_state: &mut BattleState,
+    mut rng_source: F,
+    turn_cap: u32,
+) -> AdvanceResult
+where
+    F: FnMut() -> TurnRng,
+{
+    let mut events = Vec::new();
+    let mut turns_resolved = 0;
+
+    loop {
+        if battle_state.rules.max_turns.is_none() && turns_resolved >= turn_cap {
+            let mut bus = EventBus::new();
+            end_by_timeout(battle_state, &mut bus);
+            events.extend(bus.events().iter().cloned());
+        }
+
+        if battle_state.is_over() {
+            return AdvanceResult {
+                events,
+                outcome: AdvanceOutcome::BattleOver,
+            };
+        }
+
+        if battle_state.format == BattleFormat::Double {
+            for (slot, choice) in collect_npc_slot_actions(battle_state) {
+                battle_state.double_action_queue[slot.side][slot.slot] = Some(choice);
+            }
+        } else {
+            for (player_index, action) in collect_npc_actions(battle_state) {
+                battle_state.action_queue[player_index] = Some(action);
+            }
+        }
+
+        if !ready_for_turn_resolution(battle_state) {
+            return AdvanceResult {
+                events,
+                outcome: AdvanceOutcome::AwaitingInput(players_awaiting_input(battle_state)),
+            };
+        }
+
+        let bus = resolve_turn(battle_state, rng_source());
+        events.extend(bus.events().iter().cloned());
+        turns_resolved += 1;
+    }
+}
+
+/// The players who still need to queue an action in the current game state.
+fn players_awaiting_input(battle_state: &BattleState) -> Vec<usize> {
+    if battle_state.format == BattleFormat::Double {
+        let mut players: Vec<usize> = slots_awaiting_action(battle_state)
+            .into_iter()
+            .map(|slot| slot.side)
+            .collect();
+        players.dedup();
+        return players;
+    }
+    let required = match battle_state.game_state {
+        GameState::WaitingForActions | GameState::WaitingForBothReplacements => vec![0, 1],
+        GameState::WaitingForPlayer1Replacement => vec![0],
+        GameState::WaitingForPlayer2Replacement => vec![1],
+        _ => Vec::new(),
+    };
+    required
+        .into_iter()
+        .filter(|&player_index| battle_state.action_queue[player_index].is_none())
+        .collect()
+}
+
 /// Main entry point for turn resolution
 /// Takes a battle state and RNG oracle, executes one complete turn
 /// Returns EventBus containing all events that occurred during the turn
@@ -77,18 +189,27 @@ pub fn resolve_turn(battle_state: &mut BattleState, mut rng: TurnRng) -> EventBu
             | GameState::WaitingForBothReplacements
     );
 
-    if is_replacement_phase {
+    if battle_state.format == BattleFormat::Double {
+        resolve_double_turn(battle_state, &mut bus, &mut rng);
+    } else if is_replacement_phase {
         // Pass the single action_stack here as well.
-        resolve_replacement_phase(battle_state, &mut bus, &mut action_stack);
+        resolve_replacement_phase(battle_state, &mut bus, &mut action_stack, &mut rng);
     } else {
         initialize_turn(battle_state, &mut bus);
 
         // Build the initial actions into our single, unified stack.
-        let mut action_stack = ActionStack::build_initial(battle_state);
+        let mut action_stack = ActionStack::build_initial(battle_state, &mut rng);
 
         // The while loop and the execution function now operate on the SAME stack.
         while let Some(action) = action_stack.pop_front() {
             execute_battle_action(action, battle_state, &mut action_stack, &mut bus, &mut rng);
+            let faint_commands = sweep_faints(battle_state);
+            let _ = execute_command_batch(
+                faint_commands,
+                battle_state,
+                &mut bus,
+                &mut ActionStack::new(),
+            );
 
             if battle_state.game_state != GameState::TurnInProgress {
                 break;
@@ -103,12 +224,24 @@ pub fn resolve_turn(battle_state: &mut BattleState, mut rng: TurnRng) -> EventBu
                 &mut bus,
                 &mut ActionStack::new(),
             );
+            let faint_commands = sweep_faints(battle_state);
+            let _ = execute_command_batch(
+                faint_commands,
+                battle_state,
+                &mut bus,
+                &mut ActionStack::new(),
+            );
         }
 
         // Pass the now-empty stack to finalize_turn.
         finalize_turn(battle_state, &mut bus, &mut action_stack);
     }
 
+    for (player_index, revealed) in battle_state.revealed_moves.iter_mut().enumerate() {
+        revealed.observe(player_index, bus.events());
+    }
+
+    bus.set_rng_trace(rng.take_trace());
     bus
 }
 
@@ -117,8 +250,26 @@ fn resolve_replacement_phase(
     battle_state: &mut BattleState,
     bus: &mut EventBus,
     action_stack: &mut ActionStack,
+    rng: &mut TurnRng,
 ) {
-    let mut turn_action_stack = ActionStack::build_initial(battle_state);
+    // Only the players sending in a replacement act in this phase. A player who has already
+    // chosen their next action (e.g. a voluntary switch) keeps it for the following turn.
+    let replacing = match battle_state.game_state {
+        GameState::WaitingForPlayer1Replacement => [true, false],
+        GameState::WaitingForPlayer2Replacement => [false, true],
+        _ => [true, true],
+    };
+    let held_actions: [Option<PlayerAction>; 2] = std::array::from_fn(|player_index| {
+        if replacing[player_index] {
+            None
+        } else {
+            battle_state.action_queue[player_index].take()
+        }
+    });
+
+    // Replacement switches are ordered by player index, so no tie-break roll is made today.
+    // The turn's RNG is still passed along so any roll added to this phase draws from it.
+    let mut turn_action_stack = ActionStack::build_initial(battle_state, rng);
 
     while let Some(action) = turn_action_stack.pop_front() {
         if matches!(action, BattleAction::Switch { .. }) {
@@ -127,23 +278,17 @@ fn resolve_replacement_phase(
                 battle_state,
                 action_stack, // Pass the main stack
                 bus,
-                &mut TurnRng::new_for_test(vec![]),
+                rng,
             );
         }
-        if matches!(
-            battle_state.game_state,
-            GameState::Player1Win | GameState::Player2Win | GameState::Draw
-        ) {
+        if battle_state.is_over() {
             break;
         }
     }
 
     check_win_conditions(battle_state, bus);
 
-    if !matches!(
-        battle_state.game_state,
-        GameState::Player1Win | GameState::Player2Win | GameState::Draw
-    ) {
+    if !battle_state.is_over() {
         let commands = vec![BattleCommand::SetGameState(GameState::WaitingForActions)];
         let _ = execute_command_batch(commands, battle_state, bus, action_stack);
     }
@@ -151,18 +296,45 @@ fn resolve_replacement_phase(
     let commands = vec![BattleCommand::ClearActionQueue];
     let _ = execute_command_batch(commands, battle_state, bus, action_stack);
 
-    // Inject forced actions after replacement, just like in finalize_turn
-    if !matches!(
-        battle_state.game_state,
-        GameState::Player1Win | GameState::Player2Win | GameState::Draw
-    ) {
+    // Inject forced actions after replacement, just like in finalize_turn.
+    // A forced move still takes precedence over a held action.
+    if !battle_state.is_over() {
+        let held_action_commands: Vec<BattleCommand> = held_actions
+            .into_iter()
+            .enumerate()
+            .filter_map(|(player_index, action)| {
+                action.map(|action| BattleCommand::QueueForcedAction {
+                    target: PlayerTarget::from_index(player_index),
+                    action,
+                })
+            })
+            .collect();
+        let _ = execute_command_batch(held_action_commands, battle_state, bus, action_stack);
         let forced_action_commands = calculate_forced_action_commands(battle_state);
         let _ = execute_command_batch(forced_action_commands, battle_state, bus, action_stack);
     }
 }
 
-fn initialize_turn(battle_state: &mut BattleState, bus: &mut EventBus) {
-    let commands = vec![BattleCommand::SetGameState(GameState::TurnInProgress)];
+pub(crate) fn initialize_turn(battle_state: &mut BattleState, bus: &mut EventBus) {
+    let commands = vec![
+        BattleCommand::SetGameState(GameState::TurnInProgress),
+        BattleCommand::SetLastDamageTaken {
+            target: PlayerTarget::from_index(0),
+            record: None,
+        },
+        BattleCommand::SetLastDamageTaken {
+            target: PlayerTarget::from_index(1),
+            record: None,
+        },
+        BattleCommand::SetActedThisTurn {
+            target: PlayerTarget::from_index(0),
+            acted: false,
+        },
+        BattleCommand::SetActedThisTurn {
+            target: PlayerTarget::from_index(1),
+            acted: false,
+        },
+    ];
     let _ = execute_command_batch(commands, battle_state, bus, &mut ActionStack::new());
     bus.push(BattleEvent::TurnStarted {
         turn_number: battle_state.turn_number,
@@ -177,9 +349,47 @@ pub fn execute_battle_action(
     bus: &mut EventBus,
     rng: &mut TurnRng,
 ) {
+    // Record that this side has taken its turn, whether or not the action succeeds.
+    // Later hits of a multi-hit move and confusion self-hits are part of the same turn.
+    let actor = match action {
+        BattleAction::Switch { player_index, .. } | BattleAction::CatchAttempt { player_index } => {
+            Some(PlayerTarget::from_index(player_index))
+        }
+        BattleAction::AttackHit {
+            attacker,
+            hit_number: 0,
+            ..
+        } => Some(attacker),
+        _ => None,
+    };
+    if let Some(actor) = actor {
+        let _ = execute_command(
+            BattleCommand::SetActedThisTurn {
+                target: actor,
+                acted: true,
+            },
+            battle_state,
+            bus,
+            action_stack,
+        );
+    }
+
     match action {
         BattleAction::Forfeit { player_index } => {
-            let commands = calculate_forfeit_commands(player_index);
+            // If the opponent also forfeited this turn, neither side wins. In a double
+            // battle the forfeit may have come from either of its slots.
+            let opponent_forfeited = matches!(
+                battle_state.action_queue[1 - player_index],
+                Some(PlayerAction::Forfeit)
+            ) || battle_state.double_action_queue[1 - player_index]
+                .iter()
+                .flatten()
+                .any(|choice| choice.action == PlayerAction::Forfeit);
+            let commands = if opponent_forfeited {
+                calculate_double_forfeit_commands()
+            } else {
+                calculate_forfeit_commands(player_index)
+            };
             let _ = execute_command_batch(commands, battle_state, bus, action_stack);
         }
 
@@ -187,37 +397,8 @@ pub fn execute_battle_action(
             player_index,
             target_pokemon_index,
         } => {
-            // Check if current Pokemon is fainted (switching away from fainted Pokemon is allowed)
-            // But switching TO a fainted Pokemon should not be allowed
-            let target_pokemon = &battle_state.players[player_index].team[target_pokemon_index];
-            let player = &battle_state.players[player_index];
-
-            // Only prevent switching if there's an active, non-fainted Pokemon that is trapped
-            if player.has_condition_type(PokemonConditionType::Trapped) {
-                if let Some(active_pokemon) = player.active_pokemon() {
-                    if !active_pokemon.is_fainted() {
-                        bus.push(BattleEvent::ActionFailed {
-                            reason: crate::battle::state::ActionFailureReason::IsTrapped {
-                                pokemon: active_pokemon.species,
-                            },
-                        });
-                        return;
-                    }
-                }
-            }
-            if let Some(target_pokemon) = target_pokemon {
-                if target_pokemon.is_fainted() {
-                    // Cannot switch to a fainted Pokemon
-                    bus.push(BattleEvent::ActionFailed {
-                        reason: crate::battle::state::ActionFailureReason::PokemonFainted,
-                    });
-                    return;
-                }
-            }
-
-            let commands =
-                calculate_switch_commands(player_index, target_pokemon_index, battle_state);
-            let _ = execute_command_batch(commands, battle_state, bus, &mut ActionStack::new());
+            let target = PlayerTarget::from_index(player_index);
+            execute_switch(target, target_pokemon_index, battle_state, bus);
         }
 
         BattleAction::CatchAttempt { player_index } => {
@@ -243,17 +424,13 @@ pub fn execute_battle_action(
         }
 
         BattleAction::AttackHit {
-            attacker_index,
-            defender_index,
+            attacker,
+            defender,
             move_used,
             hit_number,
         } => {
             // Check if attacker is fainted (cannot act)
-            let attacker_player = &battle_state.players[attacker_index];
-            let attacker_pokemon =
-                attacker_player.team[attacker_player.active_pokemon_index].as_ref();
-
-            if let Some(attacker_pokemon) = attacker_pokemon {
+            if let Some(attacker_pokemon) = battle_state.battler(attacker).active_pokemon() {
                 if attacker_pokemon.is_fainted() {
                     // Skip action - attacker has fainted and cannot act
                     bus.push(BattleEvent::ActionFailed {
@@ -263,103 +440,10 @@ pub fn execute_battle_action(
                 }
             }
 
-            // Check all action-preventing conditions (sleep, freeze, paralysis, confusion, etc.)
-            // This needs to happen BEFORE any move processing (including special moves)
-            // Only check prevention on the first hit (hit_number 0) of a move
-            let (failure_reason, prevention_commands) = if hit_number == 0 {
-                calculate_action_prevention(attacker_index, battle_state, rng, move_used)
-            } else {
-                (None, Vec::new()) // No prevention for subsequent hits
-            };
-
-            // Execute any commands from the prevention check (status updates, etc.)
-            let _ = execute_command_batch(prevention_commands, battle_state, bus, action_stack);
-
-            if let Some(failure_reason) = failure_reason {
-                // Always generate ActionFailed event first
-                bus.push(BattleEvent::ActionFailed {
-                    reason: failure_reason.clone(),
-                });
-
-                // Special case for confusion - also causes self-damage after the action fails
-                if matches!(failure_reason, ActionFailureReason::IsConfused { .. }) {
-                    // Add confusion self-attack action to the stack
-                    action_stack.push_front(BattleAction::AttackHit {
-                        attacker_index,
-                        defender_index: attacker_index, // Attack self
-                        move_used: Move::HittingItself,
-                        hit_number: 1, // Hit number > 0 to avoid pp check.
-                    });
-                }
-                return; // Attack is prevented
-            }
-            if hit_number == 0 {
-                // Use PP for the move via command
-                if let Err(_) = execute_command(
-                    BattleCommand::UsePP {
-                        target: PlayerTarget::from_index(attacker_index),
-                        move_used,
-                    },
-                    battle_state,
-                    bus,
-                    action_stack,
-                ) {
-                    bus.push(BattleEvent::ActionFailed {
-                        reason: crate::battle::state::ActionFailureReason::NoPPRemaining {
-                            move_used,
-                        },
-                    });
-                    return;
-                }
-
-                // Update last move used for conditions that depend on it
-                execute_command(
-                    BattleCommand::SetLastMove {
-                        target: PlayerTarget::from_index(attacker_index),
-                        move_used,
-                    },
-                    battle_state,
-                    bus,
-                    action_stack,
-                )
-                .expect("SetLastMove command should always succeed");
-
-                // Check if Enraged Pokemon used a move other than Rage - if so, remove Enraged condition
-                if battle_state.players[attacker_index]
-                    .has_condition_type(PokemonConditionType::Enraged)
-                    && Some(move_used) != battle_state.players[attacker_index].last_move
-                // Rather than requiring use of Rage, we just require it is the same move as before.
-                // This allows for multiple moves that cause the user to become Enraged.
-                {
-                    if let Some(pokemon) = battle_state.players[attacker_index].active_pokemon() {
-                        execute_command(
-                            BattleCommand::EmitEvent(BattleEvent::StatusRemoved {
-                                target: pokemon.species,
-                                status: PokemonCondition::Enraged,
-                            }),
-                            battle_state,
-                            bus,
-                            action_stack,
-                        )
-                        .expect("EmitEvent command should always succeed");
-                    }
-                    execute_command(
-                        BattleCommand::RemoveCondition {
-                            target: PlayerTarget::from_index(attacker_index),
-                            condition_type: PokemonConditionType::Enraged,
-                        },
-                        battle_state,
-                        bus,
-                        action_stack,
-                    )
-                    .expect("RemoveCondition command should always succeed");
-                }
-            }
-            // Perform pre-hit checks on the defender.
-            let defender_player = &battle_state.players[defender_index];
-            if let Some(defender_pokemon) =
-                defender_player.team[defender_player.active_pokemon_index].as_ref()
-            {
+            // Perform pre-hit checks on the defender before anything else, so a move aimed
+            // at a fainted, not-yet-replaced target fails without spending PP, consuming
+            // RNG or ticking the attacker's status.
+            if let Some(defender_pokemon) = battle_state.battler(defender).active_pokemon() {
                 let move_data = match get_move_data(move_used) {
                     Ok(data) => data,
                     Err(_) => {
@@ -368,26 +452,24 @@ pub fn execute_battle_action(
                     }
                 };
 
-                if defender_pokemon.is_fainted() {
-                    // Target has fainted. Only allow non-offensive moves (e.g., self-buffs).
-                    match move_data.category {
-                        MoveCategory::Physical | MoveCategory::Special | MoveCategory::Other => {
-                            // This is an offensive move against a fainted target. It fails.
-                            bus.push(BattleEvent::ActionFailed {
-                                reason: crate::battle::state::ActionFailureReason::NoEnemyPresent,
-                            });
-                            return;
-                        }
-                        MoveCategory::Status => {
-                            // This is a status move, it can proceed even if the opponent is fainted.
-                        }
-                    }
+                if defender_pokemon.is_fainted() && move_data.target() == Target::Target {
+                    // Target has fainted, so a move aimed at it has nothing to hit.
+                    // Moves aimed at the user (e.g., self-buffs) proceed regardless.
+                    bus.push(BattleEvent::ActionFailed {
+                        reason: crate::battle::state::ActionFailureReason::NoEnemyPresent,
+                    });
+                    return;
                 }
             }
 
+            if hit_number == 0
+                && !begin_move(attacker, move_used, battle_state, action_stack, bus, rng)
+            {
+                return;
+            }
             execute_attack_hit(
-                attacker_index,
-                defender_index,
+                attacker,
+                defender,
                 move_used,
                 hit_number,
                 action_stack,
@@ -399,10 +481,169 @@ pub fn execute_battle_action(
     }
 }
 
+/// Switch the Pokemon in `target`'s slot for the team member at `target_pokemon_index`,
+/// unless it is trapped or the incoming Pokemon can't be sent out.
+pub(crate) fn execute_switch(
+    target: PlayerTarget,
+    target_pokemon_index: usize,
+    battle_state: &mut BattleState,
+    bus: &mut EventBus,
+) {
+    // Check if current Pokemon is fainted (switching away from fainted Pokemon is allowed)
+    // But switching TO a fainted Pokemon should not be allowed
+    let player = battle_state.battler(target);
+    let target_pokemon = &player.player.team[target_pokemon_index];
+
+    // Only prevent switching if there's an active, non-fainted Pokemon that is trapped
+    if player.is_switch_blocked() {
+        if let Some(active_pokemon) = player.active_pokemon() {
+            if !active_pokemon.is_fainted() {
+                bus.push(BattleEvent::ActionFailed {
+                    reason: crate::battle::state::ActionFailureReason::IsTrapped {
+                        pokemon: active_pokemon.species,
+                    },
+                });
+                return;
+            }
+        }
+    }
+    if let Some(target_pokemon) = target_pokemon {
+        if target_pokemon.is_fainted() {
+            // Cannot switch to a fainted Pokemon
+            bus.push(BattleEvent::ActionFailed {
+                reason: crate::battle::state::ActionFailureReason::PokemonFainted,
+            });
+            return;
+        }
+        if target_pokemon_index >= battle_state.rules.format_team_size as usize {
+            // Slots beyond the format sit this battle out, even if the queued
+            // action skipped validation.
+            bus.push(BattleEvent::ActionFailed {
+                reason: crate::battle::state::ActionFailureReason::NotInFormat {
+                    pokemon: target_pokemon.species,
+                },
+            });
+            return;
+        }
+        if player.player.is_active(target_pokemon_index) {
+            // Switching to the Pokemon already on the field would waste the turn
+            // and wipe its conditions and stat stages for nothing.
+            bus.push(BattleEvent::ActionFailed {
+                reason: crate::battle::state::ActionFailureReason::AlreadyInBattle {
+                    pokemon: target_pokemon.species,
+                },
+            });
+            return;
+        }
+    }
+
+    let commands = calculate_switch_commands(target, target_pokemon_index, battle_state);
+    let _ = execute_command_batch(commands, battle_state, bus, &mut ActionStack::new());
+}
+
+/// The work done once when a Pokemon starts using a move, before its first hit: check
+/// what might stop it acting, then pay PP and record the move. Returns whether the move
+/// goes ahead. A confused Pokemon that hurts itself has that hit queued on `action_stack`.
+pub(crate) fn begin_move(
+    attacker: PlayerTarget,
+    move_used: Move,
+    battle_state: &mut BattleState,
+    action_stack: &mut ActionStack,
+    bus: &mut EventBus,
+    rng: &mut TurnRng,
+) -> bool {
+    // Check all action-preventing conditions (sleep, freeze, paralysis, confusion, etc.)
+    // This needs to happen BEFORE any move processing (including special moves)
+    let (failure_reason, prevention_commands) =
+        calculate_action_prevention(attacker, battle_state, rng, move_used);
+
+    // Execute any commands from the prevention check (status updates, etc.)
+    let _ = execute_command_batch(prevention_commands, battle_state, bus, action_stack);
+
+    if let Some(failure_reason) = failure_reason {
+        // Always generate ActionFailed event first
+        bus.push(BattleEvent::ActionFailed {
+            reason: failure_reason.clone(),
+        });
+
+        // Special case for confusion - also causes self-damage after the action fails
+        if matches!(failure_reason, ActionFailureReason::IsConfused { .. }) {
+            // Add confusion self-attack action to the stack
+            action_stack.push_front(BattleAction::AttackHit {
+                attacker,
+                defender: attacker, // Attack self
+                move_used: Move::HittingItself,
+                hit_number: 1, // Hit number > 0 to avoid pp check.
+            });
+        }
+        return false; // Attack is prevented
+    }
+    // Use PP for the move via command
+    if let Err(_) = execute_command(
+        BattleCommand::UsePP {
+            target: attacker,
+            move_used,
+        },
+        battle_state,
+        bus,
+        action_stack,
+    ) {
+        bus.push(BattleEvent::ActionFailed {
+            reason: crate::battle::state::ActionFailureReason::NoPPRemaining { move_used },
+        });
+        return false;
+    }
+
+    // Update last move used for conditions that depend on it
+    execute_command(
+        BattleCommand::SetLastMove {
+            target: attacker,
+            move_used,
+        },
+        battle_state,
+        bus,
+        action_stack,
+    )
+    .expect("SetLastMove command should always succeed");
+
+    // Check if Enraged Pokemon used a move other than Rage - if so, remove Enraged condition
+    if battle_state
+        .battler(attacker)
+        .has_condition_type(PokemonConditionType::Enraged)
+        && Some(move_used) != battle_state.battler(attacker).last_move()
+    // Rather than requiring use of Rage, we just require it is the same move as before.
+    // This allows for multiple moves that cause the user to become Enraged.
+    {
+        if let Some(pokemon) = battle_state.battler(attacker).active_pokemon() {
+            execute_command(
+                BattleCommand::EmitEvent(BattleEvent::StatusRemoved {
+                    target: pokemon.species,
+                    status: PokemonCondition::Enraged,
+                }),
+                battle_state,
+                bus,
+                action_stack,
+            )
+            .expect("EmitEvent command should always succeed");
+        }
+        execute_command(
+            BattleCommand::RemoveCondition {
+                target: attacker,
+                condition_type: PokemonConditionType::Enraged,
+            },
+            battle_state,
+            bus,
+            action_stack,
+        )
+        .expect("RemoveCondition command should always succeed");
+    }
+    true
+}
+
 /// Execute a single hit of an attack
 pub fn execute_attack_hit(
-    attacker_index: usize,
-    defender_index: usize,
+    attacker: PlayerTarget,
+    defender: PlayerTarget,
     move_used: Move,
     hit_number: u8,
     action_stack: &mut ActionStack,
@@ -411,10 +652,15 @@ pub fn execute_attack_hit(
     battle_state: &mut BattleState,
 ) {
     // 1. Guard Clause: If the defender is already fainted (from a previous hit in a
-    //    multi-hit sequence), the entire action is silently stopped.
-    if battle_state.players[defender_index]
-        .active_pokemon()
-        .map_or(true, |p| p.is_fainted())
+    //    multi-hit sequence), the entire action is silently stopped. Moves aimed at
+    //    the user don't need a living target and are allowed through.
+    let targets_opponent =
+        get_move_data(move_used).map_or(true, |data| data.target() == Target::Target);
+    if targets_opponent
+        && battle_state
+            .battler(defender)
+            .active_pokemon()
+            .map_or(true, |p| p.is_fainted())
     {
         return;
     }
@@ -423,10 +669,11 @@ pub fn execute_attack_hit(
     //    This single call determines everything that should happen as a result of the attack.
     let commands = match calculate_attack_outcome(
         battle_state,
-        attacker_index,
-        defender_index,
+        attacker,
+        defender,
         move_used,
         hit_number,
+        false,
         rng,
     ) {
         Ok(commands) => commands,
@@ -449,13 +696,11 @@ fn finalize_turn(
     action_stack: &mut ActionStack,
 ) {
     // Step 1: Clear state for fainted Pokémon.
-    for player_index in 0..2 {
-        if let Some(pokemon) = battle_state.players[player_index].active_pokemon() {
+    for target in battle_state.field_targets() {
+        if let Some(pokemon) = battle_state.battler(target).active_pokemon() {
             if pokemon.is_fainted() {
                 let _ = execute_command(
-                    BattleCommand::ClearPlayerState {
-                        target: PlayerTarget::from_index(player_index),
-                    },
+                    BattleCommand::ClearPlayerState { target },
                     battle_state,
                     bus,
                     action_stack,
@@ -464,8 +709,9 @@ fn finalize_turn(
         }
     }
 
-    // Step 2: Check for win/loss conditions.
+    // Step 2: Check for win/loss conditions, then the turn cap if the battle is still on.
     check_win_conditions(battle_state, bus);
+    check_turn_limit(battle_state, bus);
 
     // Step 3: Increment turn number if the battle is ongoing.
     if matches!(battle_state.game_state, GameState::TurnInProgress) {
@@ -486,10 +732,7 @@ fn finalize_turn(
     );
 
     // Step 5: If the battle hasn't ended, set the state to wait for the next set of actions.
-    if !matches!(
-        battle_state.game_state,
-        GameState::Player1Win | GameState::Player2Win | GameState::Draw
-    ) {
+    if !battle_state.is_over() {
         let _ = execute_command(
             BattleCommand::SetGameState(GameState::WaitingForActions),
             battle_state,
@@ -509,22 +752,70 @@ fn finalize_turn(
     bus.push(BattleEvent::TurnEnded);
 }
 
+/// End a battle still in progress once it has played `max_turns` turns,
+/// deciding it according to the `timeout_resolution` policy.
+pub(crate) fn check_turn_limit(battle_state: &mut BattleState, bus: &mut EventBus) {
+    let Some(max_turns) = battle_state.rules.max_turns else {
+        return;
+    };
+    if battle_state.game_state != GameState::TurnInProgress || battle_state.turn_number < max_turns
+    {
+        return;
+    }
+    end_by_timeout(battle_state, bus);
+}
+
+/// Decide a battle still in progress by its `timeout_resolution` policy.
+fn end_by_timeout(battle_state: &mut BattleState, bus: &mut EventBus) {
+    let winner = match battle_state.rules.timeout_resolution {
+        TimeoutResolution::Draw => None,
+        TimeoutResolution::HpPercentage => {
+            let team_size = battle_state.rules.format_team_size;
+            let p1_share = battle_state.players[0].remaining_hp_fraction_in_format(team_size);
+            let p2_share = battle_state.players[1].remaining_hp_fraction_in_format(team_size);
+            if p1_share > p2_share {
+                Some(0)
+            } else if p2_share > p1_share {
+                Some(1)
+            } else {
+                None
+            }
+        }
+    };
+    let final_state = match winner {
+        Some(0) => GameState::Player1Win,
+        Some(_) => GameState::Player2Win,
+        None => GameState::Draw,
+    };
+
+    bus.push(BattleEvent::TurnLimitReached {
+        turn_number: battle_state.turn_number,
+    });
+    let commands = vec![BattleCommand::SetGameState(final_state)];
+    let _ = execute_command_batch(commands, battle_state, bus, &mut ActionStack::new());
+    // A win on time pays out just like a knockout win.
+    if let Some(winner_index) = winner {
+        let prize_commands = calculate_prize_commands(winner_index, battle_state);
+        let _ = execute_command_batch(prize_commands, battle_state, bus, &mut ActionStack::new());
+    }
+    bus.push(BattleEvent::BattleEnded { winner });
+}
+
 /// At the end of the turn, checks if any active Pokemon have fainted and if replacements are needed.
 fn check_for_pending_replacements(battle_state: &mut BattleState, bus: &mut EventBus) {
     // This should only trigger if the battle is still technically ongoing.
-    if !matches!(
-        battle_state.game_state,
-        GameState::Player1Win | GameState::Player2Win | GameState::Draw
-    ) {
+    if !battle_state.is_over() {
         let p1_fainted = battle_state.players[0].team[battle_state.players[0].active_pokemon_index]
             .as_ref()
             .map_or(false, |p| p.is_fainted());
-        let p1_has_replacement = battle_state.players[0].can_still_battle();
+        let p1_has_replacement =
+            battle_state.players[0].can_still_battle_in_format(battle_state.rules.format_team_size);
 
         let p2_fainted = battle_state.players[1].team[battle_state.players[1].active_pokemon_index]
             .as_ref()
             .map_or(false, |p| p.is_fainted());
-        let p2_has_replacement = battle_state.players[1].can_still_battle();
+        let p2_has_replacement =
+            battle_state.players[1].can_still_battle_in_format(battle_state.rules.format_team_size);
 
         let p1_needs_replacement = p1_fainted && p1_has_replacement;
         let p2_needs_replacement = p2_fainted && p2_has_replacement;
@@ -544,9 +835,11 @@ fn check_for_pending_replacements(battle_state: &mut BattleState, bus: &mut Even
 }
 
 /// Check win conditions and update battle state accordingly
-fn check_win_conditions(battle_state: &mut BattleState, bus: &mut EventBus) {
-    let player1_has_pokemon = battle_state.players[0].can_still_battle();
-    let player2_has_pokemon = battle_state.players[1].can_still_battle();
+pub(crate) fn check_win_conditions(battle_state: &mut BattleState, bus: &mut EventBus) {
+    let player1_has_pokemon =
+        battle_state.players[0].can_still_battle_in_format(battle_state.rules.format_team_size);
+    let player2_has_pokemon =
+        battle_state.players[1].can_still_battle_in_format(battle_state.rules.format_team_size);
 
     match (player1_has_pokemon, player2_has_pokemon) {
         (false, false) => {
@@ -560,6 +853,9 @@ fn check_win_conditions(battle_state: &mut BattleState, bus: &mut EventBus) {
             let commands = vec![BattleCommand::SetGameState(GameState::Player2Win)];
             let _ = execute_command_batch(commands, battle_state, bus, &mut ActionStack::new());
             bus.push(BattleEvent::PlayerDefeated { player_index: 0 });
+            let prize_commands = calculate_prize_commands(1, battle_state);
+            let _ =
+                execute_command_batch(prize_commands, battle_state, bus, &mut ActionStack::new());
             bus.push(BattleEvent::BattleEnded { winner: Some(1) });
         }
         (true, false) => {
@@ -567,6 +863,9 @@ fn check_win_conditions(battle_state: &mut BattleState, bus: &mut EventBus) {
             let commands = vec![BattleCommand::SetGameState(GameState::Player1Win)];
             let _ = execute_command_batch(commands, battle_state, bus, &mut ActionStack::new());
             bus.push(BattleEvent::PlayerDefeated { player_index: 1 });
+            let prize_commands = calculate_prize_commands(0, battle_state);
+            let _ =
+                execute_command_batch(prize_commands, battle_state, bus, &mut ActionStack::new());
             bus.push(BattleEvent::BattleEnded { winner: Some(0) });
         }
         (true, true) => {
@@ -0,0 +1,116 @@
+use crate::battle::state::BattleState;
+use crate::player::BattlePlayer;
+use crate::pokemon::StatusCondition;
+use schema::{PokemonType, StatType};
+
+/// Stat stages encoded for each side, in order.
+const ENCODED_STAGES: [StatType; 7] = [
+    StatType::Atk,
+    StatType::Def,
+    StatType::SpAtk,
+    StatType::SpDef,
+    StatType::Spe,
+    StatType::Acc,
+    StatType::Eva,
+];
+
+/// Number of status one-hot slots: Sleep, Poison, Burn, Freeze, Paralysis, Faint.
+const STATUS_SLOTS: usize = 6;
+
+/// Values encoded for a single side of the battle.
+pub const SIDE_FEATURE_LENGTH: usize =
+    1 + PokemonType::ALL.len() + ENCODED_STAGES.len() + STATUS_SLOTS + 1;
+
+/// Total length of a `FeatureVector`: one block per player.
+pub const FEATURE_LENGTH: usize = 2 * SIDE_FEATURE_LENGTH;
+
+/// Fixed-length numeric encoding of a battle, for training agents.
+///
+/// The vector holds a block for player 0 followed by a block for player 1.
+/// Each block is laid out as:
+/// - `[0]` active Pokemon's HP fraction, `current_hp / max_hp` (0.0 with no active Pokemon)
+/// - `[1..16]` one-hot of the active Pokemon's current types, in `PokemonType::ALL` order
+/// - `[16..23]` stat stages for Atk, Def, SpAtk, SpDef, Spe, Acc, Eva, scaled to `-1.0..=1.0`
+/// - `[23..29]` status one-hot: Sleep, Poison, Burn, Freeze, Paralysis, Faint (all 0.0 if healthy)
+/// - `[29]` non-fainted team members as a fraction of a full team of 6
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeatureVector {
+    values: [f64; FEATURE_LENGTH],
+}
+
+impl FeatureVector {
+    /// Encode the current state of a battle. Does not modify it.
+    pub fn from_state(battle_state: &BattleState) -> Self {
+        let mut values = [0.0; FEATURE_LENGTH];
+        for (player, block) in battle_state
+            .players
+            .iter()
+            .zip(values.chunks_exact_mut(SIDE_FEATURE_LENGTH))
+        {
+            encode_side(player, block);
+        }
+        Self { values }
+    }
+
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+fn encode_side(player: &BattlePlayer, block: &mut [f64]) {
+    let mut offset = 0;
+
+    if let Some(pokemon) = player.active_pokemon() {
+        if pokemon.max_hp() > 0 {
+            block[offset] = f64::from(pokemon.current_hp()) / f64::from(pokemon.max_hp());
+        }
+    }
+    offset += 1;
+
+    if let Some(pokemon) = player.active_pokemon() {
+        let current_types = pokemon.get_current_types(player.battler(0));
+        for (i, pokemon_type) in PokemonType::ALL.iter().enumerate() {
+            if current_types.contains(pokemon_type) {
+                block[offset + i] = 1.0;
+            }
+        }
+    }
+    offset += PokemonType::ALL.len();
+
+    for (i, stat) in ENCODED_STAGES.iter().enumerate() {
+        block[offset + i] = f64::from(player.get_stat_stage(*stat)) / 6.0;
+    }
+    offset += ENCODED_STAGES.len();
+
+    let status_slot = player
+        .active_pokemon()
+        .and_then(|pokemon| pokemon.status)
+        .map(|status| match status {
+            StatusCondition::Sleep(_) => 0,
+            StatusCondition::Poison(_) => 1,
+            StatusCondition::Burn => 2,
+            StatusCondition::Freeze => 3,
+            StatusCondition::Paralysis => 4,
+            StatusCondition::Faint => 5,
+        });
+    if let Some(slot) = status_slot {
+        block[offset + slot] = 1.0;
+    }
+    offset += STATUS_SLOTS;
+
+    let remaining = player
+        .team
+        .iter()
+        .flatten()
+        .filter(|pokemon| !pokemon.is_fainted())
+        .count();
+    block[offset] = remaining as f64 / 6.0;
+}
@@ -0,0 +1,46 @@
+use crate::battle::state::{BattleEvent, BattleState, EventBus};
+
+/// Human-readable lines for a slice of events. Silent events are skipped.
+pub fn format_events(events: &[BattleEvent], battle_state: &BattleState) -> Vec<String> {
+    events
+        .iter()
+        .filter_map(|event| event.format(battle_state))
+        .collect()
+}
+
+/// The "X sent out Y!" lines that open a battle.
+pub fn opening_lines(battle_state: &BattleState) -> Vec<String> {
+    format_events(battle_state.emit_start_events().events(), battle_state)
+}
+
+/// Render a whole battle as plain text for support and debugging.
+///
+/// `initial_state` is the state before the first turn was resolved, and
+/// `per_turn_buses` holds the `EventBus` returned by each `resolve_turn` call, in order.
+/// Only player names are read from the state, so the initial state formats every turn.
+pub fn transcript(initial_state: &BattleState, per_turn_buses: &[EventBus]) -> String {
+    let mut lines = opening_lines(initial_state);
+
+    for bus in per_turn_buses {
+        let turn_lines = format_events(bus.events(), initial_state);
+        if turn_lines.is_empty() {
+            continue;
+        }
+        lines.push(String::new());
+        lines.extend(turn_lines);
+    }
+
+    let finished = per_turn_buses.iter().any(|bus| {
+        bus.events()
+            .iter()
+            .any(|event| matches!(event, BattleEvent::BattleEnded { .. }))
+    });
+    if !finished {
+        lines.push(String::new());
+        lines.push("The battle has not finished.".to_string());
+    }
+
+    let mut text = lines.join("\n");
+    text.push('\n');
+    text
+}
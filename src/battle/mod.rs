@@ -4,10 +4,15 @@ pub mod calculators;
 pub mod catch;
 pub mod commands;
 pub mod conditions;
+pub mod double;
 pub mod engine;
+pub mod features;
+pub mod log;
 pub mod move_effects;
 pub mod progression;
+pub mod simulation;
 pub mod state;
 pub mod stats;
 #[cfg(test)]
 mod tests;
+pub mod usage_stats;
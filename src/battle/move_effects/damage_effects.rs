@@ -3,8 +3,8 @@
 // --- IMPORTS ---
 // Use `super` to get the context types from the parent `mod.rs` file.
 use super::EffectContext;
-use crate::battle::commands::{BattleCommand, PlayerTarget};
-use crate::battle::state::{BattleState, TurnRng};
+use crate::battle::commands::BattleCommand;
+use crate::battle::state::{BattleEvent, BattleState, TurnRng};
 
 // These functions are `pub(super)` to be visible only to the parent `mod.rs`.
 
@@ -15,7 +15,7 @@ pub(super) fn apply_heal_effect(
     state: &BattleState,
 ) -> Vec<BattleCommand> {
     let mut commands = Vec::new();
-    let attacker_player = &state.players[context.attacker_index];
+    let attacker_player = state.battler(context.attacker);
 
     if let Some(attacker_pokemon) = attacker_player.active_pokemon() {
         let max_hp = attacker_pokemon.max_hp();
@@ -26,7 +26,7 @@ pub(super) fn apply_heal_effect(
             let heal_amount = (max_hp as u32 * percentage as u32 / 100) as u16;
             if heal_amount > 0 {
                 commands.push(BattleCommand::HealPokemon {
-                    target: PlayerTarget::from_index(context.attacker_index),
+                    target: context.attacker,
                     amount: heal_amount,
                 });
             }
@@ -35,7 +35,8 @@ pub(super) fn apply_heal_effect(
     commands
 }
 
-/// Apply ante effect (Pay Day).
+/// Apply ante effect (Pay Day). Scatters coins worth twice the user's level into
+/// the user's own ante. Like other secondary effects, this only lands if the move hits.
 pub(super) fn apply_ante_effect(
     chance: u8,
     context: &EffectContext,
@@ -47,13 +48,13 @@ pub(super) fn apply_ante_effect(
         return commands;
     }
 
-    let attacker_player = &state.players[context.attacker_index];
+    let attacker_player = state.battler(context.attacker);
     if let Some(attacker_pokemon) = attacker_player.active_pokemon() {
         let pokemon_level = attacker_pokemon.level as u32;
         let ante_amount = pokemon_level * 2;
 
         commands.push(BattleCommand::AddAnte {
-            target: PlayerTarget::from_index(context.defender_index),
+            target: context.attacker,
             amount: ante_amount,
         });
     }
@@ -64,14 +65,20 @@ pub(super) fn apply_ante_effect(
 pub(super) fn apply_recoil_effect(
     percentage: u8,
     context: &EffectContext,
+    state: &BattleState,
     damage_dealt: u16,
 ) -> Vec<BattleCommand> {
     let mut commands = Vec::new();
     let recoil_damage = (damage_dealt as f32 * percentage as f32 / 100.0).ceil() as u16;
 
     if recoil_damage > 0 {
+        if let Some(attacker_pokemon) = state.battler(context.attacker).active_pokemon() {
+            commands.push(BattleCommand::EmitEvent(BattleEvent::RecoilDamage {
+                target: attacker_pokemon.species,
+            }));
+        }
         commands.push(BattleCommand::DealDamage {
-            target: PlayerTarget::from_index(context.attacker_index),
+            target: context.attacker,
             amount: recoil_damage,
         });
     }
@@ -89,13 +96,16 @@ pub(super) fn apply_drain_effect(
     let heal_amount = (damage_dealt as f32 * percentage as f32 / 100.0).ceil() as u16;
 
     if heal_amount > 0 {
-        let attacker_player = &state.players[context.attacker_index];
+        let attacker_player = state.battler(context.attacker);
         if let Some(attacker_pokemon) = attacker_player.active_pokemon() {
             if !attacker_pokemon.is_fainted()
                 && attacker_pokemon.current_hp() < attacker_pokemon.max_hp()
             {
+                commands.push(BattleCommand::EmitEvent(BattleEvent::DrainHeal {
+                    target: attacker_pokemon.species,
+                }));
                 commands.push(BattleCommand::HealPokemon {
-                    target: PlayerTarget::from_index(context.attacker_index),
+                    target: context.attacker,
                     amount: heal_amount,
                 });
             }
@@ -111,14 +121,18 @@ pub(super) fn apply_reckless_effect(
     state: &BattleState,
 ) -> Vec<BattleCommand> {
     let mut commands = Vec::new();
-    let attacker_player = &state.players[context.attacker_index];
+    let attacker_player = state.battler(context.attacker);
     if let Some(attacker_pokemon) = attacker_player.active_pokemon() {
         let max_hp = attacker_pokemon.max_hp();
         let recoil_damage = (max_hp as f32 * percentage as f32 / 100.0).ceil() as u16;
 
         if recoil_damage > 0 {
+            commands.push(BattleCommand::EmitEvent(BattleEvent::CrashDamage {
+                target: attacker_pokemon.species,
+                move_used: context.move_used,
+            }));
             commands.push(BattleCommand::DealDamage {
-                target: PlayerTarget::from_index(context.attacker_index),
+                target: context.attacker,
                 amount: recoil_damage,
             });
         }
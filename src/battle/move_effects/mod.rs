@@ -9,9 +9,9 @@ mod status_effects;
 
 // --- 2. IMPORTS ---
 use crate::battle::action_stack::BattleAction;
-use crate::battle::commands::BattleCommand;
+use crate::battle::commands::{BattleCommand, PlayerTarget};
 use crate::battle::conditions::PokemonCondition;
-use crate::battle::state::{BattleState, TurnRng};
+use crate::battle::state::{ActionFailureReason, BattleEvent, BattleState, TurnRng};
 use schema::{Move, MoveEffect, Target};
 // Bring the standalone helper functions from our private modules into scope.
 use self::{damage_effects::*, special_effects::*, stat_effects::*, status_effects::*};
@@ -20,28 +20,70 @@ use self::{damage_effects::*, special_effects::*, stat_effects::*, status_effect
 // These are defined here as they are the "public" types for this module.
 #[derive(Debug, Clone)]
 pub struct EffectContext {
-    pub attacker_index: usize,
-    pub defender_index: usize,
+    pub attacker: PlayerTarget,
+    pub defender: PlayerTarget,
     pub move_used: Move,
 }
 
 impl EffectContext {
-    pub fn new(attacker_index: usize, defender_index: usize, move_used: Move) -> Self {
+    pub fn new(attacker: PlayerTarget, defender: PlayerTarget, move_used: Move) -> Self {
         Self {
-            attacker_index,
-            defender_index,
+            attacker,
+            defender,
             move_used,
         }
     }
 
-    pub fn target_index(&self, target: &Target) -> usize {
+    pub fn target(&self, target: &Target) -> PlayerTarget {
         match target {
-            Target::User => self.attacker_index,
-            Target::Target => self.defender_index,
+            Target::User => self.attacker,
+            Target::Target => self.defender,
         }
     }
 }
 
+/// Whether a move does nothing but apply its effects (no base power).
+fn is_status_move(move_used: Move) -> bool {
+    crate::move_data::get_move_data(move_used)
+        .map(|move_data| move_data.power.is_none())
+        .unwrap_or(false)
+}
+
+/// Commands for an effect that would change nothing. For a status move this is a
+/// failure, settled across all its effects by `settle_no_effect`; the secondary effect
+/// of a damaging move is just skipped.
+fn no_effect(context: &EffectContext) -> Vec<BattleCommand> {
+    if is_status_move(context.move_used) {
+        vec![BattleCommand::EmitEvent(BattleEvent::ActionFailed {
+            reason: ActionFailureReason::NoEffect {
+                move_used: context.move_used,
+            },
+        })]
+    } else {
+        Vec::new()
+    }
+}
+
+/// Decide once, across all of a move's effects, whether the move did anything. An
+/// effect with nothing to do reports `no_effect`; the move fails a single time if every
+/// effect came up empty, and otherwise those effects are just dropped.
+pub(crate) fn settle_no_effect(
+    context: &EffectContext,
+    effect_commands: &mut Vec<BattleCommand>,
+    ensured_commands: &[BattleCommand],
+) {
+    let failure = no_effect(context);
+    if failure.is_empty() {
+        return;
+    }
+    let effect_count = effect_commands.len();
+    effect_commands.retain(|command| !failure.contains(command));
+    let any_no_effect = effect_commands.len() < effect_count;
+    if any_no_effect && effect_commands.is_empty() && ensured_commands.is_empty() {
+        effect_commands.extend(failure);
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum EffectResult {
     Continue(Vec<BattleCommand>),
@@ -65,6 +107,12 @@ pub trait BattleMoveEffectExt {
         state: &BattleState,
         rng: &mut TurnRng,
     ) -> EffectResult;
+    /// The side this effect changes. Modifiers that only shape the hit itself
+    /// (priority, crit ratio, multi-hit, ...) report the `Target` they hit.
+    fn default_target(&self) -> Target;
+    /// Whether this effect takes the user's action for itself rather than riding along
+    /// with a normal attack: two-turn setups, and moves like Rest or Transform.
+    fn consumes_turn(&self) -> bool;
 }
 
 // Minimal change: Also include the BattleMoveDataExt trait here for cohesion.
@@ -81,6 +129,9 @@ pub trait BattleMoveDataExt {
         context: &EffectContext,
         state: &BattleState,
     ) -> Vec<BattleCommand>;
+    /// Who the move is aimed at. Moves with no power whose effects only touch the
+    /// user's side (self-buffs, healing, screens, etc.) target the `User`.
+    fn target(&self) -> Target;
 }
 
 // --- 5. THE LEAN IMPLEMENTATIONS ---
@@ -93,12 +144,20 @@ impl BattleMoveEffectExt for MoveEffect {
         state: &BattleState,
         rng: &mut TurnRng,
     ) -> EffectResult {
-        let defender_has_substitute = state.players[context.defender_index]
-            .active_pokemon_conditions
-            .values()
-            .any(|condition| matches!(condition, PokemonCondition::Substitute { .. }));
+        // Only a substitute on the opposing side can block an effect; effects aimed at the
+        // user (Recover, Swords Dance, ...) go through even behind the user's own substitute.
+        let aimed_at_opponent =
+            effect_target(self) == Some(Target::Target) && context.defender != context.attacker;
+        let defender_has_substitute = aimed_at_opponent
+            && state
+                .battler(context.defender)
+                .conditions()
+                .values()
+                .any(|condition| matches!(condition, PokemonCondition::Substitute { .. }));
 
         // NOTE: The call is now to a standalone function `is_blocked_by_substitute(self)`.
+        // Only this effect is dropped: the hit itself (accuracy, PP, damage to the
+        // substitute) is resolved separately in `calculate_attack_outcome`.
         if defender_has_substitute && is_blocked_by_substitute(self) {
             return EffectResult::Continue(Vec::new());
         }
@@ -166,15 +225,59 @@ impl BattleMoveEffectExt for MoveEffect {
                 apply_cure_status_effect(target, status_type, context, state),
             ),
             Self::SetTeamCondition(condition, turns) => {
-                EffectResult::Continue(apply_team_condition_effect(condition, *turns, context))
+                apply_team_condition_effect(condition, *turns, context, state)
             }
             Self::Ante(chance) => {
                 EffectResult::Continue(apply_ante_effect(*chance, context, state, rng))
             }
+            Self::Cooldown(turns) => {
+                EffectResult::Ensured(apply_cooldown_effect(*turns, context, state))
+            }
             _ => EffectResult::Continue(Vec::new()),
         }
     }
 
+    fn default_target(&self) -> Target {
+        match self {
+            MoveEffect::Recoil(_)
+            | MoveEffect::Drain(_)
+            | MoveEffect::Reckless(_)
+            | MoveEffect::Exhaust(_)
+            | MoveEffect::Explode
+            | MoveEffect::InAir
+            | MoveEffect::Underground
+            | MoveEffect::ChargeUp
+            | MoveEffect::Rampage
+            | MoveEffect::Rage(_)
+            | MoveEffect::Transform
+            | MoveEffect::Conversion
+            | MoveEffect::Counter
+            | MoveEffect::Bide(_)
+            | MoveEffect::MirrorMove
+            | MoveEffect::Metronome
+            | MoveEffect::Cooldown(_) => Target::User,
+            _ => effect_target(self).unwrap_or(Target::Target),
+        }
+    }
+
+    fn consumes_turn(&self) -> bool {
+        matches!(
+            self,
+            MoveEffect::InAir
+                | MoveEffect::Underground
+                | MoveEffect::ChargeUp
+                | MoveEffect::Teleport(_)
+                | MoveEffect::Transform
+                | MoveEffect::Conversion
+                | MoveEffect::Substitute
+                | MoveEffect::Counter
+                | MoveEffect::Bide(_)
+                | MoveEffect::MirrorMove
+                | MoveEffect::Rest(_)
+                | MoveEffect::Metronome
+        )
+    }
+
     fn apply_multi_hit_continuation(
         &self,
         context: &EffectContext,
@@ -194,8 +297,8 @@ impl BattleMoveEffectExt for MoveEffect {
 
             if should_queue_next_hit {
                 return Some(BattleCommand::PushAction(BattleAction::AttackHit {
-                    attacker_index: context.attacker_index,
-                    defender_index: context.defender_index,
+                    attacker: context.attacker,
+                    defender: context.defender,
                     move_used: context.move_used,
                     hit_number: next_hit_number,
                 }));
@@ -230,12 +333,50 @@ fn is_blocked_by_substitute(effect: &MoveEffect) -> bool {
         | MoveEffect::MultiHit(_, _)
         | MoveEffect::Haze(_)
         | MoveEffect::SetTeamCondition(..) => false,
+        // Charging, semi-invulnerable turns, rampages and fleeing only affect the user.
+        MoveEffect::InAir
+        | MoveEffect::Underground
+        | MoveEffect::ChargeUp
+        | MoveEffect::Rampage
+        | MoveEffect::Teleport(_)
+        | MoveEffect::Cooldown(_) => false,
+        // Inflicted conditions check the substitute in `PokemonCondition::can_apply_to`.
+        MoveEffect::Flinch(_)
+        | MoveEffect::Confuse(_)
+        | MoveEffect::Seed(_)
+        | MoveEffect::Trap(_) => false,
         MoveEffect::StatChange(target, ..) => matches!(target, Target::Target),
         MoveEffect::CureStatus(target, ..) => matches!(target, Target::Target),
         _ => true,
     }
 }
 
+/// Whether an effect is aimed at the user's own side rather than the opponent.
+/// Pure modifiers (priority, crit ratio, recoil, ...) don't aim anywhere and return `None`.
+fn effect_target(effect: &MoveEffect) -> Option<Target> {
+    match effect {
+        MoveEffect::Priority(_)
+        | MoveEffect::Crit(_)
+        | MoveEffect::IgnoreDef(_)
+        | MoveEffect::MultiHit(_, _)
+        | MoveEffect::Recoil(_)
+        | MoveEffect::Drain(_)
+        | MoveEffect::Reckless(_) => None,
+        MoveEffect::StatChange(target, ..) | MoveEffect::CureStatus(target, ..) => {
+            Some(target.clone())
+        }
+        MoveEffect::RaiseAllStats(_)
+        | MoveEffect::Heal(_)
+        | MoveEffect::Rest(_)
+        | MoveEffect::Substitute
+        | MoveEffect::Haze(_)
+        | MoveEffect::Teleport(_)
+        | MoveEffect::Cooldown(_)
+        | MoveEffect::SetTeamCondition(..) => Some(Target::User),
+        _ => Some(Target::Target),
+    }
+}
+
 // The implementation for MoveDataExt also lives here.
 impl BattleMoveDataExt for MoveData {
     fn apply_damage_based_effects(
@@ -251,7 +392,12 @@ impl BattleMoveDataExt for MoveData {
             }
             match effect {
                 MoveEffect::Recoil(percentage) => {
-                    all_commands.extend(apply_recoil_effect(*percentage, context, damage_dealt));
+                    all_commands.extend(apply_recoil_effect(
+                        *percentage,
+                        context,
+                        state,
+                        damage_dealt,
+                    ));
                 }
                 MoveEffect::Drain(percentage) => {
                     all_commands.extend(apply_drain_effect(
@@ -283,4 +429,57 @@ impl BattleMoveDataExt for MoveData {
         }
         all_commands
     }
+
+    fn target(&self) -> Target {
+        if self.power.is_some() {
+            return Target::Target;
+        }
+
+        let aims_at_opponent = self
+            .effects
+            .iter()
+            .filter_map(effect_target)
+            .any(|target| target == Target::Target);
+
+        if aims_at_opponent {
+            Target::Target
+        } else {
+            Target::User
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use schema::{StatType, StatusType};
+
+    #[test]
+    fn test_effects_report_default_target() {
+        assert_eq!(
+            MoveEffect::StatChange(Target::User, StatType::Atk, 2, 100).default_target(),
+            Target::User
+        );
+        assert_eq!(
+            MoveEffect::StatChange(Target::Target, StatType::Def, -1, 100).default_target(),
+            Target::Target
+        );
+        assert_eq!(MoveEffect::Burn(10).default_target(), Target::Target);
+        assert_eq!(MoveEffect::Heal(50).default_target(), Target::User);
+        assert_eq!(MoveEffect::Recoil(25).default_target(), Target::User);
+        assert_eq!(MoveEffect::Priority(1).default_target(), Target::Target);
+        assert_eq!(
+            MoveEffect::CureStatus(Target::User, StatusType::Sleep).default_target(),
+            Target::User
+        );
+    }
+
+    #[test]
+    fn test_effects_report_whether_they_consume_the_turn() {
+        assert!(MoveEffect::ChargeUp.consumes_turn());
+        assert!(MoveEffect::Rest(2).consumes_turn());
+        assert!(!MoveEffect::Burn(10).consumes_turn());
+        assert!(!MoveEffect::Exhaust(100).consumes_turn());
+        assert!(!MoveEffect::Priority(1).consumes_turn());
+    }
 }
@@ -3,17 +3,17 @@
 // --- IMPORTS ---
 use super::{EffectContext, EffectResult};
 use crate::battle::action_stack::BattleAction;
-use crate::battle::commands::{BattleCommand, PlayerTarget};
-use crate::battle::conditions::{PokemonCondition, PokemonConditionType};
+use crate::battle::commands::BattleCommand;
+use crate::battle::conditions::{PokemonCondition, PokemonConditionType, TRANSFORM_MOVE_PP};
 use crate::battle::state::{ActionFailureReason, BattleEvent, BattleState, TurnRng};
-use crate::pokemon::StatusCondition;
-use schema::{Move, TeamCondition};
+use crate::pokemon::{MoveInstance, StatusCondition};
+use schema::{Move, MoveCategory, TeamCondition};
 
 // --- STANDALONE HELPER FUNCTIONS ---
 
 pub(super) fn apply_in_air_special(context: &EffectContext, state: &BattleState) -> EffectResult {
-    let attacker_player = &state.players[context.attacker_index];
-    let attacker_target = PlayerTarget::from_index(context.attacker_index);
+    let attacker_player = state.battler(context.attacker);
+    let attacker_target = context.attacker;
 
     if attacker_player.has_condition_type(PokemonConditionType::InAir) {
         let commands = vec![BattleCommand::RemoveCondition {
@@ -35,10 +35,10 @@ pub(super) fn apply_in_air_special(context: &EffectContext, state: &BattleState)
 }
 
 pub(super) fn apply_teleport_special(context: &EffectContext, state: &BattleState) -> EffectResult {
-    let attacker_player = &state.players[context.attacker_index];
+    let attacker_player = state.battler(context.attacker);
     if attacker_player.active_pokemon().is_some() {
         let commands = vec![BattleCommand::AddCondition {
-            target: PlayerTarget::from_index(context.attacker_index),
+            target: context.attacker,
             condition: PokemonCondition::Teleported,
         }];
         return EffectResult::Skip(commands);
@@ -50,8 +50,8 @@ pub(super) fn apply_charge_up_special(
     context: &EffectContext,
     state: &BattleState,
 ) -> EffectResult {
-    let attacker_player = &state.players[context.attacker_index];
-    let attacker_target = PlayerTarget::from_index(context.attacker_index);
+    let attacker_player = state.battler(context.attacker);
+    let attacker_target = context.attacker;
 
     if attacker_player.has_condition_type(PokemonConditionType::Charging) {
         let commands = vec![BattleCommand::RemoveCondition {
@@ -75,8 +75,8 @@ pub(super) fn apply_underground_special(
     context: &EffectContext,
     state: &BattleState,
 ) -> EffectResult {
-    let attacker_player = &state.players[context.attacker_index];
-    let attacker_target = PlayerTarget::from_index(context.attacker_index);
+    let attacker_player = state.battler(context.attacker);
+    let attacker_target = context.attacker;
 
     if attacker_player.has_condition_type(PokemonConditionType::Underground) {
         let commands = vec![BattleCommand::RemoveCondition {
@@ -100,19 +100,45 @@ pub(super) fn apply_transform_special(
     context: &EffectContext,
     state: &BattleState,
 ) -> EffectResult {
-    let attacker_player = &state.players[context.attacker_index];
-    let defender_player = &state.players[context.defender_index];
+    let attacker_player = state.battler(context.attacker);
+    let defender_player = state.battler(context.defender);
 
-    if let (Some(_), Some(target_pokemon)) = (
+    if let (Some(attacker_pokemon), Some(target_pokemon)) = (
         attacker_player.active_pokemon(),
         defender_player.active_pokemon().cloned(),
     ) {
-        let commands = vec![BattleCommand::AddCondition {
-            target: PlayerTarget::from_index(context.attacker_index),
-            condition: PokemonCondition::Transformed {
-                target: target_pokemon,
+        // Transforming again keeps the moveset saved by the first Transform.
+        let original_moves = attacker_player
+            .conditions()
+            .values()
+            .find_map(|c| match c {
+                PokemonCondition::Transformed { original_moves, .. } => {
+                    Some(original_moves.clone())
+                }
+                _ => None,
+            })
+            .unwrap_or_else(|| attacker_pokemon.moves.clone());
+        let copied_moves = target_pokemon.moves.clone().map(|slot| {
+            slot.map(|inst| MoveInstance {
+                pp: TRANSFORM_MOVE_PP.min(inst.max_pp()),
+                move_: inst.move_,
+            })
+        });
+
+        let target = context.attacker;
+        let commands = vec![
+            BattleCommand::AddCondition {
+                target,
+                condition: PokemonCondition::Transformed {
+                    target: target_pokemon,
+                    original_moves,
+                },
             },
-        }];
+            BattleCommand::ReplaceMoveset {
+                target,
+                moves: copied_moves,
+            },
+        ];
         return EffectResult::Skip(commands);
     }
     EffectResult::Continue(Vec::new())
@@ -122,8 +148,8 @@ pub(super) fn apply_conversion_special(
     context: &EffectContext,
     state: &BattleState,
 ) -> EffectResult {
-    let attacker_player = &state.players[context.attacker_index];
-    let defender_player = &state.players[context.defender_index];
+    let attacker_player = state.battler(context.attacker);
+    let defender_player = state.battler(context.defender);
 
     if let (Some(_), Some(target_type)) = (
         attacker_player.active_pokemon(),
@@ -132,7 +158,7 @@ pub(super) fn apply_conversion_special(
             .and_then(|p| p.get_current_types(defender_player).into_iter().next()),
     ) {
         let commands = vec![BattleCommand::AddCondition {
-            target: PlayerTarget::from_index(context.attacker_index),
+            target: context.attacker,
             condition: PokemonCondition::Converted {
                 pokemon_type: target_type,
             },
@@ -147,8 +173,8 @@ pub(super) fn apply_substitute_special(
     state: &BattleState,
 ) -> EffectResult {
     // Get the attacker's active Pokémon instance.
-    if let Some(attacker_pokemon) = state.players[context.attacker_index].active_pokemon() {
-        let attacker_target = PlayerTarget::from_index(context.attacker_index);
+    if let Some(attacker_pokemon) = state.battler(context.attacker).active_pokemon() {
+        let attacker_target = context.attacker;
 
         // Calculate the HP cost, which is 25% of the user's max HP.
         let hp_cost = (attacker_pokemon.max_hp() / 4).max(1);
@@ -195,18 +221,25 @@ pub(super) fn apply_substitute_special(
     EffectResult::Continue(Vec::new())
 }
 
+/// Counter hits back for twice the damage of the last hit its user took this turn, as
+/// long as that hit was physical. It moves late so that hit has already landed.
 pub(super) fn apply_counter_special(context: &EffectContext, state: &BattleState) -> EffectResult {
-    if state.players[context.attacker_index]
-        .active_pokemon()
-        .is_some()
-    {
-        let commands = vec![BattleCommand::AddCondition {
-            target: PlayerTarget::from_index(context.attacker_index),
-            condition: PokemonCondition::Countering { damage: 0 },
-        }];
-        return EffectResult::Skip(commands);
+    let user = state.battler(context.attacker);
+    if user.active_pokemon().is_none() {
+        return EffectResult::Continue(Vec::new());
     }
-    EffectResult::Continue(Vec::new())
+    let commands = match user.last_damage_taken() {
+        Some(hit) if hit.category == MoveCategory::Physical => vec![BattleCommand::DealDamage {
+            target: context.defender,
+            amount: hit.damage * 2,
+        }],
+        _ => vec![BattleCommand::EmitEvent(BattleEvent::ActionFailed {
+            reason: ActionFailureReason::MoveFailedToExecute {
+                move_used: context.move_used,
+            },
+        })],
+    };
+    EffectResult::Skip(commands)
 }
 
 pub(super) fn apply_rampage_special(
@@ -214,13 +247,13 @@ pub(super) fn apply_rampage_special(
     state: &BattleState,
     rng: &mut TurnRng,
 ) -> EffectResult {
-    let attacker_player = &state.players[context.attacker_index];
+    let attacker_player = state.battler(context.attacker);
     if attacker_player.active_pokemon().is_none() {
         return EffectResult::Continue(Vec::new());
     }
 
     if let Some(PokemonCondition::Rampaging { turns_remaining }) = attacker_player
-        .active_pokemon_conditions
+        .conditions()
         .values()
         .find(|c| matches!(c, PokemonCondition::Rampaging { .. }))
     {
@@ -228,7 +261,7 @@ pub(super) fn apply_rampage_special(
             return EffectResult::Continue(Vec::new());
         } else {
             let commands = vec![BattleCommand::AddCondition {
-                target: PlayerTarget::from_index(context.attacker_index),
+                target: context.attacker,
                 condition: PokemonCondition::Confused { turns_remaining: 2 },
             }];
             return EffectResult::Continue(commands);
@@ -241,7 +274,7 @@ pub(super) fn apply_rampage_special(
         2
     };
     let commands = vec![BattleCommand::AddCondition {
-        target: PlayerTarget::from_index(context.attacker_index),
+        target: context.attacker,
         condition: PokemonCondition::Rampaging {
             turns_remaining: turns,
         },
@@ -250,12 +283,9 @@ pub(super) fn apply_rampage_special(
 }
 
 pub(super) fn apply_rage_special(context: &EffectContext, state: &BattleState) -> EffectResult {
-    if state.players[context.attacker_index]
-        .active_pokemon()
-        .is_some()
-    {
+    if state.battler(context.attacker).active_pokemon().is_some() {
         let commands = vec![BattleCommand::AddCondition {
-            target: PlayerTarget::from_index(context.attacker_index),
+            target: context.attacker,
             condition: PokemonCondition::Enraged,
         }];
         return EffectResult::Continue(commands);
@@ -264,9 +294,9 @@ pub(super) fn apply_rage_special(context: &EffectContext, state: &BattleState) -
 }
 
 pub(super) fn apply_explode_special(context: &EffectContext, state: &BattleState) -> EffectResult {
-    if let Some(attacker_pokemon) = state.players[context.attacker_index].active_pokemon() {
+    if let Some(attacker_pokemon) = state.battler(context.attacker).active_pokemon() {
         let commands = vec![BattleCommand::DealDamage {
-            target: PlayerTarget::from_index(context.attacker_index),
+            target: context.attacker,
             amount: attacker_pokemon.current_hp(),
         }];
         return EffectResult::Ensured(commands);
@@ -274,17 +304,22 @@ pub(super) fn apply_explode_special(context: &EffectContext, state: &BattleState
     EffectResult::Ensured(Vec::new())
 }
 
+/// Bide stores damage on the user's condition rather than remembering who dealt it.
+/// The release targets the defending *player*, so the stored damage lands on whichever
+/// Pokemon is active for that player at release time (e.g. a freshly switched-in one).
+///
+/// As in Gen 1, the release is typeless: exactly double the stored damage is dealt
+/// directly, so type immunity doesn't apply (it hits Ghosts). Being direct damage, it
+/// also goes straight past a Substitute to the Pokemon behind it.
 pub(super) fn apply_bide_special(
     turns: u8,
     context: &EffectContext,
     state: &BattleState,
 ) -> EffectResult {
-    let attacker_player = &state.players[context.attacker_index];
+    let attacker_player = state.battler(context.attacker);
 
-    if let Some((turns_remaining, stored_damage)) = attacker_player
-        .active_pokemon_conditions
-        .values()
-        .find_map(|c| match c {
+    if let Some((turns_remaining, stored_damage)) =
+        attacker_player.conditions().values().find_map(|c| match c {
             PokemonCondition::Biding {
                 turns_remaining,
                 damage,
@@ -293,9 +328,10 @@ pub(super) fn apply_bide_special(
         })
     {
         if turns_remaining < 1 {
+            // Resolve the target now; the original attacker may have switched out.
             let damage_to_deal = (stored_damage * 2).max(1);
             let commands = vec![BattleCommand::DealDamage {
-                target: PlayerTarget::from_index(context.defender_index),
+                target: context.defender,
                 amount: damage_to_deal,
             }];
             return EffectResult::Skip(commands);
@@ -305,7 +341,7 @@ pub(super) fn apply_bide_special(
     } else {
         if attacker_player.active_pokemon().is_some() {
             let commands = vec![BattleCommand::AddCondition {
-                target: PlayerTarget::from_index(context.attacker_index),
+                target: context.attacker,
                 condition: PokemonCondition::Biding {
                     turns_remaining: turns,
                     damage: 0,
@@ -322,22 +358,25 @@ pub(super) fn apply_rest_special(
     context: &EffectContext,
     state: &BattleState,
 ) -> EffectResult {
-    if let Some(attacker_pokemon) = state.players[context.attacker_index].active_pokemon() {
+    if let Some(attacker_pokemon) = state.battler(context.attacker).active_pokemon() {
         let mut commands = Vec::new();
         if attacker_pokemon.current_hp() < attacker_pokemon.max_hp() {
             commands.push(BattleCommand::HealPokemon {
-                target: PlayerTarget::from_index(context.attacker_index),
+                target: context.attacker,
                 amount: attacker_pokemon.max_hp(),
             });
         }
+        // Rest's sleep is self-inflicted, so it is never recorded for the sleep clause.
+        // The Toxic counter lives inside the Poison status, so curing it here clears
+        // badly poisoned state entirely (switching out only resets it; see switch-in).
         if let Some(existing_status) = attacker_pokemon.status {
             commands.push(BattleCommand::CurePokemonStatus {
-                target: PlayerTarget::from_index(context.attacker_index),
+                target: context.attacker,
                 status: existing_status,
             });
         }
         commands.push(BattleCommand::SetPokemonStatus {
-            target: PlayerTarget::from_index(context.attacker_index),
+            target: context.attacker,
             status: StatusCondition::Sleep(sleep_turns),
         });
         return EffectResult::Skip(commands);
@@ -349,9 +388,14 @@ pub(super) fn apply_mirror_move_special(
     context: &EffectContext,
     state: &BattleState,
 ) -> EffectResult {
-    let defender_player = &state.players[context.defender_index];
-    if let Some(mirrored_move) = defender_player.last_move {
-        if mirrored_move == Move::MirrorMove {
+    let defender_player = state.battler(context.defender);
+    // last_move is only recorded once a move gets past action prevention, so a
+    // move the target failed to use is never copied.
+    if let Some(mirrored_move) = defender_player.last_move() {
+        let is_mirrorable = crate::move_data::get_move_data(mirrored_move)
+            .map(|data| data.mirrorable())
+            .unwrap_or(false);
+        if !is_mirrorable {
             return EffectResult::Skip(vec![BattleCommand::EmitEvent(BattleEvent::ActionFailed {
                 reason: ActionFailureReason::MoveFailedToExecute {
                     move_used: Move::MirrorMove,
@@ -359,16 +403,16 @@ pub(super) fn apply_mirror_move_special(
             })]);
         }
 
-        if let Some(attacker_pokemon) = state.players[context.attacker_index].active_pokemon() {
+        if let Some(attacker_pokemon) = state.battler(context.attacker).active_pokemon() {
             let mirrored_action = BattleAction::AttackHit {
-                attacker_index: context.attacker_index,
-                defender_index: context.defender_index,
+                attacker: context.attacker,
+                defender: context.defender,
                 move_used: mirrored_move,
                 hit_number: 1, // Must be >0 to avoid using PP
             };
             return EffectResult::Skip(vec![
                 BattleCommand::EmitEvent(BattleEvent::MoveUsed {
-                    player_index: context.attacker_index,
+                    player_index: context.attacker.to_index(),
                     pokemon: attacker_pokemon.species,
                     move_used: mirrored_move,
                 }),
@@ -432,16 +476,16 @@ pub(super) fn apply_metronome_special(
         .find_map(|index| Move::from_index(index).filter(|&mov| !is_excluded_from_metronome(mov)));
 
     if let Some(selected_move) = selected_move {
-        if let Some(attacker_pokemon) = state.players[context.attacker_index].active_pokemon() {
+        if let Some(attacker_pokemon) = state.battler(context.attacker).active_pokemon() {
             let metronome_action = BattleAction::AttackHit {
-                attacker_index: context.attacker_index,
-                defender_index: context.defender_index,
+                attacker: context.attacker,
+                defender: context.defender,
                 move_used: selected_move,
                 hit_number: 1,
             };
             let commands = vec![
                 BattleCommand::EmitEvent(BattleEvent::MoveUsed {
-                    player_index: context.attacker_index,
+                    player_index: context.attacker.to_index(),
                     pokemon: attacker_pokemon.species,
                     move_used: selected_move,
                 }),
@@ -454,14 +498,28 @@ pub(super) fn apply_metronome_special(
     EffectResult::Continue(Vec::new())
 }
 
+/// Sets up a team condition like Reflect or Mist on the user's side.
+/// As in Gen 1, using it again while it's already up fails instead of refreshing the timer.
 pub(super) fn apply_team_condition_effect(
     condition: &TeamCondition,
     turns: u8,
     context: &EffectContext,
-) -> Vec<BattleCommand> {
-    vec![BattleCommand::AddTeamCondition {
-        target: PlayerTarget::from_index(context.attacker_index),
+    state: &BattleState,
+) -> EffectResult {
+    if state
+        .battler(context.attacker)
+        .has_team_condition(condition)
+    {
+        return EffectResult::Skip(vec![BattleCommand::EmitEvent(BattleEvent::ActionFailed {
+            reason: ActionFailureReason::AlreadyActive {
+                move_used: context.move_used,
+            },
+        })]);
+    }
+
+    EffectResult::Continue(vec![BattleCommand::AddTeamCondition {
+        target: context.attacker,
         condition: *condition,
         turns,
-    }]
+    }])
 }
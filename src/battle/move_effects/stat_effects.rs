@@ -2,9 +2,10 @@
 
 // --- IMPORTS ---
 // Use `super` to get the context types from the parent `mod.rs` file.
-use super::EffectContext;
-use crate::battle::commands::{BattleCommand, PlayerTarget};
+use super::{is_status_move, no_effect, EffectContext};
+use crate::battle::commands::BattleCommand;
 use crate::battle::state::{BattleEvent, BattleState, TurnRng};
+use crate::pokemon::StatusCondition;
 use schema::{StatType, Target, TeamCondition};
 
 // --- STANDALONE HELPER FUNCTIONS ---
@@ -26,17 +27,20 @@ pub(super) fn apply_stat_change_effect(
         return commands;
     }
 
-    let target_index = context.target_index(target);
-    let target_player = &state.players[target_index];
+    let target_slot = context.target(target);
+    let target_player = state.battler(target_slot);
 
     if let Some(target_pokemon) = target_player.active_pokemon() {
         // Check if Mist prevents this stat change
-        let is_enemy_move = target_index != context.attacker_index;
+        let is_enemy_move = target_slot != context.attacker;
         let is_negative_change = stages < 0;
         let has_mist = target_player.has_team_condition(&TeamCondition::Mist);
 
         if is_enemy_move && is_negative_change && has_mist {
-            // Mist prevents the stat change
+            // Mist prevents the stat change; a damaging move's secondary drop is skipped silently.
+            if !is_status_move(context.move_used) {
+                return commands;
+            }
             commands.push(BattleCommand::EmitEvent(BattleEvent::StatChangeBlocked {
                 target: target_pokemon.species,
                 stat: *stat,
@@ -46,13 +50,15 @@ pub(super) fn apply_stat_change_effect(
             let old_stage = target_player.get_stat_stage(*stat);
             let new_stage = (old_stage + stages).clamp(-6, 6);
 
-            if old_stage != new_stage {
-                commands.push(BattleCommand::ModifyStatStage {
-                    target: PlayerTarget::from_index(target_index),
-                    stat: *stat,
-                    delta: new_stage - old_stage,
-                });
+            if old_stage == new_stage {
+                // The stat is already at its limit
+                return no_effect(context);
             }
+            commands.push(BattleCommand::ModifyStatStage {
+                target: target_slot,
+                stat: *stat,
+                delta: new_stage - old_stage,
+            });
         }
     }
     commands
@@ -70,7 +76,7 @@ pub(super) fn apply_raise_all_stats_effect(
         return commands;
     }
 
-    let attacker_player = &state.players[context.attacker_index];
+    let attacker_player = state.battler(context.attacker);
     if attacker_player.active_pokemon().is_some() {
         let stats_to_raise = [
             StatType::Atk,
@@ -85,7 +91,7 @@ pub(super) fn apply_raise_all_stats_effect(
             if old_stage < 6 {
                 // Only raise if not already maxed out
                 commands.push(BattleCommand::ModifyStatStage {
-                    target: PlayerTarget::from_index(context.attacker_index),
+                    target: context.attacker,
                     stat: *stat,
                     delta: 1,
                 });
@@ -95,7 +101,7 @@ pub(super) fn apply_raise_all_stats_effect(
     commands
 }
 
-/// Apply haze effect (clears all stat stages for both players).
+/// Apply haze effect (clears all stat stages for both players and thaws frozen Pokémon).
 pub(super) fn apply_haze_effect(
     chance: u8,
     _context: &EffectContext,
@@ -107,9 +113,9 @@ pub(super) fn apply_haze_effect(
         return commands;
     }
 
-    // Clear stat stages for both players
-    for player_index in 0..2 {
-        let player = &state.players[player_index];
+    // Clear stat stages for every Pokemon on the field
+    for target in state.field_targets() {
+        let player = state.battler(target);
         if player.active_pokemon().is_some() {
             let all_stats = [
                 StatType::Atk,
@@ -126,13 +132,22 @@ pub(super) fn apply_haze_effect(
                 let current_stage = player.get_stat_stage(*stat);
                 if current_stage != 0 {
                     commands.push(BattleCommand::ModifyStatStage {
-                        target: PlayerTarget::from_index(player_index),
+                        target,
                         stat: *stat,
                         delta: -current_stage, // Reset to 0
                     });
                 }
             }
         }
+        // Haze is one of the few ways out of Gen 1's permanent freeze.
+        if let Some(pokemon) = player.active_pokemon() {
+            if pokemon.status == Some(StatusCondition::Freeze) {
+                commands.push(BattleCommand::CurePokemonStatus {
+                    target: target,
+                    status: StatusCondition::Freeze,
+                });
+            }
+        }
     }
     commands
 }
@@ -1,9 +1,9 @@
 // In: src/battle/move_effects/status_effects.rs
 
 // --- IMPORTS ---
-use super::EffectContext;
-use crate::battle::commands::{BattleCommand, PlayerTarget};
-use crate::battle::conditions::PokemonCondition;
+use super::{no_effect, EffectContext};
+use crate::battle::commands::BattleCommand;
+use crate::battle::conditions::{PokemonCondition, PokemonConditionType};
 use crate::battle::state::{BattleState, TurnRng};
 use crate::pokemon::StatusCondition;
 use schema::{PokemonType, StatusType, Target};
@@ -17,7 +17,7 @@ pub(super) fn apply_burn_effect(
     rng: &mut TurnRng,
 ) -> Vec<BattleCommand> {
     let mut commands = Vec::new();
-    let target_player = &state.players[context.defender_index];
+    let target_player = state.battler(context.defender);
 
     if let Some(target_pokemon) = target_player.active_pokemon() {
         if target_pokemon.status.is_some()
@@ -25,12 +25,12 @@ pub(super) fn apply_burn_effect(
                 .get_current_types(target_player)
                 .contains(&PokemonType::Fire)
         {
-            return commands;
+            return no_effect(context);
         }
 
         if rng.next_outcome("Apply Burn Check") <= chance {
             commands.push(BattleCommand::SetPokemonStatus {
-                target: PlayerTarget::from_index(context.defender_index),
+                target: context.defender,
                 status: StatusCondition::Burn,
             });
         }
@@ -45,7 +45,7 @@ pub(super) fn apply_paralyze_effect(
     rng: &mut TurnRng,
 ) -> Vec<BattleCommand> {
     let mut commands = Vec::new();
-    let target_player = &state.players[context.defender_index];
+    let target_player = state.battler(context.defender);
 
     if let Some(target_pokemon) = target_player.active_pokemon() {
         if target_pokemon.status.is_some()
@@ -53,12 +53,12 @@ pub(super) fn apply_paralyze_effect(
                 .get_current_types(target_player)
                 .contains(&PokemonType::Electric)
         {
-            return commands;
+            return no_effect(context);
         }
 
         if rng.next_outcome("Apply Paralysis Check") <= chance {
             commands.push(BattleCommand::SetPokemonStatus {
-                target: PlayerTarget::from_index(context.defender_index),
+                target: context.defender,
                 status: StatusCondition::Paralysis,
             });
         }
@@ -73,7 +73,7 @@ pub(super) fn apply_freeze_effect(
     rng: &mut TurnRng,
 ) -> Vec<BattleCommand> {
     let mut commands = Vec::new();
-    let target_player = &state.players[context.defender_index];
+    let target_player = state.battler(context.defender);
 
     if let Some(target_pokemon) = target_player.active_pokemon() {
         if target_pokemon.status.is_some()
@@ -81,12 +81,12 @@ pub(super) fn apply_freeze_effect(
                 .get_current_types(target_player)
                 .contains(&PokemonType::Ice)
         {
-            return commands;
+            return no_effect(context);
         }
 
         if rng.next_outcome("Apply Freeze Check") <= chance {
             commands.push(BattleCommand::SetPokemonStatus {
-                target: PlayerTarget::from_index(context.defender_index),
+                target: context.defender,
                 status: StatusCondition::Freeze,
             });
         }
@@ -101,7 +101,7 @@ pub(super) fn apply_poison_effect(
     rng: &mut TurnRng,
 ) -> Vec<BattleCommand> {
     let mut commands = Vec::new();
-    let target_player = &state.players[context.defender_index];
+    let target_player = state.battler(context.defender);
 
     if let Some(target_pokemon) = target_player.active_pokemon() {
         if target_pokemon.status.is_some()
@@ -109,12 +109,12 @@ pub(super) fn apply_poison_effect(
                 .get_current_types(target_player)
                 .contains(&PokemonType::Poison)
         {
-            return commands;
+            return no_effect(context);
         }
 
         if rng.next_outcome("Apply Poison Check") <= chance {
             commands.push(BattleCommand::SetPokemonStatus {
-                target: PlayerTarget::from_index(context.defender_index),
+                target: context.defender,
                 status: StatusCondition::Poison(0),
             });
         }
@@ -129,20 +129,24 @@ pub(super) fn apply_sedate_effect(
     rng: &mut TurnRng,
 ) -> Vec<BattleCommand> {
     let mut commands = Vec::new();
-    let target_player = &state.players[context.defender_index];
+    let target_player = state.battler(context.defender);
 
     if let Some(target_pokemon) = target_player.active_pokemon() {
         // In Gen 1, no types are immune to sleep.
-        if target_pokemon.status.is_some() {
-            return commands;
+        if target_pokemon.status.is_some() || state.sleep_clause_blocks(context.defender.to_index())
+        {
+            return no_effect(context);
         }
 
         if rng.next_outcome("Apply Sedate Check") <= chance {
             let sleep_turns = (rng.next_outcome("Generate Sleep Duration") % 3) + 1;
             commands.push(BattleCommand::SetPokemonStatus {
-                target: PlayerTarget::from_index(context.defender_index),
+                target: context.defender,
                 status: StatusCondition::Sleep(sleep_turns),
             });
+            commands.push(BattleCommand::RecordInducedSleep {
+                target: context.defender,
+            });
         }
     }
     commands
@@ -155,18 +159,24 @@ pub(super) fn apply_flinch_effect(
     rng: &mut TurnRng,
 ) -> Vec<BattleCommand> {
     let mut commands = Vec::new();
-    if rng.next_outcome("Apply Flinch Effect") > chance {
+    let target_player = state.battler(context.defender);
+    let Some(target_pokemon) = target_player.active_pokemon() else {
+        return commands;
+    };
+    if !PokemonCondition::Flinched.can_apply_to(target_pokemon, target_player) {
         return commands;
     }
-    if state.players[context.defender_index]
-        .active_pokemon()
-        .is_some()
-    {
-        commands.push(BattleCommand::AddCondition {
-            target: PlayerTarget::from_index(context.defender_index),
-            condition: PokemonCondition::Flinched,
-        });
+    // A Pokemon that has already moved this turn can't be made to flinch.
+    if state.has_acted(context.defender) {
+        return commands;
+    }
+    if rng.next_outcome("Apply Flinch Effect") > chance {
+        return commands;
     }
+    commands.push(BattleCommand::AddCondition {
+        target: context.defender,
+        condition: PokemonCondition::Flinched,
+    });
     commands
 }
 
@@ -177,15 +187,19 @@ pub(super) fn apply_confuse_effect(
     rng: &mut TurnRng,
 ) -> Vec<BattleCommand> {
     let mut commands = Vec::new();
-    let target_player = &state.players[context.defender_index];
-    if target_player.active_pokemon().is_none() {
+    let target_player = state.battler(context.defender);
+    let Some(target_pokemon) = target_player.active_pokemon() else {
         return commands;
+    };
+    let condition = PokemonCondition::Confused { turns_remaining: 0 };
+    if !condition.can_apply_to(target_pokemon, target_player) {
+        return no_effect(context);
     }
 
     if rng.next_outcome("Apply Confuse Effect") <= chance {
         let confuse_turns = (rng.next_outcome("Generate Confusion Duration") % 4) + 1;
         commands.push(BattleCommand::AddCondition {
-            target: PlayerTarget::from_index(context.defender_index),
+            target: context.defender,
             condition: PokemonCondition::Confused {
                 turns_remaining: confuse_turns,
             },
@@ -194,6 +208,17 @@ pub(super) fn apply_confuse_effect(
     commands
 }
 
+/// Map a 1-100 roll onto the Gen 1 partial-trap length: 2 or 3 turns (3/8 each),
+/// 4 or 5 turns (1/8 each).
+fn partial_trap_duration(roll: u8) -> u8 {
+    match roll {
+        0..=37 => 2,
+        38..=75 => 3,
+        76..=88 => 4,
+        _ => 5,
+    }
+}
+
 pub(super) fn apply_trap_effect(
     chance: u8,
     context: &EffectContext,
@@ -201,19 +226,38 @@ pub(super) fn apply_trap_effect(
     rng: &mut TurnRng,
 ) -> Vec<BattleCommand> {
     let mut commands = Vec::new();
-    if state.players[context.defender_index]
-        .active_pokemon()
-        .is_none()
+    // A trapper already locked into its move is just continuing the same trap.
+    if state
+        .battler(context.attacker)
+        .has_condition_type(PokemonConditionType::Trapping)
     {
         return commands;
     }
+    let target_player = state.battler(context.defender);
+    let Some(target_pokemon) = target_player.active_pokemon() else {
+        return commands;
+    };
+    let condition = PokemonCondition::Trapped { turns_remaining: 0 };
+    if !condition.can_apply_to(target_pokemon, target_player) {
+        return no_effect(context);
+    }
 
     if rng.next_outcome("Apply Trap Check") <= chance {
-        let trap_turns = (rng.next_outcome("Generate Trap Duration") % 4) + 2;
+        let trap_turns = partial_trap_duration(rng.next_outcome("Partial Trap Duration"));
+        // The condition ticks at the end of the turn it lands, so it lasts `trap_turns`
+        // end-of-turn ticks (and chip hits) including this one.
         commands.push(BattleCommand::AddCondition {
-            target: PlayerTarget::from_index(context.defender_index),
+            target: context.defender,
             condition: PokemonCondition::Trapped {
-                turns_remaining: trap_turns,
+                turns_remaining: trap_turns - 1,
+            },
+        });
+        // Gen 1 locks the user in for the same stretch: it keeps using the move
+        // and can't switch out until the target breaks free.
+        commands.push(BattleCommand::AddCondition {
+            target: context.attacker,
+            condition: PokemonCondition::Trapping {
+                turns_remaining: trap_turns - 1,
             },
         });
     }
@@ -227,16 +271,17 @@ pub(super) fn apply_seed_effect(
     rng: &mut TurnRng,
 ) -> Vec<BattleCommand> {
     let mut commands = Vec::new();
-    if state.players[context.defender_index]
-        .active_pokemon()
-        .is_none()
-    {
+    let target_player = state.battler(context.defender);
+    let Some(target_pokemon) = target_player.active_pokemon() else {
         return commands;
+    };
+    if !PokemonCondition::Seeded.can_apply_to(target_pokemon, target_player) {
+        return no_effect(context);
     }
 
     if rng.next_outcome("Apply Seeded Effect") <= chance {
         commands.push(BattleCommand::AddCondition {
-            target: PlayerTarget::from_index(context.defender_index),
+            target: context.defender,
             condition: PokemonCondition::Seeded,
         });
     }
@@ -250,22 +295,38 @@ pub(super) fn apply_exhaust_effect(
     rng: &mut TurnRng,
 ) -> Vec<BattleCommand> {
     let mut commands = Vec::new();
-    if state.players[context.attacker_index]
-        .active_pokemon()
-        .is_none()
-    {
+    if state.battler(context.attacker).active_pokemon().is_none() {
         return commands;
     }
 
     if rng.next_outcome("Apply Exhaust Check") <= chance {
         commands.push(BattleCommand::AddCondition {
-            target: PlayerTarget::from_index(context.attacker_index),
+            target: context.attacker,
             condition: PokemonCondition::Exhausted { turns_remaining: 2 },
         });
     }
     commands
 }
 
+/// Put the move just used on cooldown for the user's next `turns` turns. The count
+/// gets one extra turn because the end-of-turn tick also runs on the turn it's used.
+pub(super) fn apply_cooldown_effect(
+    turns: u8,
+    context: &EffectContext,
+    state: &BattleState,
+) -> Vec<BattleCommand> {
+    if state.battler(context.attacker).active_pokemon().is_none() {
+        return Vec::new();
+    }
+    vec![BattleCommand::AddCondition {
+        target: context.attacker,
+        condition: PokemonCondition::Cooldown {
+            pokemon_move: context.move_used,
+            turns_remaining: turns.saturating_add(1),
+        },
+    }]
+}
+
 pub(super) fn apply_cure_status_effect(
     target: &Target,
     status_type: &StatusType,
@@ -273,8 +334,8 @@ pub(super) fn apply_cure_status_effect(
     state: &BattleState,
 ) -> Vec<BattleCommand> {
     let mut commands = Vec::new();
-    let target_index = context.target_index(target);
-    let target_player = &state.players[target_index];
+    let target_slot = context.target(target);
+    let target_player = state.battler(target_slot);
 
     if let Some(target_pokemon) = target_player.active_pokemon() {
         let status_to_cure = match (&target_pokemon.status, status_type) {
@@ -288,7 +349,7 @@ pub(super) fn apply_cure_status_effect(
 
         if let Some(status) = status_to_cure {
             commands.push(BattleCommand::CurePokemonStatus {
-                target: PlayerTarget::from_index(target_index),
+                target: target_slot,
                 status,
             });
         }
@@ -36,7 +36,7 @@ pub fn calculate_progression_commands(
 
     // Get the index of the Pokemon that fainted
     let fainted_player_index = fainted_target.to_index();
-    let fainted_pokemon_index = battle_state.players[fainted_player_index].active_pokemon_index;
+    let fainted_pokemon_index = battle_state.battler(fainted_target).pokemon_index();
 
     // Get base experience and EV yield for the fainted Pokemon
     let base_exp = match calculator.calculate_base_exp(fainted_species) {
@@ -54,47 +54,49 @@ pub fn calculate_progression_commands(
         .participation_tracker
         .get_participants_against(fainted_player_index, fainted_pokemon_index);
 
-    // Calculate experience share among participants
-    let exp_per_participant = if participants.is_empty() {
-        0
-    } else {
-        base_exp / participants.len() as u32
-    };
-
-    // Award experience and EVs to each participant
-    let opposing_player = PlayerTarget::from_index(1 - fainted_player_index);
-    let mut experience_recipients = Vec::new();
+    // Party members that can still gain experience (alive and below max level)
+    let opposing_index = 1 - fainted_player_index;
+    let opposing_player = PlayerTarget::from_index(opposing_index);
+    let eligible: Vec<usize> = battle_state.players[opposing_index]
+        .team
+        .iter()
+        .enumerate()
+        .filter_map(|(index, slot)| {
+            slot.as_ref()
+                .filter(|pokemon| pokemon.current_hp() > 0 && pokemon.level < 100)
+                .map(|_| index)
+        })
+        .collect();
 
-    for &participant_index in &participants {
-        // Check if the participant is still alive and not at max level
-        if let Some(pokemon) =
-            battle_state.players[1 - fainted_player_index].team[participant_index].as_ref()
-        {
-            if pokemon.current_hp() > 0 && pokemon.level < 100 {
-                experience_recipients.push((
-                    opposing_player,
-                    participant_index,
-                    exp_per_participant,
-                ));
-
-                // Award EVs to this participant
-                let ev_stats = [
-                    ev_yield.hp,
-                    ev_yield.attack,
-                    ev_yield.defense,
-                    ev_yield.special_attack,
-                    ev_yield.special_defense,
-                    ev_yield.speed,
-                ];
-                commands.push(BattleCommand::DistributeEffortValues {
-                    target: opposing_player,
-                    pokemon_index: participant_index,
-                    stats: ev_stats,
-                });
-            }
-        }
+    // Award EVs to each eligible participant
+    for &participant_index in participants.iter().filter(|i| eligible.contains(i)) {
+        let ev_stats = [
+            ev_yield.hp,
+            ev_yield.attack,
+            ev_yield.defense,
+            ev_yield.special_attack,
+            ev_yield.special_defense,
+            ev_yield.speed,
+        ];
+        commands.push(BattleCommand::DistributeEffortValues {
+            target: opposing_player,
+            pokemon_index: participant_index,
+            stats: ev_stats,
+        });
     }
 
+    // Split experience among participants (or the whole party with Exp. Share)
+    let experience_recipients: Vec<(PlayerTarget, usize, u32)> = calculator
+        .distribute_experience(
+            base_exp,
+            &participants,
+            &eligible,
+            battle_state.rules.exp_share,
+        )
+        .into_iter()
+        .map(|(index, exp)| (opposing_player, index, exp))
+        .collect();
+
     // Add the experience award command if there are valid recipients
     // The execute_award_experience function will handle generating LevelUpPokemon commands
     if !experience_recipients.is_empty() {
@@ -105,3 +107,28 @@ pub fn calculate_progression_commands(
 
     commands
 }
+
+/// Calculate the prize money awarded when a battle is won.
+/// Only a human beating an NPC in a trainer battle is paid.
+pub fn calculate_prize_commands(
+    winner_index: usize,
+    battle_state: &BattleState,
+) -> Vec<BattleCommand> {
+    if battle_state.battle_type != BattleType::Trainer {
+        return Vec::new();
+    }
+    let winner = &battle_state.players[winner_index];
+    let loser = &battle_state.players[1 - winner_index];
+    if winner.player_type != PlayerType::Human || loser.player_type != PlayerType::NPC {
+        return Vec::new();
+    }
+
+    let amount = RewardCalculator.preview_prize(loser.team.iter().flatten());
+    if amount == 0 {
+        return Vec::new();
+    }
+    vec![BattleCommand::AwardPrizeMoney {
+        target: PlayerTarget::from_index(winner_index),
+        amount,
+    }]
+}
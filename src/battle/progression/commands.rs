@@ -1,7 +1,56 @@
 use crate::battle::commands::{BattleCommand, ExecutionError, PlayerTarget};
 use crate::pokemon::MoveInstance;
-use crate::species::Species;
-use crate::{BattleState, Move};
+use crate::species::{moves_learned_at, Species};
+use crate::{BattleState, Move, PlayerType};
+use serde::{Deserialize, Serialize};
+
+/// How an NPC picks which move to forget when it learns a new one with a full moveset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum MoveLearnPolicy {
+    /// Forget the move with the lowest base power (status moves count as 0),
+    /// but only if the new move is stronger.
+    #[default]
+    ReplaceLowestPower,
+    /// Forget the move in the first slot and shift the rest up, so the new move goes last.
+    ReplaceOldest,
+    /// Never forget a move; the new move is not learned.
+    KeepExisting,
+}
+
+impl MoveLearnPolicy {
+    /// Teach `new_move` into a full moveset according to this policy.
+    fn apply(self, moves: &mut [Option<MoveInstance>; 4], new_move: Move) {
+        match self {
+            MoveLearnPolicy::ReplaceLowestPower => {
+                let weakest = moves
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, slot)| {
+                        slot.as_ref()
+                            .map(|instance| (index, base_power(instance.move_)))
+                    })
+                    .min_by_key(|&(_, power)| power);
+
+                if let Some((index, power)) = weakest {
+                    if base_power(new_move) > power {
+                        moves[index] = Some(MoveInstance::new(new_move));
+                    }
+                }
+            }
+            MoveLearnPolicy::ReplaceOldest => {
+                moves.rotate_left(1);
+                moves[3] = Some(MoveInstance::new(new_move));
+            }
+            MoveLearnPolicy::KeepExisting => {}
+        }
+    }
+}
+
+fn base_power(move_: Move) -> u8 {
+    crate::move_data::get_move_data(move_)
+        .map(|data| data.power.unwrap_or(0))
+        .unwrap_or(0)
+}
 
 /// Execute experience award command
 pub fn execute_award_experience(
@@ -49,15 +98,13 @@ pub fn execute_level_up_pokemon(
         let calculator = crate::progression::RewardCalculator;
 
         // First, check for moves learned at this level (before evolution)
-        if let Ok(moves) = calculator.moves_learned_at_level(pokemon.species, current_level) {
-            for move_ in moves {
-                additional_commands.push(BattleCommand::LearnMove {
-                    target,
-                    pokemon_index,
-                    move_,
-                    replace_index: None, // Let execute_learn_move handle the choice
-                });
-            }
+        for move_ in moves_learned_at(pokemon.species, current_level) {
+            additional_commands.push(BattleCommand::LearnMove {
+                target,
+                pokemon_index,
+                move_,
+                replace_index: None, // Let execute_learn_move handle the choice
+            });
         }
 
         // Then, check for evolution at this level (after moves)
@@ -84,6 +131,10 @@ pub fn execute_learn_move(
     state: &mut BattleState,
 ) -> Result<Vec<BattleCommand>, ExecutionError> {
     let player_index = target.to_index();
+    let npc_policy = match state.players[player_index].player_type {
+        PlayerType::NPC => Some(state.rules.npc_move_learn_policy),
+        PlayerType::Human => None,
+    };
 
     if let Some(pokemon) = state.players[player_index].team[pokemon_index].as_mut() {
         let new_move = MoveInstance::new(move_);
@@ -100,10 +151,13 @@ pub fn execute_learn_move(
                 let empty_slot = pokemon.moves.iter_mut().find(|slot| slot.is_none());
                 match empty_slot {
                     Some(slot) => *slot = Some(new_move),
-                    None => {
-                        // No empty slot, replace the last move as default
-                        pokemon.moves[3] = Some(new_move);
-                    }
+                    None => match npc_policy {
+                        Some(policy) => policy.apply(&mut pokemon.moves, move_),
+                        None => {
+                            // No empty slot, replace the last move as default
+                            pokemon.moves[3] = Some(new_move);
+                        }
+                    },
                 }
             }
         }
@@ -128,18 +182,15 @@ pub fn execute_evolve_pokemon(
         pokemon.evolve(new_species);
 
         let mut additional_commands = Vec::new();
-        let calculator = crate::progression::RewardCalculator;
 
         // Check if the newly evolved Pokemon learns any moves at this level
-        if let Ok(moves) = calculator.moves_learned_at_level(new_species, current_level) {
-            for move_ in moves {
-                additional_commands.push(BattleCommand::LearnMove {
-                    target,
-                    pokemon_index,
-                    move_,
-                    replace_index: None, // Let execute_learn_move handle the choice
-                });
-            }
+        for move_ in moves_learned_at(new_species, current_level) {
+            additional_commands.push(BattleCommand::LearnMove {
+                target,
+                pokemon_index,
+                move_,
+                replace_index: None, // Let execute_learn_move handle the choice
+            });
         }
 
         Ok(additional_commands)
@@ -165,3 +216,131 @@ pub fn execute_distribute_effort_values(
 
     Ok(vec![])
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::tests::common::{create_test_battle, TestPokemonBuilder};
+
+    fn npc_battle_with_full_moveset(policy: MoveLearnPolicy) -> BattleState {
+        let learner = TestPokemonBuilder::new(Species::Pikachu, 25)
+            .with_moves(vec![
+                Move::QuickAttack,
+                Move::TailWhip,
+                Move::Growl,
+                Move::Slam,
+            ])
+            .build();
+        let opponent = TestPokemonBuilder::new(Species::Charmander, 25).build();
+        let mut state = create_test_battle(learner, opponent);
+        state.players[0].player_type = PlayerType::NPC;
+        state.rules.npc_move_learn_policy = policy;
+        state
+    }
+
+    fn known_moves(state: &BattleState) -> Vec<Move> {
+        state.players[0].team[0]
+            .as_ref()
+            .unwrap()
+            .moves
+            .iter()
+            .flatten()
+            .map(|instance| instance.move_)
+            .collect()
+    }
+
+    #[test]
+    fn test_npc_replaces_weakest_move_with_stronger_one() {
+        let mut state = npc_battle_with_full_moveset(MoveLearnPolicy::default());
+
+        execute_learn_move(
+            PlayerTarget::from_index(0),
+            0,
+            Move::Lightning,
+            None,
+            &mut state,
+        )
+        .unwrap();
+
+        // Tail Whip is the first of the zero-power moves, so it is the one forgotten.
+        assert_eq!(
+            known_moves(&state),
+            vec![Move::QuickAttack, Move::Lightning, Move::Growl, Move::Slam]
+        );
+    }
+
+    #[test]
+    fn test_npc_keeps_existing_moves_under_keep_existing() {
+        let mut state = npc_battle_with_full_moveset(MoveLearnPolicy::KeepExisting);
+
+        execute_learn_move(
+            PlayerTarget::from_index(0),
+            0,
+            Move::ThunderWave,
+            None,
+            &mut state,
+        )
+        .unwrap();
+
+        assert_eq!(
+            known_moves(&state),
+            vec![Move::QuickAttack, Move::TailWhip, Move::Growl, Move::Slam]
+        );
+    }
+
+    #[test]
+    fn test_npc_forgets_oldest_move_under_replace_oldest() {
+        let mut state = npc_battle_with_full_moveset(MoveLearnPolicy::ReplaceOldest);
+
+        execute_learn_move(
+            PlayerTarget::from_index(0),
+            0,
+            Move::ThunderWave,
+            None,
+            &mut state,
+        )
+        .unwrap();
+
+        assert_eq!(
+            known_moves(&state),
+            vec![Move::TailWhip, Move::Growl, Move::Slam, Move::ThunderWave]
+        );
+    }
+
+    fn queued_learns(commands: &[BattleCommand]) -> Vec<Move> {
+        commands
+            .iter()
+            .filter_map(|command| match command {
+                BattleCommand::LearnMove { move_, .. } => Some(*move_),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_level_up_queues_move_learned_at_new_level() {
+        let learner = TestPokemonBuilder::new(Species::Charmander, 6).build();
+        let opponent = TestPokemonBuilder::new(Species::Pikachu, 25).build();
+        let mut state = create_test_battle(learner, opponent);
+
+        let commands =
+            execute_level_up_pokemon(PlayerTarget::from_index(0), 0, &mut state).unwrap();
+
+        // Charmander learns Ember at level 7, and nothing else.
+        assert_eq!(moves_learned_at(Species::Charmander, 7), vec![Move::Ember]);
+        assert_eq!(queued_learns(&commands), vec![Move::Ember]);
+    }
+
+    #[test]
+    fn test_level_up_queues_nothing_when_no_move_is_learned() {
+        let learner = TestPokemonBuilder::new(Species::Charmander, 7).build();
+        let opponent = TestPokemonBuilder::new(Species::Pikachu, 25).build();
+        let mut state = create_test_battle(learner, opponent);
+
+        let commands =
+            execute_level_up_pokemon(PlayerTarget::from_index(0), 0, &mut state).unwrap();
+
+        assert!(moves_learned_at(Species::Charmander, 8).is_empty());
+        assert!(queued_learns(&commands).is_empty());
+    }
+}
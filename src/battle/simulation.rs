@@ -0,0 +1,91 @@
+use crate::battle::ai::random_legal_action;
+use crate::battle::engine::{ready_for_turn_resolution, resolve_turn};
+use crate::battle::state::{BattleState, GameState, TurnRng};
+use crate::player::BattlePlayer;
+use crate::pokemon::PokemonInst;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
+
+/// Battles that haven't ended after this many turns are counted as unfinished.
+const MAX_TURNS: u32 = 500;
+
+/// Outcome counts over a batch of simulated battles.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AggregateResults {
+    pub battles: u32,
+    pub team_a_wins: u32,
+    pub team_b_wins: u32,
+    pub draws: u32,
+    /// Battles cut off at the turn limit, or stuck waiting on a choice.
+    pub unfinished: u32,
+    pub total_turns: u64,
+}
+
+impl AggregateResults {
+    /// Average number of turns per battle, including unfinished ones.
+    pub fn average_turns(&self) -> f64 {
+        if self.battles == 0 {
+            0.0
+        } else {
+            self.total_turns as f64 / self.battles as f64
+        }
+    }
+}
+
+/// Run `n` battles between two teams with uniformly random legal actions.
+///
+/// Action choice and turn resolution both draw from one generator seeded with `seed`.
+/// Useful as a stress test and benchmark target.
+pub fn simulate_random_battles(
+    team_a: &[PokemonInst],
+    team_b: &[PokemonInst],
+    n: u32,
+    seed: u64,
+) -> AggregateResults {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut results = AggregateResults::default();
+
+    for battle_number in 0..n {
+        let player_a = BattlePlayer::new("a".to_string(), "Team A".to_string(), team_a.to_vec());
+        let player_b = BattlePlayer::new("b".to_string(), "Team B".to_string(), team_b.to_vec());
+        let mut battle_state =
+            BattleState::new(format!("sim_{}", battle_number), player_a, player_b);
+
+        while battle_state.turn_number <= MAX_TURNS {
+            let players_to_act: &[usize] = match battle_state.game_state {
+                GameState::WaitingForActions | GameState::WaitingForBothReplacements => &[0, 1],
+                GameState::WaitingForPlayer1Replacement => &[0],
+                GameState::WaitingForPlayer2Replacement => &[1],
+                _ => break,
+            };
+
+            for &player_index in players_to_act {
+                if battle_state.action_queue[player_index].is_none() {
+                    let action = random_legal_action(
+                        player_index,
+                        &battle_state,
+                        &mut TurnRng::from_rng(&mut rng),
+                    );
+                    battle_state.action_queue[player_index] = Some(action);
+                }
+            }
+
+            if !ready_for_turn_resolution(&battle_state) {
+                break;
+            }
+            resolve_turn(&mut battle_state, TurnRng::from_rng(&mut rng));
+        }
+
+        results.battles += 1;
+        results.total_turns += u64::from(battle_state.turn_number - 1);
+        match battle_state.game_state {
+            GameState::Player1Win => results.team_a_wins += 1,
+            GameState::Player2Win => results.team_b_wins += 1,
+            GameState::Draw => results.draws += 1,
+            _ => results.unfinished += 1,
+        }
+    }
+
+    results
+}
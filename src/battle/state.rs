@@ -1,11 +1,17 @@
+use std::collections::HashMap;
 use std::fmt;
 
-use crate::battle::commands::BattleCommand;
+use crate::battle::ai::RevealedMoves;
+use crate::battle::commands::{BattleCommand, PlayerTarget};
 use crate::battle::conditions::PokemonCondition;
-use crate::player::{BattlePlayer, PlayerAction, StatType, TeamCondition};
+use crate::battle::features::FeatureVector;
+use crate::battle::progression::MoveLearnPolicy;
+use crate::player::{
+    BattlePlayer, Battler, BattlerMut, PartnerSlot, PlayerAction, StatType, TeamCondition,
+};
 use crate::progression::BattleParticipationTracker;
 use crate::species::Species;
-use schema::Move;
+use schema::{Item, Move};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Copy)]
@@ -76,6 +82,10 @@ pub enum BattleEvent {
     TurnEnded,
 
     // Pokemon Actions
+    PokemonSentOut {
+        player_index: usize,
+        pokemon: Species,
+    },
     PokemonSwitched {
         player_index: usize,
         old_pokemon: Species,
@@ -117,6 +127,22 @@ pub enum BattleEvent {
         amount: u16,
         new_hp: u16,
     },
+    // Narration for self-inflicted damage and healing; the HP change itself follows
+    // as a DamageDealt or PokemonHealed event.
+    RecoilDamage {
+        target: Species,
+    },
+    DrainHeal {
+        target: Species,
+    },
+    HeldItemConsumed {
+        target: Species,
+        item: Item,
+    },
+    CrashDamage {
+        target: Species,
+        move_used: Move,
+    },
     PokemonFainted {
         player_index: usize,
         pokemon: Species,
@@ -193,6 +219,10 @@ pub enum BattleEvent {
         amount: u32,
         new_total: u32,
     },
+    PrizeMoneyAwarded {
+        player_index: usize,
+        amount: u32,
+    },
 
     // Catch Events
     CatchAttempted {
@@ -200,6 +230,10 @@ pub enum BattleEvent {
         pokemon: Species,
         catch_rate: f32,
     },
+    BallShook {
+        player_index: usize,
+        count: u8,
+    },
     CatchSucceeded {
         player_index: usize,
         pokemon: Species,
@@ -251,12 +285,44 @@ pub enum BattleEvent {
     PlayerDefeated {
         player_index: usize,
     },
+    TurnLimitReached {
+        turn_number: u32,
+    },
     BattleEnded {
         winner: Option<usize>,
     },
 }
 
 impl BattleEvent {
+    /// Whether this event produces any text for the player, i.e. `format` returns `Some`.
+    pub fn is_user_visible(&self, battle_state: &BattleState) -> bool {
+        self.format(battle_state).is_some()
+    }
+
+    /// Whether this event takes HP off a Pokémon, from any source.
+    pub fn is_damage(&self) -> bool {
+        matches!(
+            self,
+            BattleEvent::DamageDealt { .. }
+                | BattleEvent::RecoilDamage { .. }
+                | BattleEvent::CrashDamage { .. }
+                | BattleEvent::StatusDamage { .. }
+                | BattleEvent::PokemonStatusDamage { .. }
+        )
+    }
+
+    pub fn is_faint(&self) -> bool {
+        matches!(self, BattleEvent::PokemonFainted { .. })
+    }
+
+    /// Whether this event announces the end of the battle or the side that lost it.
+    pub fn is_battle_end(&self) -> bool {
+        matches!(
+            self,
+            BattleEvent::PlayerDefeated { .. } | BattleEvent::BattleEnded { .. }
+        )
+    }
+
     #[allow(dead_code)]
     /// Formats the event into a human-readable string using battle context.
     /// Returns None for silent events that should not produce user-visible text.
@@ -268,6 +334,17 @@ impl BattleEvent {
             BattleEvent::TurnEnded => {
                 None // Silent - turn ending is usually obvious from context
             }
+            BattleEvent::PokemonSentOut {
+                player_index,
+                pokemon,
+            } => {
+                let player_name = &battle_state.players[*player_index].player_name;
+                Some(format!(
+                    "{} sent out {}!",
+                    player_name,
+                    Self::format_species_name(*pokemon)
+                ))
+            }
             BattleEvent::PokemonSwitched {
                 player_index,
                 old_pokemon,
@@ -331,6 +408,23 @@ impl BattleEvent {
                 let target_name = Self::format_species_name(*target);
                 Some(format!("{} recovered {} HP!", target_name, amount))
             }
+            BattleEvent::RecoilDamage { target } => {
+                let target_name = Self::format_species_name(*target);
+                Some(format!("{} is hit by recoil!", target_name))
+            }
+            BattleEvent::DrainHeal { target } => {
+                let target_name = Self::format_species_name(*target);
+                Some(format!("{} drained some health!", target_name))
+            }
+            BattleEvent::HeldItemConsumed { target, item } => Some(format!(
+                "{} ate its {}!",
+                Self::format_species_name(*target),
+                Self::format_item_name(*item)
+            )),
+            BattleEvent::CrashDamage { move_used, .. } => {
+                let move_name = Self::format_move_name(*move_used);
+                Some(format!("{} crashed!", move_name))
+            }
             BattleEvent::PokemonFainted { pokemon, .. } => {
                 let pokemon_name = Self::format_species_name(*pokemon);
                 Some(format!("{} fainted!", pokemon_name))
@@ -429,14 +523,18 @@ impl BattleEvent {
                     Some(format!("{}'s {} fell!", target_name, stat_name))
                 }
             }
-            BattleEvent::StatChangeBlocked { target, .. } => {
-                let target_name = Self::format_species_name(*target);
-                Some(format!("{}'s stats won't go any higher!", target_name))
-            }
+            BattleEvent::StatChangeBlocked { .. } => Some("But it failed!".to_string()),
             BattleEvent::ActionFailed { reason } => {
                 Self::format_action_failure_reason(reason) // Some failures should be silent
             }
             BattleEvent::AnteIncreased { .. } => Some(format!("Coins scattered around!")),
+            BattleEvent::PrizeMoneyAwarded {
+                player_index,
+                amount,
+            } => Some(format!(
+                "{} got ₽{} for winning!",
+                battle_state.players[*player_index].player_name, amount
+            )),
             BattleEvent::CatchAttempted {
                 player_index,
                 pokemon,
@@ -451,6 +549,10 @@ impl BattleEvent {
                     player_name, pokemon_name, rate_desc
                 ))
             }
+            BattleEvent::BallShook { count, .. } => match count {
+                1 => Some("The ball shook once...".to_string()),
+                _ => Some(format!("The ball shook {} times...", count)),
+            },
             BattleEvent::CatchSucceeded {
                 player_index,
                 pokemon,
@@ -485,6 +587,10 @@ impl BattleEvent {
                 let player_name = &battle_state.players[*player_index].player_name;
                 Some(format!("{} is out of usable Pokémon!", player_name))
             }
+            BattleEvent::TurnLimitReached { turn_number } => Some(format!(
+                "Time's up! The battle reached its {}-turn limit.",
+                turn_number
+            )),
             BattleEvent::BattleEnded { winner } => match winner {
                 Some(index) => Some(format!(
                     "{} has won the battle!",
@@ -573,6 +679,21 @@ impl BattleEvent {
         species.name().to_string()
     }
 
+    fn format_item_name(item: Item) -> String {
+        // Convert CamelCase enum variants to Title Case, e.g. OranBerry -> "Oran Berry"
+        format!("{:?}", item)
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                if i > 0 && c.is_uppercase() {
+                    format!(" {}", c)
+                } else {
+                    c.to_string()
+                }
+            })
+            .collect()
+    }
+
     fn format_move_name(move_used: Move) -> String {
         // Convert CamelCase enum variants to human-readable names
         match move_used {
@@ -613,6 +734,10 @@ impl BattleEvent {
             crate::battle::conditions::PokemonConditionType::Trapped => {
                 "became trapped".to_string()
             }
+            crate::battle::conditions::PokemonConditionType::SwitchLocked => {
+                "can no longer escape".to_string()
+            }
+            crate::battle::conditions::PokemonConditionType::Trapping => "can't let go".to_string(),
             crate::battle::conditions::PokemonConditionType::Flinched => "flinched".to_string(),
             crate::battle::conditions::PokemonConditionType::Rampaging => {
                 "is rampaging".to_string()
@@ -620,13 +745,13 @@ impl BattleEvent {
             crate::battle::conditions::PokemonConditionType::Disabled => {
                 "had its move disabled".to_string()
             }
+            crate::battle::conditions::PokemonConditionType::Cooldown => {
+                "must let its move cool down".to_string()
+            }
             crate::battle::conditions::PokemonConditionType::Biding => "began biding".to_string(),
             crate::battle::conditions::PokemonConditionType::Teleported => {
                 "moved in a blink".to_string()
             }
-            crate::battle::conditions::PokemonConditionType::Countering => {
-                "prepared to counter".to_string()
-            }
             crate::battle::conditions::PokemonConditionType::Charging => {
                 "began charging".to_string()
             }
@@ -660,8 +785,9 @@ impl BattleEvent {
             crate::battle::conditions::PokemonConditionType::Underground => None,
             crate::battle::conditions::PokemonConditionType::InAir => None,
             crate::battle::conditions::PokemonConditionType::Teleported => None,
-            crate::battle::conditions::PokemonConditionType::Countering => None,
             crate::battle::conditions::PokemonConditionType::Biding => None,
+            // The trapped side's "broke free!" already covers the end of the lock.
+            crate::battle::conditions::PokemonConditionType::Trapping => None,
 
             // Custom messages for specific conditions
             crate::battle::conditions::PokemonConditionType::Confused => {
@@ -676,9 +802,15 @@ impl BattleEvent {
             crate::battle::conditions::PokemonConditionType::Trapped => {
                 Some(format!("{} broke free!", target_name))
             }
+            crate::battle::conditions::PokemonConditionType::SwitchLocked => {
+                Some(format!("{} can escape again.", target_name))
+            }
             crate::battle::conditions::PokemonConditionType::Disabled => {
                 Some(format!("{} is no longer disabled!", target_name))
             }
+            crate::battle::conditions::PokemonConditionType::Cooldown => {
+                Some(format!("{}'s move is ready again!", target_name))
+            }
             crate::battle::conditions::PokemonConditionType::Enraged => {
                 Some(format!("{} calmed down.", target_name))
             }
@@ -766,6 +898,12 @@ impl BattleEvent {
             ActionFailureReason::IsTrapped { pokemon } => {
                 Some(format!("{} can't escape!", pokemon.name()))
             }
+            ActionFailureReason::AlreadyInBattle { pokemon } => {
+                Some(format!("{} is already in battle!", pokemon.name()))
+            }
+            ActionFailureReason::NotInFormat { pokemon } => {
+                Some(format!("{} isn't part of this battle!", pokemon.name()))
+            }
             ActionFailureReason::NoPPRemaining { move_used } => Some(format!(
                 "But there was no PP left for {}!",
                 Self::format_move_name(*move_used)
@@ -773,6 +911,9 @@ impl BattleEvent {
             ActionFailureReason::MoveFailedToExecute { move_used } => {
                 Some(format!("{} failed!", Self::format_move_name(*move_used)))
             }
+            ActionFailureReason::AlreadyActive { .. } | ActionFailureReason::NoEffect { .. } => {
+                Some("But it failed!".to_string())
+            }
             // Silent failures - these happen naturally and don't need messages
             ActionFailureReason::PokemonFainted => None, // Pokemon faints before moving
             ActionFailureReason::NoEnemyPresent => None, // No target for move
@@ -843,6 +984,13 @@ mod event_formatting_tests {
                 move_used: Move::Tackle,
             },
             BattleEvent::AttackTypeEffectiveness { multiplier: 2.0 }, // Super effective
+            BattleEvent::RecoilDamage {
+                target: Species::Machamp,
+            },
+            BattleEvent::CrashDamage {
+                target: Species::Hitmonlee,
+                move_used: Move::JumpKick,
+            },
             BattleEvent::PokemonFainted {
                 player_index: 0,
                 pokemon: Species::Pikachu,
@@ -975,6 +1123,45 @@ mod event_formatting_tests {
         assert!(display_output.contains("MoveHit"));
         assert!(display_output.contains("CriticalHit"));
     }
+
+    #[test]
+    fn test_event_bus_filtered_presets() {
+        let battle_state = create_test_battle_state();
+        let mut event_bus = EventBus::new();
+        event_bus.push(BattleEvent::TurnStarted { turn_number: 1 });
+        event_bus.push(BattleEvent::MoveHit {
+            attacker: Species::Pikachu,
+            defender: Species::Charmander,
+            move_used: Move::Tackle,
+        });
+        event_bus.push(BattleEvent::DamageDealt {
+            target: Species::Charmander,
+            damage: 12,
+            remaining_hp: 0,
+        });
+        event_bus.push(BattleEvent::PokemonFainted {
+            player_index: 1,
+            pokemon: Species::Charmander,
+        });
+        event_bus.push(BattleEvent::BattleEnded { winner: Some(0) });
+        event_bus.push(BattleEvent::TurnEnded);
+
+        let visible = event_bus.filtered(|event| event.is_user_visible(&battle_state));
+        assert!(!visible
+            .iter()
+            .any(|event| matches!(event, BattleEvent::TurnEnded | BattleEvent::MoveHit { .. })));
+        assert!(visible
+            .iter()
+            .all(|event| event.format(&battle_state).is_some()));
+        assert!(matches!(visible[0], BattleEvent::TurnStarted { .. }));
+
+        assert_eq!(event_bus.filtered(BattleEvent::is_damage).len(), 1);
+        assert_eq!(event_bus.filtered(BattleEvent::is_faint).len(), 1);
+        assert_eq!(
+            event_bus.filtered(BattleEvent::is_battle_end),
+            vec![&BattleEvent::BattleEnded { winner: Some(0) }]
+        );
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -986,10 +1173,28 @@ pub enum ActionFailureReason {
     IsFlinching { pokemon: crate::species::Species },
     IsConfused { pokemon: crate::species::Species },
     IsTrapped { pokemon: crate::species::Species },
+    AlreadyInBattle { pokemon: crate::species::Species }, // Switching to the Pokemon that is already active
+    NotInFormat { pokemon: crate::species::Species }, // Switching to a slot beyond the battle's format_team_size
     NoEnemyPresent, // When opponent-targeting move can't execute (e.g., opponent fainted, only self-targeting moves allowed)
     NoPPRemaining { move_used: Move },
     PokemonFainted, // When the acting Pokemon or target is fainted
     MoveFailedToExecute { move_used: Move },
+    AlreadyActive { move_used: Move }, // The move's effect is already in place (e.g., a second Reflect)
+    NoEffect { move_used: Move }, // A status move that would change nothing (e.g., Growl at -6 Attack)
+}
+
+/// Why a move is certain to fail, as far as can be told before the turn is resolved.
+/// Only deterministic checks are covered; chance-based ones like paralysis are not.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum PredictedFailure {
+    NoMoveInSlot,
+    NoPPRemaining { move_used: Move }, // The engine will use Struggle instead
+    MustRecharge,
+    IsTrapped,
+    IsAsleep,
+    IsFrozen, // Only when nothing can thaw it first: no self-thaw rule or thawing move
+    Disabled { move_used: Move },
+    TargetNotAsleep { move_used: Move }, // e.g., Nightmare against an awake target
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -1023,11 +1228,26 @@ pub enum CatchFailureReason {
 #[derive(Debug, Clone)]
 pub struct EventBus {
     events: Vec<BattleEvent>,
+    /// RNG values consumed during the turn with their reasons, if tracing was enabled.
+    rng_trace: Option<Vec<(String, u8)>>,
 }
 
 impl EventBus {
     pub fn new() -> Self {
-        Self { events: Vec::new() }
+        Self {
+            events: Vec::new(),
+            rng_trace: None,
+        }
+    }
+
+    /// The RNG consumption trace for this turn, in order. Only present when the
+    /// turn was resolved with a `TurnRng` created via `with_trace`.
+    pub fn rng_trace(&self) -> Option<&[(String, u8)]> {
+        self.rng_trace.as_deref()
+    }
+
+    pub fn set_rng_trace(&mut self, trace: Option<Vec<(String, u8)>>) {
+        self.rng_trace = trace;
     }
 
     pub fn push(&mut self, event: BattleEvent) {
@@ -1100,6 +1320,15 @@ impl EventBus {
     {
         self.events.iter().find(|&e| predicate(e))
     }
+
+    /// The events matching `predicate`, in order. The presets on `BattleEvent`
+    /// (`is_user_visible`, `is_damage`, ...) cover the common subscriptions.
+    pub fn filtered(&self, predicate: impl Fn(&BattleEvent) -> bool) -> Vec<&BattleEvent> {
+        self.events
+            .iter()
+            .filter(|event| predicate(event))
+            .collect()
+    }
 }
 
 impl std::fmt::Display for EventBus {
@@ -1116,22 +1345,67 @@ impl std::fmt::Display for EventBus {
 pub struct TurnRng {
     outcomes: Vec<u8>,
     index: usize,
+    /// Opt-in record of every consumed value and its reason, for debugging desyncs.
+    trace: Option<Vec<(String, u8)>>,
+    /// Fixed outcomes by reason for scripted encounters. Overridden rolls still use up
+    /// their slot in `outcomes`, so every other roll stays as it would have been.
+    overrides: HashMap<String, u8>,
 }
 
 impl TurnRng {
     pub fn new_for_test(outcomes: Vec<u8>) -> Self {
-        Self { outcomes, index: 0 }
+        Self {
+            outcomes,
+            index: 0,
+            trace: None,
+            overrides: HashMap::new(),
+        }
+    }
+
+    /// Enable recording of each consumed outcome alongside its reason.
+    pub fn with_trace(mut self) -> Self {
+        self.trace = Some(Vec::new());
+        self
+    }
+
+    /// Always answer `outcome` when a roll is made for `reason` (e.g. `1` for
+    /// "Critical Hit Check" to force a crit). Meant for scripted story battles.
+    pub fn with_override(mut self, reason: &str, outcome: u8) -> Self {
+        self.overrides.insert(reason.to_string(), outcome);
+        self
+    }
+
+    /// Take the recorded trace, leaving tracing disabled. Returns `None` if tracing was never enabled.
+    pub fn take_trace(&mut self) -> Option<Vec<(String, u8)>> {
+        self.trace.take()
     }
 
     pub fn new_random() -> Self {
-        use rand::Rng;
-        let mut rng = rand::rng();
+        Self::from_rng(&mut rand::rng())
+    }
+
+    /// Draw a turn's worth of outcomes from the given generator, e.g. a seeded one
+    /// for reproducible simulations.
+    pub fn from_rng(rng: &mut impl rand::Rng) -> Self {
         // Pre-generate a reasonable number of random values for a turn
         let outcomes: Vec<u8> = (0..100).map(|_| rng.random_range(1..=100)).collect();
-        Self { outcomes, index: 0 }
+        Self {
+            outcomes,
+            index: 0,
+            trace: None,
+            overrides: HashMap::new(),
+        }
     }
 
     pub fn next_outcome(&mut self, reason: &str) -> u8 {
+        if let Some(&outcome) = self.overrides.get(reason) {
+            if let Some(trace) = self.trace.as_mut() {
+                trace.push((reason.to_string(), outcome));
+            }
+            // Skip the value this roll would have drawn so later rolls line up.
+            self.index += 1;
+            return outcome;
+        }
         if self.index >= self.outcomes.len() {
             // Add the reason to the panic message for better debugging!
             panic!(
@@ -1140,10 +1414,9 @@ impl TurnRng {
             );
         }
         let outcome = self.outcomes[self.index];
-
-        // The magic line: Print the consumption event to the console during tests.
-        #[cfg(test)]
-        println!("[RNG] Consumed {} for: {}", outcome, reason);
+        if let Some(trace) = self.trace.as_mut() {
+            trace.push((reason.to_string(), outcome));
+        }
 
         self.index += 1;
         outcome
@@ -1162,6 +1435,95 @@ pub enum BattleType {
     Safari,
 }
 
+/// Mechanics where authentic Gen 1 behaviour differs from later quality-of-life fixes.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GenQuirks {
+    /// Gen 1 as released: a frozen Pokémon never thaws on its own.
+    /// Only a Fire move, Haze, or a move flagged to thaw its user frees it.
+    #[default]
+    Authentic,
+    /// A frozen Pokémon has a 25% chance to thaw each time it tries to act.
+    QualityOfLife,
+}
+
+impl GenQuirks {
+    /// Whether a frozen Pokémon can thaw out by chance when trying to act.
+    pub fn frozen_self_thaw(self) -> bool {
+        matches!(self, GenQuirks::QualityOfLife)
+    }
+}
+
+/// How a battle that reaches its `max_turns` cap is decided.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeoutResolution {
+    /// The side with the larger share of its team's HP left wins; equal shares draw.
+    #[default]
+    HpPercentage,
+    /// The battle is declared a draw.
+    Draw,
+}
+
+/// Ruleset options a battle is played under. Every field falls back to its default
+/// when missing, so battles saved before an option existed still load.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct BattleRules {
+    /// When set, experience is shared with the whole party, not just participants.
+    /// Off by default to match Gen 1.
+    pub exp_share: bool,
+    /// How many team slots take part in this format (1-6), e.g. 3 for a 3v3.
+    /// Slots beyond it can't be switched to and don't count toward win conditions.
+    pub format_team_size: u8,
+    /// Casual ruleset option: a Pokémon's major status is cured when it switches out.
+    /// Off by default, since Gen 1 keeps status through switches.
+    pub natural_cure_on_switch: bool,
+    /// Which move an NPC forgets when it levels into a new move with a full moveset.
+    pub npc_move_learn_policy: MoveLearnPolicy,
+    /// Sleep clause: a side can't put a second opposing Pokémon to sleep while one
+    /// it already put to sleep is still asleep. Off by default.
+    pub sleep_clause: bool,
+    /// Which Gen 1 quirks are kept. Authentic by default.
+    pub gen_quirks: GenQuirks,
+    /// The battle is decided by `timeout_resolution` once this many turns have been
+    /// played without a winner. No cap by default.
+    pub max_turns: Option<u32>,
+    pub timeout_resolution: TimeoutResolution,
+}
+
+impl Default for BattleRules {
+    fn default() -> Self {
+        Self {
+            exp_share: false,
+            format_team_size: 6,
+            natural_cure_on_switch: false,
+            npc_move_learn_policy: MoveLearnPolicy::default(),
+            sleep_clause: false,
+            gen_quirks: GenQuirks::default(),
+            max_turns: None,
+            timeout_resolution: TimeoutResolution::default(),
+        }
+    }
+}
+
+/// How many Pokémon each side has on the field at once.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BattleFormat {
+    /// One against one, as in Gen 1.
+    #[default]
+    Single,
+    /// Two against two. Each player picks an action, and a target, for both of its
+    /// active Pokémon; see `battle::double`.
+    Double,
+}
+
+/// One active Pokémon's choice in a double battle. `target` picks which Pokémon a
+/// single-target move is aimed at; `None` aims it at the first opponent still standing.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SlotAction {
+    pub action: PlayerAction,
+    pub target: Option<PlayerTarget>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct BattleState {
     pub battle_id: String,
@@ -1171,8 +1533,36 @@ pub struct BattleState {
     pub battle_type: BattleType,
     pub action_queue: [Option<PlayerAction>; 2],
     pub participation_tracker: BattleParticipationTracker,
-    /// Persistent command stack for step-by-step execution
+    /// Scratch buffer `execute_command_batch` keeps its pending commands in, so the
+    /// allocation is reused across batches. Empty between batches, so it isn't saved.
+    #[serde(skip)]
     pub command_stack: Vec<BattleCommand>,
+    /// The ruleset options this battle is played under.
+    #[serde(default)]
+    pub rules: BattleRules,
+    /// Whether each side's slot-0 Pokémon has already had its faint processed, so
+    /// `HandleFainted` never runs twice for the same faint. Slot 1 keeps its flag on
+    /// `PartnerSlot`; `is_faint_handled` reads either.
+    #[serde(default)]
+    pub faint_handled: [bool; 2],
+    /// Team index on each side of the Pokémon the opponent put to sleep, if any.
+    /// Self-inflicted sleep (Rest) is never recorded, so it doesn't count, and a record
+    /// is dropped as soon as that Pokémon's sleep ends.
+    #[serde(default)]
+    pub opponent_induced_sleep: [Option<usize>; 2],
+    /// Whether each side's slot-0 Pokémon has already taken its action this turn, reset
+    /// at turn start. Slot 1 keeps its flag on `PartnerSlot`; `has_acted` reads either.
+    #[serde(default)]
+    pub acted_this_turn: [bool; 2],
+    /// The moves each side has been seen using so far, which AI opponents may reason about.
+    #[serde(default)]
+    pub revealed_moves: [RevealedMoves; 2],
+    #[serde(default)]
+    pub format: BattleFormat,
+    /// A double battle's queued choices, indexed by side then slot. Unused in singles,
+    /// which queue into `action_queue`.
+    #[serde(default)]
+    pub double_action_queue: [[Option<SlotAction>; 2]; 2],
 }
 
 impl BattleState {
@@ -1195,7 +1585,447 @@ impl BattleState {
             action_queue: [None, None],
             participation_tracker, // Assign the initialized tracker.
             command_stack: Vec::new(),
+            rules: BattleRules::default(),
+            faint_handled: [false; 2],
+            opponent_induced_sleep: [None, None],
+            acted_this_turn: [false; 2],
+            revealed_moves: Default::default(),
+            format: BattleFormat::Single,
+            double_action_queue: Default::default(),
+        }
+    }
+
+    /// Build a double battle. Each side's first two healthy Pokémon lead; a side with
+    /// only one fights alone.
+    pub fn new_double(id: String, mut player1: BattlePlayer, mut player2: BattlePlayer) -> Self {
+        for player in [&mut player1, &mut player2] {
+            let lead = player.active_pokemon_index;
+            // With no second healthy Pokémon, the partner slot points at an empty or
+            // fainted team slot and simply never acts.
+            let pokemon_index = player
+                .team
+                .iter()
+                .enumerate()
+                .position(|(index, slot)| {
+                    index != lead && slot.as_ref().is_some_and(|pokemon| !pokemon.is_fainted())
+                })
+                .unwrap_or(if lead == 0 { 1 } else { 0 });
+            let standing = player.team[pokemon_index]
+                .as_ref()
+                .is_some_and(|pokemon| !pokemon.is_fainted());
+            player.partner = Some(PartnerSlot {
+                pokemon_index,
+                faint_handled: !standing,
+                ..Default::default()
+            });
+        }
+        let mut battle_state = Self::new(id, player1, player2);
+        battle_state.format = BattleFormat::Double;
+        battle_state.record_field_participation();
+        battle_state
+    }
+
+    /// Build a battle that resumes at a given turn and game state, e.g. when loading a
+    /// mid-battle save. Every other setting starts from the `new` defaults.
+    ///
+    /// A side whose active Pokémon is already fainted is treated as having had that
+    /// faint processed, so resuming in a replacement state doesn't handle it twice.
+    pub fn with_state(
+        id: String,
+        player1: BattlePlayer,
+        player2: BattlePlayer,
+        turn_number: u32,
+        game_state: GameState,
+        action_queue: [Option<PlayerAction>; 2],
+    ) -> Self {
+        let mut battle_state = Self::new(id, player1, player2);
+        battle_state.turn_number = turn_number;
+        battle_state.game_state = game_state;
+        battle_state.action_queue = action_queue;
+        for (player_index, player) in battle_state.players.iter().enumerate() {
+            battle_state.faint_handled[player_index] = player
+                .active_pokemon()
+                .is_some_and(|pokemon| pokemon.is_fainted());
+        }
+        battle_state
+    }
+
+    /// The Pokemon in a slot on the field, with its battle state.
+    pub fn battler(&self, target: PlayerTarget) -> Battler<'_> {
+        self.players[target.side].battler(target.slot)
+    }
+
+    pub fn battler_mut(&mut self, target: PlayerTarget) -> BattlerMut<'_> {
+        self.players[target.side].battler_mut(target.slot)
+    }
+
+    /// Every slot on the field, side by side: just slot 0 of each side in a single
+    /// battle, both slots of each side in a double battle.
+    pub fn field_targets(&self) -> Vec<PlayerTarget> {
+        let slots = match self.format {
+            BattleFormat::Single => 1,
+            BattleFormat::Double => 2,
+        };
+        (0..2)
+            .flat_map(|side| (0..slots).map(move |slot| PlayerTarget::new(side, slot)))
+            .collect()
+    }
+
+    /// Whether the faint of the Pokemon in `target`'s slot has already been processed.
+    pub fn is_faint_handled(&self, target: PlayerTarget) -> bool {
+        match target.slot {
+            0 => self.faint_handled[target.side],
+            _ => self.players[target.side]
+                .partner
+                .as_ref()
+                .is_some_and(|partner| partner.faint_handled),
+        }
+    }
+
+    pub fn set_faint_handled(&mut self, target: PlayerTarget, handled: bool) {
+        match target.slot {
+            0 => self.faint_handled[target.side] = handled,
+            _ => {
+                if let Some(partner) = self.players[target.side].partner.as_mut() {
+                    partner.faint_handled = handled;
+                }
+            }
+        }
+    }
+
+    /// Whether the Pokemon in `target`'s slot has already taken its action this turn.
+    pub fn has_acted(&self, target: PlayerTarget) -> bool {
+        match target.slot {
+            0 => self.acted_this_turn[target.side],
+            _ => self.players[target.side]
+                .partner
+                .as_ref()
+                .is_some_and(|partner| partner.acted_this_turn),
+        }
+    }
+
+    pub fn set_acted_this_turn(&mut self, target: PlayerTarget, acted: bool) {
+        match target.slot {
+            0 => self.acted_this_turn[target.side] = acted,
+            _ => {
+                if let Some(partner) = self.players[target.side].partner.as_mut() {
+                    partner.acted_this_turn = acted;
+                }
+            }
+        }
+    }
+
+    /// Mark every Pokemon now on the field as having faced each one on the other side.
+    pub fn record_field_participation(&mut self) {
+        let targets = self.field_targets();
+        for &ours in targets.iter().filter(|target| target.side == 0) {
+            for &theirs in targets.iter().filter(|target| target.side == 1) {
+                let ours_index = self.battler(ours).pokemon_index();
+                let theirs_index = self.battler(theirs).pokemon_index();
+                self.participation_tracker
+                    .record_participation(ours_index, theirs_index);
+            }
+        }
+    }
+
+    /// Whether the sleep clause stops the opponent from putting `player_index`'s
+    /// active Pokémon to sleep, because another of its Pokémon they put to sleep
+    /// hasn't woken up yet.
+    pub fn sleep_clause_blocks(&self, player_index: usize) -> bool {
+        if !self.rules.sleep_clause {
+            return false;
+        }
+        let Some(team_index) = self.opponent_induced_sleep[player_index] else {
+            return false;
+        };
+        self.players[player_index].team[team_index]
+            .as_ref()
+            .is_some_and(|pokemon| {
+                matches!(pokemon.status, Some(crate::pokemon::StatusCondition::Sleep(turns)) if turns > 0)
+            })
+    }
+
+    /// Drop any induced-sleep record whose Pokémon is no longer asleep: it woke up, was
+    /// cured or fainted. A later Rest then doesn't count against the sleep clause.
+    pub(crate) fn forget_ended_induced_sleep(&mut self) {
+        for player_index in 0..2 {
+            let Some(team_index) = self.opponent_induced_sleep[player_index] else {
+                continue;
+            };
+            let still_asleep = self.players[player_index].team[team_index]
+                .as_ref()
+                .is_some_and(|pokemon| {
+                    matches!(
+                        pokemon.status,
+                        Some(crate::pokemon::StatusCondition::Sleep(_))
+                    )
+                });
+            if !still_asleep {
+                self.opponent_induced_sleep[player_index] = None;
+            }
+        }
+    }
+
+    /// Validate structural invariants of the battle state, returning the first violation found.
+    /// Intended for tests and fuzzing: any `Err` here points to an engine bug.
+    pub fn check_invariants(&self) -> Result<(), String> {
+        // No condition in the engine lasts longer than this many turns.
+        const MAX_CONDITION_TURNS: u8 = 8;
+
+        for (player_index, player) in self.players.iter().enumerate() {
+            let awaiting_replacement = match self.game_state {
+                GameState::WaitingForPlayer1Replacement => player_index == 0,
+                GameState::WaitingForPlayer2Replacement => player_index == 1,
+                GameState::WaitingForBothReplacements => true,
+                _ => false,
+            };
+            if !awaiting_replacement && player.active_pokemon().is_none() {
+                return Err(format!(
+                    "Player {} has no Pokemon in active slot {}",
+                    player_index, player.active_pokemon_index
+                ));
+            }
+
+            for pokemon in player.team.iter().flatten() {
+                if pokemon.current_hp() > pokemon.max_hp() {
+                    return Err(format!(
+                        "{:?} has {} HP, above its max of {}",
+                        pokemon.species,
+                        pokemon.current_hp(),
+                        pokemon.max_hp()
+                    ));
+                }
+                for move_instance in pokemon.moves.iter().flatten() {
+                    if move_instance.pp > move_instance.max_pp() {
+                        return Err(format!(
+                            "{:?}'s {:?} has {} PP, above its max of {}",
+                            pokemon.species,
+                            move_instance.move_,
+                            move_instance.pp,
+                            move_instance.max_pp()
+                        ));
+                    }
+                }
+            }
+
+            for (stat, stage) in &player.stat_stages {
+                if !(-6..=6).contains(stage) {
+                    return Err(format!(
+                        "Player {} has {:?} at stage {}, outside -6..=6",
+                        player_index, stat, stage
+                    ));
+                }
+            }
+
+            for condition in player.active_pokemon_conditions.values() {
+                let turns = match condition {
+                    PokemonCondition::Confused { turns_remaining }
+                    | PokemonCondition::Exhausted { turns_remaining }
+                    | PokemonCondition::Trapped { turns_remaining }
+                    | PokemonCondition::SwitchLocked { turns_remaining }
+                    | PokemonCondition::Trapping { turns_remaining }
+                    | PokemonCondition::Rampaging { turns_remaining }
+                    | PokemonCondition::Disabled {
+                        turns_remaining, ..
+                    }
+                    | PokemonCondition::Cooldown {
+                        turns_remaining, ..
+                    }
+                    | PokemonCondition::Biding {
+                        turns_remaining, ..
+                    } => *turns_remaining,
+                    _ => 0,
+                };
+                if turns > MAX_CONDITION_TURNS {
+                    return Err(format!(
+                        "Player {} has {:?} with {} turns remaining",
+                        player_index, condition, turns
+                    ));
+                }
+            }
+
+            for (condition, turns) in &player.team_conditions {
+                if *turns > MAX_CONDITION_TURNS {
+                    return Err(format!(
+                        "Player {} has {:?} with {} turns remaining",
+                        player_index, condition, turns
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Panic with a descriptive message if any invariant from `check_invariants` is broken.
+    pub fn assert_invariants(&self) {
+        if let Err(violation) = self.check_invariants() {
+            panic!("Battle state invariant violated: {}", violation);
+        }
+    }
+
+    /// The opening narration: each side sending out its active Pokemon (both of them in a
+    /// double battle). Used both when a battle begins and when a saved battle is resumed.
+    pub fn emit_start_events(&self) -> EventBus {
+        let mut bus = EventBus::new();
+        for (player_index, player) in self.players.iter().enumerate() {
+            let partner = player
+                .partner
+                .as_ref()
+                .and_then(|partner| player.team[partner.pokemon_index].as_ref());
+            for pokemon in player.active_pokemon().into_iter().chain(partner) {
+                bus.push(BattleEvent::PokemonSentOut {
+                    player_index,
+                    pokemon: pokemon.species,
+                });
+            }
         }
+        bus
+    }
+
+    /// Credit every Pokemon that took part in this battle with one more lifetime
+    /// participation. Called once, when the battle reaches its result.
+    pub fn record_participation_totals(&mut self) {
+        for (player_index, player) in self.players.iter_mut().enumerate() {
+            for (pokemon_index, slot) in player.team.iter_mut().enumerate() {
+                if let Some(pokemon) = slot {
+                    if self
+                        .participation_tracker
+                        .has_participated(player_index, pokemon_index)
+                    {
+                        pokemon.times_participated += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Whether the battle has ended in a win for either side or a draw.
+    pub fn is_over(&self) -> bool {
+        self.winner().is_some()
+    }
+
+    /// The result of the battle: `None` while it's still going, `Some(None)` for a draw,
+    /// and `Some(Some(i))` when player `i` won.
+    pub fn winner(&self) -> Option<Option<usize>> {
+        match self.game_state {
+            GameState::Player1Win => Some(Some(0)),
+            GameState::Player2Win => Some(Some(1)),
+            GameState::Draw => Some(None),
+            _ => None,
+        }
+    }
+
+    /// Fixed-length numeric encoding of the battle for training agents.
+    /// See `FeatureVector` for the layout.
+    pub fn feature_snapshot(&self) -> FeatureVector {
+        FeatureVector::from_state(self)
+    }
+
+    /// Which player's active Pokemon is faster right now, factoring in paralysis and
+    /// stat stages. Returns `None` on a speed tie or if either side has no active Pokemon.
+    pub fn faster_side(&self) -> Option<usize> {
+        let speeds: Vec<u16> = self
+            .players
+            .iter()
+            .map(|player| {
+                player.active_pokemon().map(|pokemon| {
+                    crate::battle::stats::effective_speed(pokemon, player.battler(0))
+                })
+            })
+            .collect::<Option<_>>()?;
+
+        match speeds[0].cmp(&speeds[1]) {
+            std::cmp::Ordering::Greater => Some(0),
+            std::cmp::Ordering::Less => Some(1),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+
+    /// Predict whether the player's move in `move_index` is sure to fail if chosen now.
+    /// This mirrors the deterministic checks in `calculate_action_prevention` without
+    /// consuming any RNG, so UIs can gray out and explain unusable moves.
+    pub fn move_would_fail(
+        &self,
+        player_index: usize,
+        move_index: usize,
+    ) -> Option<PredictedFailure> {
+        let player = &self.players[player_index];
+        let pokemon = player.active_pokemon()?;
+        let Some(move_instance) = pokemon.moves.get(move_index).and_then(|slot| slot.as_ref())
+        else {
+            return Some(PredictedFailure::NoMoveInSlot);
+        };
+        let move_used = move_instance.move_;
+
+        if move_instance.pp == 0 {
+            return Some(PredictedFailure::NoPPRemaining { move_used });
+        }
+
+        let conditions = &player.active_pokemon_conditions;
+        if conditions.values().any(|condition| {
+            matches!(condition, PokemonCondition::Exhausted { turns_remaining } if *turns_remaining > 0)
+        }) {
+            return Some(PredictedFailure::MustRecharge);
+        }
+        if conditions.values().any(|condition| {
+            matches!(condition, PokemonCondition::Trapped { turns_remaining } if *turns_remaining > 0)
+        }) {
+            return Some(PredictedFailure::IsTrapped);
+        }
+
+        if matches!(
+            pokemon.status,
+            Some(crate::pokemon::StatusCondition::Sleep(turns)) if turns > 0
+        ) {
+            return Some(PredictedFailure::IsAsleep);
+        }
+
+        if matches!(
+            pokemon.status,
+            Some(crate::pokemon::StatusCondition::Freeze)
+        ) && !self.rules.gen_quirks.frozen_self_thaw()
+        {
+            let thaws_user = crate::move_data::get_move_data(move_used)
+                .is_ok_and(|move_data| move_data.thaws_user);
+            if !thaws_user {
+                return Some(PredictedFailure::IsFrozen);
+            }
+        }
+
+        if conditions.values().any(|condition| {
+            matches!(
+                condition,
+                PokemonCondition::Disabled { pokemon_move, turns_remaining }
+                    | PokemonCondition::Cooldown { pokemon_move, turns_remaining }
+                    if *turns_remaining > 0 && *pokemon_move == move_used
+            )
+        }) {
+            return Some(PredictedFailure::Disabled { move_used });
+        }
+
+        if let Ok(move_data) = crate::move_data::get_move_data(move_used) {
+            if move_data
+                .effects
+                .iter()
+                .any(|effect| matches!(effect, schema::MoveEffect::Nightmare))
+            {
+                let target_is_asleep =
+                    self.players[1 - player_index]
+                        .active_pokemon()
+                        .is_some_and(|target| {
+                            matches!(
+                                target.status,
+                                Some(crate::pokemon::StatusCondition::Sleep(_))
+                            )
+                        });
+                if !target_is_asleep {
+                    return Some(PredictedFailure::TargetNotAsleep { move_used });
+                }
+            }
+        }
+
+        None
     }
 }
 
@@ -1,26 +1,23 @@
 use crate::battle::conditions::{PokemonCondition, PokemonConditionType};
 use crate::errors::BattleResult;
 use crate::move_data::get_move_data;
-use crate::player::{BattlePlayer, StatType};
+use crate::player::{Battler, StatType};
 use crate::pokemon::PokemonInst;
 use schema::{Move, MoveCategory, MoveEffect, PokemonType};
 
 /// Calculate effective attack stat including stat stages, conditions, and other modifiers
-pub fn effective_attack(
-    pokemon: &PokemonInst,
-    player: &BattlePlayer,
-    move_: Move,
-) -> BattleResult<u16> {
+pub fn effective_attack(pokemon: &PokemonInst, battler: Battler, move_: Move) -> BattleResult<u16> {
     let move_data = get_move_data(move_)?;
 
     // Check if transformed - use target Pokemon's base stats
-    let base_attack = if let Some(transform_condition) = player
-        .active_pokemon_conditions
-        .values()
-        .find_map(|condition| match condition {
-            PokemonCondition::Transformed { target } => Some(target),
-            _ => None,
-        }) {
+    let base_attack = if let Some(transform_condition) =
+        battler
+            .conditions()
+            .values()
+            .find_map(|condition| match condition {
+                PokemonCondition::Transformed { target, .. } => Some(target),
+                _ => None,
+            }) {
         match move_data.category {
             MoveCategory::Physical => transform_condition.stats.attack,
             MoveCategory::Special => transform_condition.stats.sp_attack,
@@ -44,7 +41,7 @@ pub fn effective_attack(
         MoveCategory::Other => return Ok(0),
     };
 
-    let stage = player.get_stat_stage(attack_stat);
+    let stage = battler.get_stat_stage(attack_stat);
     let mut multiplied_attack = apply_stat_stage_multiplier(base_attack, stage);
 
     // Apply burn status (halves physical attack only)
@@ -67,19 +64,20 @@ pub fn effective_attack(
 /// Calculate effective defense stat including stat stages, conditions, and other modifiers
 pub fn effective_defense(
     pokemon: &PokemonInst,
-    player: &BattlePlayer,
+    battler: Battler,
     move_: Move,
 ) -> BattleResult<u16> {
     let move_data = get_move_data(move_)?;
 
     // Check if transformed - use target Pokemon's base stats
-    let base_defense = if let Some(transform_condition) = player
-        .active_pokemon_conditions
-        .values()
-        .find_map(|condition| match condition {
-            PokemonCondition::Transformed { target } => Some(target),
-            _ => None,
-        }) {
+    let base_defense = if let Some(transform_condition) =
+        battler
+            .conditions()
+            .values()
+            .find_map(|condition| match condition {
+                PokemonCondition::Transformed { target, .. } => Some(target),
+                _ => None,
+            }) {
         match move_data.category {
             MoveCategory::Physical => transform_condition.stats.defense,
             MoveCategory::Special => transform_condition.stats.sp_defense,
@@ -103,7 +101,7 @@ pub fn effective_defense(
         MoveCategory::Other => return Ok(0),
     };
 
-    let stage = player.get_stat_stage(defense_stat);
+    let stage = battler.get_stat_stage(defense_stat);
     let mut multiplied_defense = apply_stat_stage_multiplier(base_defense, stage);
     for effect in &move_data.effects {
         if let MoveEffect::IgnoreDef(percentage) = effect {
@@ -124,13 +122,13 @@ pub fn effective_defense(
     match move_data.category {
         MoveCategory::Physical => {
             // Reflect reduces damage from physical moves by 50%
-            if player.has_team_condition(&crate::player::TeamCondition::Reflect) {
+            if battler.has_team_condition(&crate::player::TeamCondition::Reflect) {
                 multiplied_defense = (multiplied_defense as f64 * 2.0).round() as u16;
             }
         }
         MoveCategory::Special => {
             // Light Screen reduces damage from special moves by 50%
-            if player.has_team_condition(&crate::player::TeamCondition::LightScreen) {
+            if battler.has_team_condition(&crate::player::TeamCondition::LightScreen) {
                 multiplied_defense = (multiplied_defense as f64 * 2.0).round() as u16;
             }
         }
@@ -146,22 +144,23 @@ pub fn effective_defense(
 }
 
 /// Calculate effective speed including stat stages, paralysis, and other modifiers
-pub fn effective_speed(pokemon: &PokemonInst, player: &BattlePlayer) -> u16 {
+pub fn effective_speed(pokemon: &PokemonInst, battler: Battler) -> u16 {
     // Check if transformed - use target Pokemon's base speed
-    let base_speed = if let Some(transform_condition) = player
-        .active_pokemon_conditions
-        .values()
-        .find_map(|condition| match condition {
-            PokemonCondition::Transformed { target } => Some(target),
-            _ => None,
-        }) {
+    let base_speed = if let Some(transform_condition) =
+        battler
+            .conditions()
+            .values()
+            .find_map(|condition| match condition {
+                PokemonCondition::Transformed { target, .. } => Some(target),
+                _ => None,
+            }) {
         transform_condition.stats.speed
     } else {
         pokemon.stats.speed
     };
 
     // Apply stat stage modifiers
-    let stage = player.get_stat_stage(StatType::Spe);
+    let stage = battler.get_stat_stage(StatType::Spe);
     let mut multiplied_speed = apply_stat_stage_multiplier(base_speed, stage);
 
     // Apply paralysis (quarter speed)
@@ -180,7 +179,7 @@ pub fn effective_speed(pokemon: &PokemonInst, player: &BattlePlayer) -> u16 {
 /// Returns true if the move is a critical hit
 pub fn move_is_critical_hit(
     _attacker: &PokemonInst,
-    attacker_player: &BattlePlayer,
+    attacker_battler: Battler,
     move_: Move,
     rng: &mut crate::battle::state::TurnRng,
 ) -> BattleResult<bool> {
@@ -202,7 +201,7 @@ pub fn move_is_critical_hit(
     }
 
     // Check for Focus Energy stat stage (increases crit ratio)
-    let focus_stage = attacker_player.get_stat_stage(StatType::Crit);
+    let focus_stage = attacker_battler.get_stat_stage(StatType::Crit);
     if focus_stage > 0 {
         crit_ratio = crit_ratio.saturating_add(focus_stage as u8);
     }
@@ -228,10 +227,10 @@ pub fn move_is_critical_hit(
 /// Calculate if a move hits based on accuracy, evasion, and move accuracy
 /// Returns true if the move hits, false if it misses
 pub fn move_hits(
-    _attacker: &PokemonInst,
-    _defender: &PokemonInst,
-    attacker_player: &BattlePlayer,
-    defender_player: &BattlePlayer,
+    attacker: &PokemonInst,
+    defender: &PokemonInst,
+    attacker_battler: Battler,
+    defender_battler: Battler,
     move_: Move,
     rng: &mut crate::battle::state::TurnRng,
 ) -> BattleResult<bool> {
@@ -243,24 +242,37 @@ pub fn move_hits(
     };
 
     // If defender is Teleported, InAir, or Underground, moves with accuracy always miss
-    if defender_player.has_condition_type(PokemonConditionType::Teleported)
-        || defender_player.has_condition_type(PokemonConditionType::InAir)
-        || defender_player.has_condition_type(PokemonConditionType::Underground)
+    if defender_battler.has_condition_type(PokemonConditionType::Teleported)
+        || defender_battler.has_condition_type(PokemonConditionType::InAir)
+        || defender_battler.has_condition_type(PokemonConditionType::Underground)
     {
         return Ok(false);
     }
 
-    // Calculate adjusted stages: attacker's accuracy - defender's evasion
-    let accuracy_stage = attacker_player.get_stat_stage(StatType::Acc);
-    let evasion_stage = defender_player.get_stat_stage(StatType::Eva);
-    let adjusted_stage = (accuracy_stage - evasion_stage).clamp(-6, 6);
+    let clamped_accuracy = if move_data
+        .effects
+        .iter()
+        .any(|effect| matches!(effect, MoveEffect::OHKO))
+    {
+        // OHKO moves ignore accuracy and evasion stages. They can't hit a higher-level
+        // target, and gain a point of accuracy for each level the user has over it.
+        let Some(level_gap) = attacker.level.checked_sub(defender.level) else {
+            return Ok(false);
+        };
+        base_accuracy.saturating_add(level_gap).min(100)
+    } else {
+        // Calculate adjusted stages: attacker's accuracy - defender's evasion
+        let accuracy_stage = attacker_battler.get_stat_stage(StatType::Acc);
+        let evasion_stage = defender_battler.get_stat_stage(StatType::Eva);
+        let adjusted_stage = (accuracy_stage - evasion_stage).clamp(-6, 6);
 
-    // Calculate stage multiplier
-    let stage_multiplier = apply_accuracy_stage_multiplier(adjusted_stage);
+        // Calculate stage multiplier
+        let stage_multiplier = apply_accuracy_stage_multiplier(adjusted_stage);
 
-    // Calculate final accuracy threshold
-    let modified_accuracy = (base_accuracy as f64 * stage_multiplier).round() as u8;
-    let clamped_accuracy = modified_accuracy.clamp(1, 100);
+        // Calculate final accuracy threshold
+        let modified_accuracy = (base_accuracy as f64 * stage_multiplier).round() as u8;
+        modified_accuracy.clamp(1, 100)
+    };
 
     // Roll for hit/miss
     let roll = rng.next_outcome("Hit/Miss Check");
@@ -322,15 +334,55 @@ pub fn is_immune(attack_type: PokemonType, defense_types: &[PokemonType]) -> boo
         .any(|&def_type| PokemonType::type_effectiveness(attack_type, def_type) == 0.0)
 }
 
+/// Lowest Gen 1 damage roll: damage is multiplied by a roll in
+/// `MIN_DAMAGE_ROLL..=MAX_DAMAGE_ROLL` and divided by 255, so this is ~85%.
+pub const MIN_DAMAGE_ROLL: u16 = 217;
+/// Highest Gen 1 damage roll, giving full (100%) damage.
+pub const MAX_DAMAGE_ROLL: u16 = 255;
+
+/// Map a `TurnRng` outcome (1-100) onto a Gen 1 damage roll in
+/// `MIN_DAMAGE_ROLL..=MAX_DAMAGE_ROLL`. Higher outcomes never roll lower.
+pub fn damage_roll(outcome: u8) -> u16 {
+    let outcome = outcome.clamp(1, 100) as u16;
+    MIN_DAMAGE_ROLL + (outcome - 1) * (MAX_DAMAGE_ROLL - MIN_DAMAGE_ROLL) / 99
+}
+
 /// Formula: ((((2 * Level / 5 + 2) * Power * STAB * A / D) / 50 + 2) * CRIT * TYPE_ADV * RAND * MODIFIERS)
 pub fn calculate_attack_damage(
     attacker: &PokemonInst,
     defender: &PokemonInst,
-    attacker_player: &BattlePlayer,
-    defender_player: &BattlePlayer,
+    attacker_battler: Battler,
+    defender_battler: Battler,
     move_used: Move,
     is_critical: bool,
     rng: &mut crate::battle::state::TurnRng,
+) -> BattleResult<u16> {
+    // Moves without power never roll for damage variance.
+    if get_move_data(move_used)?.power.unwrap_or(0) == 0 {
+        return Ok(0);
+    }
+    let roll = damage_roll(rng.next_outcome("Random Damage Multiplier Roll"));
+    calculate_attack_damage_with_roll(
+        attacker,
+        defender,
+        attacker_battler,
+        defender_battler,
+        move_used,
+        is_critical,
+        roll,
+    )
+}
+
+/// `calculate_attack_damage` with a fixed damage roll instead of one drawn from the RNG.
+/// Passing `MIN_DAMAGE_ROLL` and `MAX_DAMAGE_ROLL` gives the endpoints of a damage preview.
+pub fn calculate_attack_damage_with_roll(
+    attacker: &PokemonInst,
+    defender: &PokemonInst,
+    attacker_battler: Battler,
+    defender_battler: Battler,
+    move_used: Move,
+    is_critical: bool,
+    roll: u16,
 ) -> BattleResult<u16> {
     let move_data = get_move_data(move_used)?;
 
@@ -344,15 +396,15 @@ pub fn calculate_attack_damage(
 
     // 2. Determine effective Attack and Defense stats.
     // These functions already account for stat stages, burn, etc.
-    let attack = effective_attack(attacker, attacker_player, move_used)?;
-    let defense = effective_defense(defender, defender_player, move_used)?;
+    let attack = effective_attack(attacker, attacker_battler, move_used)?;
+    let defense = effective_defense(defender, defender_battler, move_used)?;
 
     // Assume a fixed level for all battle calculations, a common standard for competitive play.
     let level: u16 = 50;
 
     // 3. Calculate STAB (Same-Type Attack Bonus)
     let stab_multiplier = {
-        let attacker_types = attacker.get_current_types(attacker_player);
+        let attacker_types = attacker.get_current_types(attacker_battler);
         if attacker_types.contains(&move_data.move_type) {
             1.5
         } else {
@@ -361,29 +413,28 @@ pub fn calculate_attack_damage(
     };
 
     // 4. Calculate the core part of the formula using integer arithmetic first.
-    let term1 = (2 * level / 5) + 2;
+    // Gen 1 critical hits double the level in the formula rather than the final damage,
+    // so they land a little short of twice a normal hit.
+    let effective_level = if is_critical { level * 2 } else { level };
+    let term1 = (2 * effective_level / 5) + 2;
     // We cast to f64 to incorporate the STAB multiplier before the main division.
     let base_damage_part =
         (term1 as f64) * (power as f64) * (stab_multiplier) * (attack as f64) / (defense as f64);
     let base_damage = (base_damage_part / 50.0) + 2.0;
 
     // 5. Gather all final multipliers.
-    // Critical Hit: 2x multiplier
-    let crit_multiplier = if is_critical { 2.0 } else { 1.0 };
-
     // Use the centralized type getter that handles Transform and Conversion
-    let defender_types = defender.get_current_types(defender_player);
+    let defender_types = defender.get_current_types(defender_battler);
     let type_adv_multiplier = get_type_effectiveness(move_data.move_type, &defender_types);
-    // Random Variance: A random multiplier between 0.85 and 1.00
-    let random_multiplier =
-        (85.0 + (rng.next_outcome("Random Damage Multiplier Roll") % 16) as f64) / 100.0;
+    // Random Variance: the Gen 1 roll of 217-255 out of 255 (~0.85 to 1.00)
+    let random_multiplier = roll as f64 / MAX_DAMAGE_ROLL as f64;
 
     // Other modifiers (e.g., from items, abilities). Placeholder for now.
     let other_modifiers = 1.0;
 
     // 6. Apply all multipliers to the base damage.
     let final_damage_float =
-        base_damage * crit_multiplier * type_adv_multiplier * random_multiplier * other_modifiers;
+        base_damage * type_adv_multiplier * random_multiplier * other_modifiers;
 
     // 7. Convert to integer and ensure damage is at least 1 unless the pokemon is immune.
     let final_damage = final_damage_float.ceil() as u16;
@@ -433,7 +484,9 @@ pub fn calculate_special_attack_damage(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::battle::tests::common::{assert_ok, assert_ok_false, assert_ok_true};
+    use crate::battle::tests::common::{
+        assert_ok, assert_ok_false, assert_ok_true, create_test_player, TestPokemonBuilder,
+    };
     use crate::species::Species;
     use std::collections::HashMap;
 
@@ -484,15 +537,18 @@ mod tests {
             team_conditions: HashMap::new(),
             active_pokemon_conditions: HashMap::new(),
             last_move: None,
+            last_damage_taken: None,
+            partner: None,
             ante: 200,
+            bag: HashMap::new(),
         };
 
         // Paralysis should quarter speed: 100 / 4 = 25
-        assert_eq!(effective_speed(&pokemon, &player), 25);
+        assert_eq!(effective_speed(&pokemon, player.battler(0)), 25);
 
         // Test without paralysis
         pokemon.status = None;
-        assert_eq!(effective_speed(&pokemon, &player), 100);
+        assert_eq!(effective_speed(&pokemon, player.battler(0)), 100);
     }
 
     #[test]
@@ -521,33 +577,105 @@ mod tests {
             team_conditions: HashMap::new(),
             active_pokemon_conditions: HashMap::new(),
             last_move: None,
+            last_damage_taken: None,
+            partner: None,
             ante: 200,
+            bag: HashMap::new(),
         };
 
         // Burn should halve physical attack: 80 / 2 = 40
         assert_eq!(
-            assert_ok(effective_attack(&pokemon, &player, schema::Move::Tackle)),
+            assert_ok(effective_attack(
+                &pokemon,
+                player.battler(0),
+                schema::Move::Tackle
+            )),
             40
         );
 
         // Burn should NOT affect special attacks
         assert_eq!(
-            assert_ok(effective_attack(&pokemon, &player, schema::Move::Ember)),
+            assert_ok(effective_attack(
+                &pokemon,
+                player.battler(0),
+                schema::Move::Ember
+            )),
             80
         );
 
         // Test without burn
         pokemon.status = None;
         assert_eq!(
-            assert_ok(effective_attack(&pokemon, &player, schema::Move::Tackle)),
+            assert_ok(effective_attack(
+                &pokemon,
+                player.battler(0),
+                schema::Move::Tackle
+            )),
             80
         );
         assert_eq!(
-            assert_ok(effective_attack(&pokemon, &player, schema::Move::Ember)),
+            assert_ok(effective_attack(
+                &pokemon,
+                player.battler(0),
+                schema::Move::Ember
+            )),
             80
         );
     }
 
+    #[test]
+    fn test_explode_moves_halve_target_defense() {
+        let pokemon = crate::pokemon::PokemonInst::new_for_test(
+            Species::Snorlax,
+            0,
+            0,
+            100,
+            [15; 6],
+            [0; 6],
+            [100, 80, 90, 80, 80, 100], // Defense = 90
+            [const { None }; 4],
+            None,
+        );
+
+        let player = crate::player::BattlePlayer {
+            player_id: "test".to_string(),
+            player_name: "Test".to_string(),
+            player_type: crate::player::PlayerType::NPC,
+            team: [const { None }; 6],
+            active_pokemon_index: 0,
+            stat_stages: HashMap::new(),
+            team_conditions: HashMap::new(),
+            active_pokemon_conditions: HashMap::new(),
+            last_move: None,
+            last_damage_taken: None,
+            partner: None,
+            ante: 0,
+            bag: HashMap::new(),
+        };
+
+        assert_eq!(
+            assert_ok(effective_defense(&pokemon, player.battler(0), Move::Tackle)),
+            90
+        );
+        // Gen 1 quirk: Self-Destruct and Explosion halve the target's Defense.
+        assert_eq!(
+            assert_ok(effective_defense(
+                &pokemon,
+                player.battler(0),
+                Move::SelfDestruct
+            )),
+            45
+        );
+        assert_eq!(
+            assert_ok(effective_defense(
+                &pokemon,
+                player.battler(0),
+                Move::Explosion
+            )),
+            45
+        );
+    }
+
     #[test]
     fn test_critical_hit_calculation() {
         // Initialize move data (required for get_move_data to work)
@@ -574,14 +702,17 @@ mod tests {
             team_conditions: HashMap::new(),
             active_pokemon_conditions: HashMap::new(),
             last_move: None,
+            last_damage_taken: None,
+            partner: None,
             ante: 0,
+            bag: HashMap::new(),
         };
 
         // Test with deterministic RNG - low roll should not be critical hit
         let mut rng_low = crate::battle::state::TurnRng::new_for_test(vec![10, 10, 10]);
         assert_ok_false(move_is_critical_hit(
             &pokemon,
-            &player,
+            player.battler(0),
             schema::Move::Tackle,
             &mut rng_low,
         ));
@@ -590,7 +721,7 @@ mod tests {
         let mut rng_high = crate::battle::state::TurnRng::new_for_test(vec![3, 3, 3]);
         assert_ok_true(move_is_critical_hit(
             &pokemon,
-            &player,
+            player.battler(0),
             schema::Move::Tackle,
             &mut rng_high,
         ));
@@ -600,7 +731,7 @@ mod tests {
         let mut rng_focus = crate::battle::state::TurnRng::new_for_test(vec![20, 20, 20]);
         assert_ok_true(move_is_critical_hit(
             &pokemon,
-            &player,
+            player.battler(0),
             schema::Move::Tackle,
             &mut rng_focus,
         ));
@@ -609,7 +740,7 @@ mod tests {
         let mut rng_status = crate::battle::state::TurnRng::new_for_test(vec![99, 99, 99]);
         assert_ok_false(move_is_critical_hit(
             &pokemon,
-            &player,
+            player.battler(0),
             schema::Move::Growl,
             &mut rng_status,
         ));
@@ -655,14 +786,17 @@ mod tests {
             team_conditions: HashMap::new(),
             active_pokemon_conditions: HashMap::new(),
             last_move: None,
+            last_damage_taken: None,
+            partner: None,
             ante: 200,
+            bag: HashMap::new(),
         };
 
         // Test burn effects
         assert_eq!(
             assert_ok(effective_attack(
                 &burned_pokemon,
-                &player,
+                player.battler(0),
                 schema::Move::Tackle
             )),
             40,
@@ -671,28 +805,28 @@ mod tests {
         assert_eq!(
             assert_ok(effective_attack(
                 &burned_pokemon,
-                &player,
+                player.battler(0),
                 schema::Move::Ember
             )),
             80,
             "Burn should NOT affect special attack"
         );
         assert_eq!(
-            effective_speed(&burned_pokemon, &player),
+            effective_speed(&burned_pokemon, player.battler(0)),
             100,
             "Burn should NOT affect speed"
         );
 
         // Test paralysis effects
         assert_eq!(
-            effective_speed(&paralyzed_pokemon, &player),
+            effective_speed(&paralyzed_pokemon, player.battler(0)),
             25,
             "Paralysis should quarter speed: 100/4=25"
         );
         assert_eq!(
             assert_ok(effective_attack(
                 &paralyzed_pokemon,
-                &player,
+                player.battler(0),
                 schema::Move::Tackle
             )),
             80,
@@ -701,7 +835,7 @@ mod tests {
         assert_eq!(
             assert_ok(effective_attack(
                 &paralyzed_pokemon,
-                &player,
+                player.battler(0),
                 schema::Move::ThunderPunch
             )),
             80,
@@ -715,11 +849,254 @@ mod tests {
         assert_eq!(
             assert_ok(effective_attack(
                 &burned_pokemon,
-                &player,
+                player.battler(0),
                 schema::Move::Tackle
             )),
             80
         );
-        assert_eq!(effective_speed(&paralyzed_pokemon, &player), 100);
+        assert_eq!(effective_speed(&paralyzed_pokemon, player.battler(0)), 100);
+    }
+
+    #[test]
+    fn test_offensive_coverage_for_pikachu() {
+        let species_data = crate::get_species_data(Species::Pikachu).unwrap();
+        let pikachu = PokemonInst::new(
+            Species::Pikachu,
+            species_data,
+            25,
+            None,
+            Some(vec![Move::Lightning, Move::QuickAttack]),
+        );
+
+        let coverage = pikachu.offensive_coverage(false);
+        assert_eq!(coverage.len(), PokemonType::ALL.len());
+        assert_eq!(coverage[&PokemonType::Water], 2.0);
+        assert_eq!(coverage[&PokemonType::Flying], 2.0);
+        // Ground shrugs off Electric, so only Quick Attack's neutral hit is left.
+        assert_eq!(coverage[&PokemonType::Ground], 1.0);
+        // Quick Attack can't touch Ghost, but Electric still hits it.
+        assert_eq!(coverage[&PokemonType::Ghost], 1.0);
+
+        let with_stab = pikachu.offensive_coverage(true);
+        assert_eq!(with_stab[&PokemonType::Water], 3.0);
+        assert_eq!(with_stab[&PokemonType::Ground], 1.0);
+    }
+
+    #[test]
+    fn test_struggle_is_neutral_against_every_type() {
+        // Struggle is typeless, so nothing resists it and even Ghosts can be hit.
+        let struggle_type = get_move_data(Move::Struggle).unwrap().move_type;
+        assert_eq!(struggle_type, PokemonType::Typeless);
+
+        let all_types = [
+            PokemonType::Normal,
+            PokemonType::Fighting,
+            PokemonType::Flying,
+            PokemonType::Poison,
+            PokemonType::Ground,
+            PokemonType::Rock,
+            PokemonType::Bug,
+            PokemonType::Ghost,
+            PokemonType::Fire,
+            PokemonType::Water,
+            PokemonType::Grass,
+            PokemonType::Electric,
+            PokemonType::Psychic,
+            PokemonType::Ice,
+            PokemonType::Dragon,
+            PokemonType::Typeless,
+        ];
+        for defense_type in all_types {
+            assert_eq!(
+                get_type_effectiveness(struggle_type, &[defense_type]),
+                1.0,
+                "Struggle should be neutral against {}",
+                defense_type
+            );
+            assert!(!is_immune(struggle_type, &[defense_type]));
+        }
+        assert_eq!(
+            get_type_effectiveness(struggle_type, &[PokemonType::Ghost, PokemonType::Poison]),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_critical_hit_doubles_level_term() {
+        use crate::battle::tests::common::{create_test_player, TestPokemonBuilder};
+
+        // Pikachu's Tackle into Rattata: no STAB, neutral typing.
+        let pikachu = TestPokemonBuilder::new(Species::Pikachu, 50)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let rattata = TestPokemonBuilder::new(Species::Rattata, 50).build();
+        let attacker_player = create_test_player("p1", "Player 1", vec![pikachu.clone()]);
+        let defender_player = create_test_player("p2", "Player 2", vec![rattata.clone()]);
+
+        let attack =
+            effective_attack(&pikachu, attacker_player.battler(0), Move::Tackle).unwrap() as f64;
+        let defense =
+            effective_defense(&rattata, defender_player.battler(0), Move::Tackle).unwrap() as f64;
+        // Gen 1: ((2 * Level / 5 + 2) * Power * Attack / Defense) / 50 + 2, with Level
+        // doubled from 50 to 100 on a critical hit.
+        let gen1_crit = ((42.0 * 40.0 * attack / defense) / 50.0 + 2.0).ceil() as u16;
+        let naive_double = (2.0 * ((22.0 * 40.0 * attack / defense) / 50.0 + 2.0)).ceil() as u16;
+
+        // Roll 100 gives the maximum (255/255) damage variance.
+        let mut rng = crate::battle::state::TurnRng::new_for_test(vec![100]);
+        let crit_damage = calculate_attack_damage(
+            &pikachu,
+            &rattata,
+            attacker_player.battler(0),
+            defender_player.battler(0),
+            Move::Tackle,
+            true,
+            &mut rng,
+        )
+        .unwrap();
+
+        assert_eq!(crit_damage, gen1_crit);
+        assert!(crit_damage < naive_double);
+    }
+
+    #[test]
+    fn test_damage_roll_endpoints_match_gen1_factor() {
+        use crate::battle::tests::common::{create_test_player, TestPokemonBuilder};
+
+        let pikachu = TestPokemonBuilder::new(Species::Pikachu, 50)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let rattata = TestPokemonBuilder::new(Species::Rattata, 50).build();
+        let attacker_player = create_test_player("p1", "Player 1", vec![pikachu.clone()]);
+        let defender_player = create_test_player("p2", "Player 2", vec![rattata.clone()]);
+
+        let attack =
+            effective_attack(&pikachu, attacker_player.battler(0), Move::Tackle).unwrap() as f64;
+        let defense =
+            effective_defense(&rattata, defender_player.battler(0), Move::Tackle).unwrap() as f64;
+        let base_damage = (22.0 * 40.0 * attack / defense) / 50.0 + 2.0;
+        let expected_min = (base_damage * 217.0 / 255.0).ceil() as u16;
+        let expected_max = base_damage.ceil() as u16;
+
+        assert_eq!(damage_roll(1), MIN_DAMAGE_ROLL);
+        assert_eq!(damage_roll(100), MAX_DAMAGE_ROLL);
+        assert!((1..100).all(|outcome| damage_roll(outcome) <= damage_roll(outcome + 1)));
+
+        let damage_for = |outcome: u8| {
+            let mut rng = crate::battle::state::TurnRng::new_for_test(vec![outcome]);
+            calculate_attack_damage(
+                &pikachu,
+                &rattata,
+                attacker_player.battler(0),
+                defender_player.battler(0),
+                Move::Tackle,
+                false,
+                &mut rng,
+            )
+            .unwrap()
+        };
+        assert_eq!(damage_for(1), expected_min);
+        assert_eq!(damage_for(100), expected_max);
+
+        // The preview endpoints agree with the rolled extremes.
+        let preview = |roll: u16| {
+            calculate_attack_damage_with_roll(
+                &pikachu,
+                &rattata,
+                attacker_player.battler(0),
+                defender_player.battler(0),
+                Move::Tackle,
+                false,
+                roll,
+            )
+            .unwrap()
+        };
+        assert_eq!(preview(MIN_DAMAGE_ROLL), expected_min);
+        assert_eq!(preview(MAX_DAMAGE_ROLL), expected_max);
+    }
+
+    fn hit_roll(attacker_level: u8, defender_level: u8, move_: Move, roll: u8) -> bool {
+        let attacker = TestPokemonBuilder::new(Species::Dugtrio, attacker_level)
+            .with_moves(vec![move_])
+            .build();
+        let defender = TestPokemonBuilder::new(Species::Snorlax, defender_level).build();
+        let attacker_player = create_test_player("p1", "Attacker", vec![attacker.clone()]);
+        let mut defender_player = create_test_player("p2", "Defender", vec![defender.clone()]);
+        // Evasion only matters for moves that use the normal accuracy check.
+        defender_player.set_stat_stage(StatType::Eva, 1);
+        let mut rng = crate::battle::state::TurnRng::new_for_test(vec![roll]);
+        assert_ok(move_hits(
+            &attacker,
+            &defender,
+            attacker_player.battler(0),
+            defender_player.battler(0),
+            move_,
+            &mut rng,
+        ))
+    }
+
+    #[test]
+    fn test_ohko_accuracy_scales_with_level_gap() {
+        // Same level: Fissure's listed 30%.
+        assert!(hit_roll(50, 50, Move::Fissure, 30));
+        assert!(!hit_roll(50, 50, Move::Fissure, 31));
+
+        // Ten levels up: 40%, with the defender's evasion ignored.
+        assert!(hit_roll(60, 50, Move::Fissure, 40));
+        assert!(!hit_roll(60, 50, Move::Fissure, 41));
+
+        // A lower-level user always misses.
+        assert!(!hit_roll(49, 50, Move::Fissure, 1));
+    }
+
+    #[test]
+    fn test_fixed_damage_moves_use_flat_accuracy() {
+        // Sonic Boom's 100% is cut to 75% by one stage of evasion, whatever the levels.
+        for (attacker_level, defender_level) in [(10, 50), (50, 50), (60, 50)] {
+            assert!(hit_roll(
+                attacker_level,
+                defender_level,
+                Move::SonicBoom,
+                75
+            ));
+            assert!(!hit_roll(
+                attacker_level,
+                defender_level,
+                Move::SonicBoom,
+                76
+            ));
+        }
+    }
+
+    #[test]
+    fn test_transformed_stats_use_own_stat_stages() {
+        let ditto = TestPokemonBuilder::new(Species::Ditto, 50).build();
+        let machamp = TestPokemonBuilder::new(Species::Machamp, 50).build();
+        let mut player = create_test_player("p1", "Ditto", vec![ditto.clone()]);
+        let mut target_player = create_test_player("p2", "Machamp", vec![machamp.clone()]);
+        player.add_condition(PokemonCondition::Transformed {
+            target: machamp.clone(),
+            original_moves: Default::default(),
+        });
+        player.set_stat_stage(StatType::Atk, 2);
+        player.set_stat_stage(StatType::Def, -1);
+        player.set_stat_stage(StatType::Spe, 1);
+        // The copied Pokemon's own stages must not carry over.
+        target_player.set_stat_stage(StatType::Atk, -6);
+        target_player.set_stat_stage(StatType::Spe, 6);
+
+        // Transformed base stat first, then Ditto's stages: +2 is x2, -1 is x2/3, +1 is x3/2.
+        assert_eq!(
+            assert_ok(effective_attack(&ditto, player.battler(0), Move::Tackle)),
+            machamp.stats.attack * 2
+        );
+        assert_eq!(
+            assert_ok(effective_defense(&ditto, player.battler(0), Move::Tackle)),
+            (f64::from(machamp.stats.defense) * 2.0 / 3.0).round() as u16
+        );
+        assert_eq!(
+            effective_speed(&ditto, player.battler(0)),
+            (f64::from(machamp.stats.speed) * 1.5).round() as u16
+        );
     }
 }
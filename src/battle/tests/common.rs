@@ -3,7 +3,7 @@ use crate::errors::BattleResult;
 use crate::player::BattlePlayer;
 use crate::pokemon::{PokemonInst, StatusCondition};
 use crate::species::Species;
-use schema::Move;
+use schema::{Item, Move};
 
 /// A builder for creating test Pokemon instances with common defaults.
 ///
@@ -20,6 +20,7 @@ pub struct TestPokemonBuilder {
     moves: Option<Vec<Move>>,
     status: Option<StatusCondition>,
     current_hp: Option<u16>,
+    held_item: Option<Item>,
 }
 
 impl TestPokemonBuilder {
@@ -31,6 +32,7 @@ impl TestPokemonBuilder {
             moves: None,
             status: None,
             current_hp: None,
+            held_item: None,
         }
     }
 
@@ -46,6 +48,12 @@ impl TestPokemonBuilder {
         self
     }
 
+    /// Gives the test Pokemon a held item.
+    pub fn with_held_item(mut self, item: Item) -> Self {
+        self.held_item = Some(item);
+        self
+    }
+
     /// Sets the current HP for the test Pokemon. If not set, HP will be max.
     pub fn with_hp(mut self, hp: u16) -> Self {
         self.current_hp = Some(hp);
@@ -71,6 +79,7 @@ impl TestPokemonBuilder {
         );
 
         pokemon.status = self.status;
+        pokemon.held_item = self.held_item;
 
         if let Some(hp) = self.current_hp {
             pokemon.set_hp(hp);
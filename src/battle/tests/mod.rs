@@ -1,32 +1,48 @@
 pub mod common;
 mod test_action_prevention;
+mod test_ai_memory;
 mod test_ante;
 mod test_catch;
 mod test_condition_damage;
+mod test_cooldown;
 mod test_critical_hits;
 mod test_cure_status;
 mod test_damage_effects;
+mod test_double_battle;
 mod test_end_of_turn;
 mod test_fainting;
+mod test_features;
+mod test_flinch;
 mod test_haze;
 mod test_heal;
+mod test_held_items;
 mod test_immunity;
+mod test_invariants;
+mod test_log;
 mod test_metronome;
 mod test_mist;
 mod test_multi_attacks;
 mod test_nightmare;
 mod test_npc_npc_battle;
 mod test_pp_use;
+mod test_random_ai;
 mod test_reckless;
 mod test_reflect_lightscreen;
 mod test_resolve_turn;
 mod test_rest;
+mod test_resume;
+mod test_reward_preview;
+mod test_simulation;
 mod test_simultaneous_multiturn_bug;
 mod test_special_damage_moves;
 mod test_special_moves;
 mod test_stat_modifiers;
 mod test_status_application;
 mod test_status_moves;
+mod test_substitute;
 mod test_switch;
 mod test_team_condition_expiry;
 mod test_team_condition_moves;
+mod test_thaw;
+mod test_turn_limit;
+mod test_usage_stats;
@@ -1,12 +1,16 @@
 #[cfg(test)]
 mod tests {
     use crate::battle::action_stack::{ActionStack, BattleAction};
+    use crate::battle::commands::PlayerTarget;
     use crate::battle::conditions::{PokemonCondition, PokemonConditionType};
-    use crate::battle::engine::execute_battle_action;
-    use crate::battle::state::{ActionFailureReason, BattleEvent, EventBus, TurnRng};
+    use crate::battle::engine::{execute_battle_action, resolve_turn};
+    use crate::battle::state::{
+        ActionFailureReason, BattleEvent, EventBus, GenQuirks, PredictedFailure, TurnRng,
+    };
     use crate::battle::tests::common::{
-        create_test_battle, create_test_player, TestPokemonBuilder,
+        create_test_battle, create_test_player, predictable_rng, TestPokemonBuilder,
     };
+    use crate::player::PlayerAction;
     use crate::pokemon::StatusCondition;
     use crate::species::Species;
     use pretty_assertions::assert_eq;
@@ -30,8 +34,8 @@ mod tests {
         // Act
         execute_battle_action(
             BattleAction::AttackHit {
-                attacker_index: 0,
-                defender_index: 1,
+                attacker: PlayerTarget::from_index(0),
+                defender: PlayerTarget::from_index(1),
                 move_used: Move::Tackle,
                 hit_number: 0,
             },
@@ -71,8 +75,8 @@ mod tests {
         // Act
         execute_battle_action(
             BattleAction::AttackHit {
-                attacker_index: 0,
-                defender_index: 1,
+                attacker: PlayerTarget::from_index(0),
+                defender: PlayerTarget::from_index(1),
                 move_used: Move::Tackle,
                 hit_number: 0,
             },
@@ -119,8 +123,8 @@ mod tests {
         // Act
         execute_battle_action(
             BattleAction::AttackHit {
-                attacker_index: 0,
-                defender_index: 1,
+                attacker: PlayerTarget::from_index(0),
+                defender: PlayerTarget::from_index(1),
                 move_used: Move::Ember,
                 hit_number: 0,
             },
@@ -195,8 +199,8 @@ mod tests {
         // Act
         execute_battle_action(
             BattleAction::AttackHit {
-                attacker_index: 0,
-                defender_index: 1,
+                attacker: PlayerTarget::from_index(0),
+                defender: PlayerTarget::from_index(1),
                 move_used: Move::Ember,
                 hit_number: 0,
             },
@@ -340,8 +344,8 @@ mod tests {
         // Act
         execute_battle_action(
             BattleAction::AttackHit {
-                attacker_index: 0,
-                defender_index: 1,
+                attacker: PlayerTarget::from_index(0),
+                defender: PlayerTarget::from_index(1),
                 move_used: Move::Tackle,
                 hit_number: 0,
             },
@@ -362,6 +366,106 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_full_paralysis_on_release_turn_loses_charge() {
+        // Arrange: Venusaur charged Solar Beam last turn and is now paralyzed.
+        let attacker = TestPokemonBuilder::new(Species::Venusaur, 50)
+            .with_moves(vec![Move::SolarBeam])
+            .with_status(StatusCondition::Paralysis)
+            .build();
+        let defender = TestPokemonBuilder::new(Species::Charmander, 50)
+            .with_moves(vec![Move::Tackle])
+            .build();
+
+        let mut player1 = create_test_player("p1", "Player 1", vec![attacker]);
+        player1.add_condition(PokemonCondition::Charging);
+        player1.last_move = Some(Move::SolarBeam);
+        let player2 = create_test_player("p2", "Player 2", vec![defender]);
+        let mut battle_state =
+            crate::battle::state::BattleState::new("test".to_string(), player1, player2);
+        let defender_max_hp = battle_state.players[1].active_pokemon().unwrap().max_hp();
+
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        // Act: low rolls make the paralysis check fail.
+        let bus = resolve_turn(&mut battle_state, TurnRng::new_for_test(vec![10; 20]));
+
+        // Assert: the charge is gone and Solar Beam never fired.
+        bus.print_debug_with_message(
+            "Events for test_full_paralysis_on_release_turn_loses_charge:",
+        );
+        assert!(bus.events().iter().any(|e| matches!(
+            e,
+            BattleEvent::ActionFailed {
+                reason: ActionFailureReason::IsParalyzed { .. }
+            }
+        )));
+        assert!(!battle_state.players[0].has_condition_type(PokemonConditionType::Charging));
+        assert_eq!(battle_state.players[0].forced_move(), None);
+        assert_eq!(
+            battle_state.players[1]
+                .active_pokemon()
+                .unwrap()
+                .current_hp(),
+            defender_max_hp
+        );
+    }
+
+    #[test]
+    fn test_trapped_pokemon_loses_turn_until_trap_ends() {
+        // Arrange: Pikachu is caught in a trap that holds for one more turn.
+        let attacker = TestPokemonBuilder::new(Species::Pikachu, 25)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let defender = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![Move::Splash])
+            .build();
+
+        let mut player1 = create_test_player("p1", "Player 1", vec![attacker]);
+        player1.add_condition(PokemonCondition::Trapped { turns_remaining: 1 });
+        let player2 = create_test_player("p2", "Player 2", vec![defender]);
+        let mut battle_state =
+            crate::battle::state::BattleState::new("test".to_string(), player1, player2);
+
+        let tackle_used = |bus: &EventBus| {
+            bus.events().iter().any(|e| {
+                matches!(
+                    e,
+                    BattleEvent::MoveUsed {
+                        player_index: 0,
+                        move_used: Move::Tackle,
+                        ..
+                    }
+                )
+            })
+        };
+
+        // Act: Turn 1, while the trap holds.
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        let bus1 = resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert: The queued Tackle never executes.
+        bus1.print_debug_with_message("Events for trapped turn:");
+        assert!(bus1.events().iter().any(|e| matches!(
+            e,
+            BattleEvent::ActionFailed {
+                reason: ActionFailureReason::IsTrapped { .. }
+            }
+        )));
+        assert!(!tackle_used(&bus1));
+
+        // Act: Turn 2, once the trap has run out.
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        let bus2 = resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert: Pikachu acts normally again.
+        bus2.print_debug_with_message("Events for turn after the trap:");
+        assert!(tackle_used(&bus2));
+    }
+
     #[test]
     fn test_multiple_conditions_priority() {
         // Arrange: Sleep condition should take priority over Flinched condition.
@@ -384,8 +488,8 @@ mod tests {
         // Act
         execute_battle_action(
             BattleAction::AttackHit {
-                attacker_index: 0,
-                defender_index: 1,
+                attacker: PlayerTarget::from_index(0),
+                defender: PlayerTarget::from_index(1),
                 move_used: Move::Tackle,
                 hit_number: 0,
             },
@@ -436,8 +540,8 @@ mod tests {
         // Act
         execute_battle_action(
             BattleAction::AttackHit {
-                attacker_index: 0,
-                defender_index: 1,
+                attacker: PlayerTarget::from_index(0),
+                defender: PlayerTarget::from_index(1),
                 move_used: move_to_use,
                 hit_number: 0,
             },
@@ -483,8 +587,8 @@ mod tests {
         // Act
         execute_battle_action(
             BattleAction::AttackHit {
-                attacker_index: 0,
-                defender_index: 1,
+                attacker: PlayerTarget::from_index(0),
+                defender: PlayerTarget::from_index(1),
                 move_used: Move::Tackle,
                 hit_number: 0,
             },
@@ -503,4 +607,87 @@ mod tests {
             .iter()
             .any(|e| matches!(e, BattleEvent::ActionFailed { .. })));
     }
+
+    #[rstest]
+    #[case(
+        "Gen 1 freeze",
+        GenQuirks::Authentic,
+        Move::Tackle,
+        Some(PredictedFailure::IsFrozen)
+    )]
+    #[case(
+        "freeze with a chance to thaw",
+        GenQuirks::QualityOfLife,
+        Move::Tackle,
+        None
+    )]
+    #[case(
+        "move that thaws its user",
+        GenQuirks::Authentic,
+        Move::BlazeKick,
+        None
+    )]
+    fn test_move_would_fail_while_frozen(
+        #[case] desc: &str,
+        #[case] gen_quirks: GenQuirks,
+        #[case] move_used: Move,
+        #[case] expected: Option<PredictedFailure>,
+    ) {
+        // Arrange
+        let attacker = TestPokemonBuilder::new(Species::Charmander, 25)
+            .with_moves(vec![move_used])
+            .with_status(StatusCondition::Freeze)
+            .build();
+        let defender = TestPokemonBuilder::new(Species::Pikachu, 25).build();
+        let mut battle_state = crate::battle::state::BattleState::new(
+            "test".to_string(),
+            create_test_player("p1", "Player 1", vec![attacker]),
+            create_test_player("p2", "Player 2", vec![defender]),
+        );
+        battle_state.rules.gen_quirks = gen_quirks;
+
+        // Act
+        let prediction = battle_state.move_would_fail(0, 0);
+
+        // Assert
+        assert_eq!(prediction, expected, "Prediction mismatch for {}", desc);
+    }
+
+    #[rstest]
+    #[case("usable move", 0, None)]
+    #[case("disabled move", 1, Some(PredictedFailure::Disabled { move_used: Move::Ember }))]
+    #[case("move without PP", 2, Some(PredictedFailure::NoPPRemaining { move_used: Move::Scratch }))]
+    #[case("Dream Eater on an awake target", 3, Some(PredictedFailure::TargetNotAsleep { move_used: Move::DreamEater }))]
+    fn test_move_would_fail_predictions(
+        #[case] desc: &str,
+        #[case] move_index: usize,
+        #[case] expected: Option<PredictedFailure>,
+    ) {
+        // Arrange
+        let mut attacker = TestPokemonBuilder::new(Species::Charmander, 25)
+            .with_moves(vec![
+                Move::Tackle,
+                Move::Ember,
+                Move::Scratch,
+                Move::DreamEater,
+            ])
+            .build();
+        attacker.moves[2].as_mut().unwrap().pp = 0;
+        let defender = TestPokemonBuilder::new(Species::Pikachu, 25).build();
+
+        let mut player1 = create_test_player("p1", "Player 1", vec![attacker]);
+        player1.add_condition(PokemonCondition::Disabled {
+            pokemon_move: Move::Ember,
+            turns_remaining: 2,
+        });
+        let player2 = create_test_player("p2", "Player 2", vec![defender]);
+        let battle_state =
+            crate::battle::state::BattleState::new("test".to_string(), player1, player2);
+
+        // Act
+        let prediction = battle_state.move_would_fail(0, move_index);
+
+        // Assert
+        assert_eq!(prediction, expected, "Prediction mismatch for {}", desc);
+    }
 }
@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod tests {
+    use crate::battle::ai::{Behavior, ScoringAI};
+    use crate::battle::engine::resolve_turn;
+    use crate::battle::state::BattleState;
+    use crate::battle::tests::common::{create_test_player, predictable_rng, TestPokemonBuilder};
+    use crate::player::PlayerAction;
+    use crate::species::Species;
+    use pretty_assertions::assert_eq;
+    use schema::Move;
+
+    /// Player 1 leads with Squirtle and has Sandshrew in reserve; Player 2's Pikachu
+    /// knows an Electric move but hasn't used it yet.
+    fn squirtle_vs_pikachu() -> BattleState {
+        let squirtle = TestPokemonBuilder::new(Species::Squirtle, 50)
+            .with_moves(vec![Move::WaterGun, Move::TailWhip])
+            .build();
+        let sandshrew = TestPokemonBuilder::new(Species::Sandshrew, 50)
+            .with_moves(vec![Move::Scratch])
+            .build();
+        let pikachu = TestPokemonBuilder::new(Species::Pikachu, 30)
+            .with_moves(vec![Move::ThunderPunch])
+            .build();
+        let player1 = create_test_player("p1", "Player 1", vec![squirtle, sandshrew]);
+        let player2 = create_test_player("p2", "Player 2", vec![pikachu]);
+        BattleState::new("test".to_string(), player1, player2)
+    }
+
+    #[test]
+    fn test_ai_stays_in_before_anything_is_revealed() {
+        let battle_state = squirtle_vs_pikachu();
+
+        assert!(battle_state.revealed_moves[1]
+            .moves_of(Species::Pikachu)
+            .is_empty());
+        assert_eq!(
+            ScoringAI::new().decide_action(0, &battle_state),
+            PlayerAction::UseMove { move_index: 0 }
+        );
+    }
+
+    #[test]
+    fn test_ai_switches_water_type_out_after_electric_move_is_revealed() {
+        let mut battle_state = squirtle_vs_pikachu();
+
+        // Act: Pikachu reveals Thunder Punch while Squirtle uses Tail Whip.
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 1 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        let bus = resolve_turn(&mut battle_state, predictable_rng());
+        bus.print_debug_with_message("Events for Pikachu revealing Thunder Punch:");
+
+        // Assert: The move is remembered, and the AI brings in the Ground type.
+        assert_eq!(
+            battle_state.revealed_moves[1].moves_of(Species::Pikachu),
+            &[Move::ThunderPunch]
+        );
+        assert!(battle_state.revealed_moves[0]
+            .moves_of(Species::Pikachu)
+            .is_empty());
+        assert_eq!(
+            ScoringAI::new().decide_action(0, &battle_state),
+            PlayerAction::SwitchPokemon { team_index: 1 }
+        );
+    }
+}
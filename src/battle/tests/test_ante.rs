@@ -36,14 +36,14 @@ mod tests {
 
         let expected_ante = attacker_level as u32 * 2;
         assert_eq!(
-            battle_state.players[1].get_ante(),
+            battle_state.players[0].get_ante(),
             expected_ante,
-            "Player 2's ante should be 2x the attacker's level"
+            "The Pay Day user's ante should be 2x its level"
         );
         assert_eq!(
-            battle_state.players[0].get_ante(),
+            battle_state.players[1].get_ante(),
             0,
-            "Player 1's ante should remain unchanged"
+            "The defender's ante should remain unchanged"
         );
 
         // Find and verify the specific event
@@ -61,7 +61,7 @@ mod tests {
             "Should have emitted an AnteIncreased event"
         );
         let (player_index, amount, new_total) = ante_event.unwrap();
-        assert_eq!(player_index, 1);
+        assert_eq!(player_index, 0);
         assert_eq!(amount, expected_ante);
         assert_eq!(new_total, expected_ante);
     }
@@ -93,7 +93,7 @@ mod tests {
             .print_debug_with_message("Events for test_pay_day_activates_at_100_percent_chance:");
         let expected_ante = 20u32 * 2;
         assert_eq!(
-            battle_state.players[1].get_ante(),
+            battle_state.players[0].get_ante(),
             expected_ante,
             "Pay Day with 100% chance should activate even with an RNG roll of 100"
         );
@@ -119,7 +119,7 @@ mod tests {
 
         // Assert - Turn 1
         bus1.print_debug_with_message("Events for test_ante_accumulation (Turn 1):");
-        assert_eq!(battle_state.players[1].get_ante(), expected_per_use);
+        assert_eq!(battle_state.players[0].get_ante(), expected_per_use);
 
         // Act - Turn 2
         battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
@@ -129,9 +129,38 @@ mod tests {
         // Assert - Turn 2
         bus2.print_debug_with_message("Events for test_ante_accumulation (Turn 2):");
         assert_eq!(
-            battle_state.players[1].get_ante(),
+            battle_state.players[0].get_ante(),
             expected_per_use * 2,
             "Ante should accumulate across multiple turns"
         );
     }
+
+    #[test]
+    fn test_pay_day_miss_adds_no_ante() {
+        // Arrange
+        let attacker = TestPokemonBuilder::new(Species::Alakazam, 20)
+            .with_moves(vec![Move::PayDay])
+            .build();
+        let defender = TestPokemonBuilder::new(Species::Machamp, 30)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let mut battle_state = create_test_battle(attacker, defender);
+
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        // Pay Day has 90 accuracy, so a roll of 95 misses.
+        let test_rng = TurnRng::new_for_test(vec![95; 20]);
+
+        // Act
+        let event_bus = resolve_turn(&mut battle_state, test_rng);
+
+        // Assert
+        event_bus.print_debug_with_message("Events for test_pay_day_miss_adds_no_ante:");
+        assert_eq!(battle_state.players[0].get_ante(), 0);
+        assert!(!event_bus
+            .events()
+            .iter()
+            .any(|e| matches!(e, BattleEvent::AnteIncreased { .. })));
+    }
 }
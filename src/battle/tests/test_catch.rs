@@ -91,7 +91,7 @@ fn test_catch_validation(
 
 #[rstest]
 #[case(BattleType::Wild, 1, vec![1], true, "Low roll should succeed")]
-#[case(BattleType::Wild, 1, vec![255], false, "High roll should fail")]
+#[case(BattleType::Wild, 1, vec![255, 100], false, "High roll should fail")]
 #[case(BattleType::Trainer, 1, vec![1], false, "Trainer battle should fail immediately")]
 #[case(BattleType::Wild, 6, vec![1], false, "Full team should fail immediately")]
 fn test_catch_commands(
@@ -112,8 +112,8 @@ fn test_catch_commands(
 
         assert_eq!(
             commands.len(),
-            2,
-            "{}: Should have CatchAttempted + AttemptCatch",
+            3,
+            "{}: Should have CatchAttempted + BallShook + AttemptCatch",
             description
         );
 
@@ -127,10 +127,19 @@ fn test_catch_commands(
             description
         );
 
-        // Second should be AttemptCatch command
+        // The ball shakes three times before the catch lands
+        assert!(
+            matches!(
+                &commands[1],
+                BattleCommand::EmitEvent(BattleEvent::BallShook { count: 3, .. })
+            ),
+            "{}: Second command should be a three-shake BallShook",
+            description
+        );
+
         assert!(
-            matches!(&commands[1], BattleCommand::AttemptCatch { .. }),
-            "{}: Second command should be AttemptCatch",
+            matches!(&commands[2], BattleCommand::AttemptCatch { .. }),
+            "{}: Third command should be AttemptCatch",
             description
         );
     } else if battle_type == BattleType::Wild && team_size < 6 {
@@ -176,7 +185,7 @@ fn test_catch_commands(
 
 #[rstest]
 #[case(vec![1], true, "Low roll should result in successful catch")]
-#[case(vec![255], false, "High roll should result in failed catch")]
+#[case(vec![255, 100], false, "High roll should result in failed catch")]
 fn test_catch_action_execution(
     #[case] rng_values: Vec<u8>,
     #[case] should_succeed: bool,
@@ -291,7 +300,7 @@ fn test_catch_rates_with_modifiers(
         }
     }
 
-    let mut rng = TurnRng::new_for_test(vec![100]);
+    let mut rng = TurnRng::new_for_test(vec![100, 100]);
     let commands = calculate_catch_commands(0, species, &battle_state, &mut rng);
 
     let catch_rate =
@@ -326,7 +335,7 @@ fn test_catch_rates_with_low_hp() {
         pokemon.take_damage(damage);
     }
 
-    let mut rng = TurnRng::new_for_test(vec![100]);
+    let mut rng = TurnRng::new_for_test(vec![100, 100]);
     let commands = calculate_catch_commands(0, Species::Caterpie, &battle_state, &mut rng);
 
     let catch_rate =
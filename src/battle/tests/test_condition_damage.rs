@@ -1,8 +1,8 @@
 #[cfg(test)]
 mod tests {
-    use crate::battle::conditions::PokemonCondition;
+    use crate::battle::conditions::{PokemonCondition, PokemonConditionType};
     use crate::battle::engine::resolve_turn;
-    use crate::battle::state::{BattleEvent, BattleState, TurnRng};
+    use crate::battle::state::{ActionFailureReason, BattleEvent, BattleState, TurnRng};
     use crate::battle::tests::common::{create_test_player, predictable_rng, TestPokemonBuilder};
     use crate::player::{PlayerAction, StatType, TeamCondition};
     use crate::species::Species;
@@ -121,6 +121,71 @@ mod tests {
             .any(|c| matches!(c, PokemonCondition::Trapped { turns_remaining: 1 })));
     }
 
+    #[rstest]
+    #[case("partial trap", PokemonCondition::Trapped { turns_remaining: 2 }, true)]
+    #[case("switch lock", PokemonCondition::SwitchLocked { turns_remaining: 2 }, false)]
+    fn test_switch_blocking_conditions(
+        #[case] desc: &str,
+        #[case] condition: PokemonCondition,
+        #[case] expect_chip_damage: bool,
+    ) {
+        // Arrange: Onix has a healthy Geodude to switch to.
+        let onix = TestPokemonBuilder::new(Species::Onix, 10)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let geodude = TestPokemonBuilder::new(Species::Geodude, 10)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let p2_pokemon = TestPokemonBuilder::new(Species::Pikachu, 10)
+            .with_moves(vec![Move::Splash])
+            .build();
+
+        let mut player1 = create_test_player("p1", "Player 1", vec![onix, geodude]);
+        player1.add_condition(condition);
+        let player2 = create_test_player("p2", "Player 2", vec![p2_pokemon]);
+        let initial_p1_hp = player1.active_pokemon().unwrap().current_hp();
+        let mut battle_state = BattleState::new("test".to_string(), player1, player2);
+
+        // Assert: Both conditions block switching up front.
+        let player = &battle_state.players[0];
+        assert!(player.is_switch_blocked(), "{}", desc);
+        assert!(player.get_valid_switches().is_empty(), "{}", desc);
+        assert!(player
+            .validate_action(&PlayerAction::SwitchPokemon { team_index: 1 })
+            .is_err());
+
+        // Act: Try to switch anyway.
+        battle_state.action_queue[0] = Some(PlayerAction::SwitchPokemon { team_index: 1 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        let event_bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert: The switch fails; only the partial trap deals chip damage.
+        event_bus.print_debug_with_message(&format!(
+            "Events for test_switch_blocking_conditions [{}]:",
+            desc
+        ));
+        assert!(event_bus.events().iter().any(|e| matches!(
+            e,
+            BattleEvent::ActionFailed {
+                reason: ActionFailureReason::IsTrapped {
+                    pokemon: Species::Onix
+                }
+            }
+        )));
+        assert_eq!(battle_state.players[0].active_pokemon_index, 0);
+
+        let took_chip_damage = event_bus
+            .events()
+            .iter()
+            .any(|e| matches!(e, BattleEvent::StatusDamage { .. }));
+        let final_p1_hp = battle_state.players[0]
+            .active_pokemon()
+            .unwrap()
+            .current_hp();
+        assert_eq!(took_chip_damage, expect_chip_damage, "{}", desc);
+        assert_eq!(final_p1_hp < initial_p1_hp, expect_chip_damage, "{}", desc);
+    }
+
     #[test]
     fn test_both_seeded_and_trapped_damage() {
         // Arrange
@@ -462,4 +527,179 @@ mod tests {
             description, damage_taken
         );
     }
+
+    #[test]
+    fn test_confusion_self_hit_uses_gen1_formula() {
+        // Arrange: A confused Gengar; its opponent's Reflect and Defense boosts must not matter.
+        let gengar = TestPokemonBuilder::new(Species::Gengar, 50)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let attack = gengar.stats.attack as f64;
+        let defense = gengar.stats.defense as f64;
+        let initial_hp = gengar.current_hp();
+
+        let mut player1 = create_test_player("p1", "Player 1", vec![gengar]);
+        player1.add_condition(PokemonCondition::Confused { turns_remaining: 2 });
+        let mut player2 = create_test_player(
+            "p2",
+            "Player 2",
+            vec![TestPokemonBuilder::new(Species::Snorlax, 50)
+                .with_moves(vec![Move::Splash])
+                .build()],
+        );
+        player2.add_team_condition(TeamCondition::Reflect, 5);
+        player2.set_stat_stage(StatType::Def, 6);
+        let mut battle_state = BattleState::new("test".to_string(), player1, player2);
+
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        // Act: Roll 1 hits itself in confusion and would also be a critical hit if one were rolled.
+        let event_bus = resolve_turn(&mut battle_state, TurnRng::new_for_test(vec![1; 10]));
+
+        // Assert: 40 power, Gengar's own Attack vs its own Defense, no STAB, crit or typing.
+        event_bus.print_debug_with_message("Events for test_confusion_self_hit_uses_gen1_formula:");
+        let random_multiplier = 218.0 / 255.0;
+        let base_damage = (22.0 * 40.0 * attack / defense) / 50.0 + 2.0;
+        let expected_damage = (base_damage * random_multiplier).ceil() as u16;
+
+        assert!(!event_bus.events().iter().any(|e| matches!(
+            e,
+            BattleEvent::CriticalHit {
+                move_used: Move::HittingItself,
+                ..
+            }
+        )));
+        assert!(!event_bus
+            .events()
+            .iter()
+            .any(|e| matches!(e, BattleEvent::AttackTypeEffectiveness { .. })));
+        let final_hp = battle_state.players[0]
+            .active_pokemon()
+            .unwrap()
+            .current_hp();
+        assert_eq!(initial_hp - final_hp, expected_damage);
+    }
+
+    /// Pikachu wraps a Splashing Snorlax on turn 1 with the given duration roll.
+    /// Rolls: hit, trap check, trap duration, no crit, damage.
+    fn wrap_battle(duration_roll: u8) -> (BattleState, crate::battle::state::EventBus) {
+        let pikachu = TestPokemonBuilder::new(Species::Pikachu, 25)
+            .with_moves(vec![Move::Wrap, Move::Splash])
+            .build();
+        let snorlax = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let player1 = create_test_player("p1", "Player 1", vec![pikachu]);
+        let player2 = create_test_player("p2", "Player 2", vec![snorlax]);
+        let mut battle_state = BattleState::new("test".to_string(), player1, player2);
+
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        let mut rolls = vec![1, 1, duration_roll, 99, 50];
+        rolls.extend([50; 20]);
+        let bus = resolve_turn(&mut battle_state, TurnRng::new_for_test(rolls));
+        (battle_state, bus)
+    }
+
+    fn trap_chip_damage(bus: &crate::battle::state::EventBus) -> u16 {
+        bus.events()
+            .iter()
+            .filter_map(|e| match e {
+                BattleEvent::StatusDamage {
+                    status: PokemonCondition::Trapped { .. },
+                    damage,
+                    ..
+                } => Some(*damage),
+                _ => None,
+            })
+            .sum()
+    }
+
+    #[rstest]
+    #[case(1, 2)]
+    #[case(37, 2)]
+    #[case(38, 3)]
+    #[case(75, 3)]
+    #[case(76, 4)]
+    #[case(88, 4)]
+    #[case(89, 5)]
+    #[case(100, 5)]
+    fn test_partial_trap_duration_distribution(#[case] roll: u8, #[case] expected_turns: u8) {
+        let (battle_state, bus) = wrap_battle(roll);
+        bus.print_debug_with_message(&format!("Events for Wrap with duration roll {}:", roll));
+
+        // One end-of-turn tick has already happened on the turn Wrap landed.
+        let expected = PokemonCondition::Trapped {
+            turns_remaining: expected_turns - 2,
+        };
+        assert!(battle_state.players[1]
+            .active_pokemon_conditions
+            .values()
+            .any(|c| *c == expected));
+    }
+
+    #[rstest]
+    #[case(1, 2)]
+    #[case(50, 3)]
+    #[case(80, 4)]
+    #[case(95, 5)]
+    fn test_partial_trap_chip_damage_over_the_lock(#[case] roll: u8, #[case] lock_turns: u16) {
+        let (mut battle_state, first_bus) = wrap_battle(roll);
+        let max_hp = battle_state.players[1].active_pokemon().unwrap().max_hp();
+        let mut total_chip = trap_chip_damage(&first_bus);
+
+        for _ in 0..10 {
+            if !battle_state.players[1].has_condition_type(PokemonConditionType::Trapped) {
+                break;
+            }
+            battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 1 });
+            battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+            let bus = resolve_turn(&mut battle_state, predictable_rng());
+            total_chip += trap_chip_damage(&bus);
+        }
+
+        assert!(!battle_state.players[1].has_condition_type(PokemonConditionType::Trapped));
+        assert_eq!(total_chip, lock_turns * (max_hp / 16).max(1));
+    }
+
+    #[test]
+    fn test_partial_trap_locks_the_user_into_repeating_the_move() {
+        // Roll 50 gives a three-turn trap, one turn of which has already passed.
+        let (mut battle_state, _) = wrap_battle(50);
+        assert!(battle_state.players[0].has_condition_type(PokemonConditionType::Trapping));
+
+        let mut forced_wraps = 0;
+        for _ in 0..10 {
+            if !battle_state.players[0].has_condition_type(PokemonConditionType::Trapping) {
+                break;
+            }
+            // The wrapper's next move is already chosen for it, and it can't switch.
+            assert_eq!(
+                battle_state.action_queue[0],
+                Some(PlayerAction::UseMove { move_index: 0 })
+            );
+            assert!(battle_state.players[0]
+                .validate_action(&PlayerAction::SwitchPokemon { team_index: 0 })
+                .is_err());
+
+            battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+            let bus = resolve_turn(&mut battle_state, predictable_rng());
+            assert!(bus.events().iter().any(|e| matches!(
+                e,
+                BattleEvent::MoveUsed {
+                    player_index: 0,
+                    move_used: Move::Wrap,
+                    ..
+                }
+            )));
+            forced_wraps += 1;
+        }
+
+        // Both ends of the trap run out together, and the wrapper is free again.
+        assert_eq!(forced_wraps, 2);
+        assert!(!battle_state.players[1].has_condition_type(PokemonConditionType::Trapped));
+        assert!(!battle_state.players[0].has_condition_type(PokemonConditionType::Trapping));
+        assert_eq!(battle_state.action_queue[0], None);
+    }
 }
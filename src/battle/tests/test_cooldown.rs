@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod tests {
+    use crate::battle::action_stack::ActionStack;
+    use crate::battle::commands::{execute_command_batch, BattleCommand, PlayerTarget};
+    use crate::battle::conditions::{PokemonCondition, PokemonConditionType};
+    use crate::battle::engine::resolve_turn;
+    use crate::battle::move_effects::{BattleMoveEffectExt, EffectContext, EffectResult};
+    use crate::battle::state::{ActionFailureReason, BattleEvent, EventBus, TurnRng};
+    use crate::battle::tests::common::{create_test_battle, predictable_rng, TestPokemonBuilder};
+    use crate::player::PlayerAction;
+    use crate::species::Species;
+    use pretty_assertions::assert_eq;
+    use schema::{Move, MoveEffect};
+
+    fn tackle_used(bus: &EventBus) -> bool {
+        bus.events().iter().any(|e| {
+            matches!(
+                e,
+                BattleEvent::MoveUsed {
+                    player_index: 0,
+                    move_used: Move::Tackle,
+                    ..
+                }
+            )
+        })
+    }
+
+    #[test]
+    fn test_cooldown_blocks_reuse_until_it_expires() {
+        // Arrange: Player 1 knows Tackle and Scratch; the opponent just Splashes.
+        let snorlax = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![Move::Tackle, Move::Scratch])
+            .build();
+        let opponent = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let mut battle_state = create_test_battle(snorlax, opponent);
+
+        // A Cooldown(1) effect on Tackle puts it on cooldown through the end-of-turn tick.
+        let context = EffectContext::new(
+            PlayerTarget::from_index(0),
+            PlayerTarget::from_index(1),
+            Move::Tackle,
+        );
+        let EffectResult::Ensured(commands) = MoveEffect::Cooldown(1).apply(
+            &context,
+            &battle_state,
+            &mut TurnRng::new_for_test(vec![]),
+        ) else {
+            panic!("Cooldown should always apply");
+        };
+        assert_eq!(
+            commands,
+            vec![BattleCommand::AddCondition {
+                target: PlayerTarget::from_index(0),
+                condition: PokemonCondition::Cooldown {
+                    pokemon_move: Move::Tackle,
+                    turns_remaining: 2,
+                },
+            }]
+        );
+        let mut bus = EventBus::new();
+        execute_command_batch(
+            commands,
+            &mut battle_state,
+            &mut bus,
+            &mut ActionStack::new(),
+        )
+        .unwrap();
+
+        // Turn 1 ends, as it would after using the move.
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 1 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        resolve_turn(&mut battle_state, predictable_rng());
+
+        // Turn 2: Tackle can't be picked or used, but Scratch still can.
+        let player = &battle_state.players[0];
+        assert!(player.is_move_blocked(Move::Tackle));
+        assert!(player
+            .validate_action(&PlayerAction::UseMove { move_index: 0 })
+            .is_err());
+        assert_eq!(
+            player.get_valid_moves(),
+            vec![PlayerAction::UseMove { move_index: 1 }]
+        );
+
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        let blocked_bus = resolve_turn(&mut battle_state, predictable_rng());
+        blocked_bus.print_debug_with_message("Events for Tackle on cooldown:");
+        assert!(!tackle_used(&blocked_bus));
+        assert!(blocked_bus.events().iter().any(|e| matches!(
+            e,
+            BattleEvent::ActionFailed {
+                reason: ActionFailureReason::MoveFailedToExecute {
+                    move_used: Move::Tackle
+                }
+            }
+        )));
+
+        // Turn 3: The cooldown has run out, so Tackle works again and the condition clears.
+        assert!(!battle_state.players[0].is_move_blocked(Move::Tackle));
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        let ready_bus = resolve_turn(&mut battle_state, predictable_rng());
+        ready_bus.print_debug_with_message("Events for Tackle after cooldown:");
+        assert!(tackle_used(&ready_bus));
+        assert!(!battle_state.players[0].has_condition_type(PokemonConditionType::Cooldown));
+    }
+}
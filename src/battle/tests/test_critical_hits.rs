@@ -1,10 +1,14 @@
 #[cfg(test)]
 mod tests {
+    use crate::battle::conditions::PokemonConditionType;
     use crate::battle::engine::{collect_npc_actions, resolve_turn};
     use crate::battle::state::{BattleEvent, TurnRng};
     use crate::battle::tests::common::{create_test_battle, TestPokemonBuilder};
+    use crate::player::PlayerAction;
     use crate::species::Species;
     use pretty_assertions::assert_eq;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
     use rstest::rstest;
     use schema::Move;
 
@@ -65,4 +69,91 @@ mod tests {
         assert_eq!(has_crit, expect_crit, "Critical hit expectation mismatch");
         assert_eq!(has_miss, expect_miss, "Miss expectation mismatch");
     }
+
+    #[test]
+    fn test_sky_attack_crit_boost_applies_on_release() {
+        // Arrange: Every roll is 20, which is above the base crit threshold (4)
+        // but within Sky Attack's boosted one (33).
+        let attacker = TestPokemonBuilder::new(Species::Pidgeot, 50)
+            .with_moves(vec![Move::SkyAttack])
+            .build();
+        let defender = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let mut battle_state = create_test_battle(attacker, defender);
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        // Act: Turn 1 charges.
+        let charge_bus = resolve_turn(&mut battle_state, TurnRng::new_for_test(vec![20; 100]));
+        charge_bus.print_debug_with_message("Events for Sky Attack [Turn 1 - Charge]:");
+
+        // Assert: Nothing is rolled for damage or crits while charging.
+        assert!(battle_state.players[0].has_condition_type(PokemonConditionType::Charging));
+        assert!(!charge_bus.events().iter().any(|e| matches!(
+            e,
+            BattleEvent::CriticalHit { .. } | BattleEvent::DamageDealt { .. }
+        )));
+
+        // Act: Turn 2 releases the forced Sky Attack.
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        let release_bus = resolve_turn(&mut battle_state, TurnRng::new_for_test(vec![20; 100]));
+        release_bus.print_debug_with_message("Events for Sky Attack [Turn 2 - Release]:");
+
+        // Assert: The release hit carries Sky Attack's boosted crit ratio.
+        assert!(!battle_state.players[0].has_condition_type(PokemonConditionType::Charging));
+        assert!(release_bus.events().iter().any(|e| matches!(
+            e,
+            BattleEvent::CriticalHit {
+                move_used: Move::SkyAttack,
+                ..
+            }
+        )));
+    }
+
+    #[test]
+    fn test_scripted_crit_override_leaves_other_rolls_random() {
+        for seed in 0..5 {
+            // Arrange: Swift never misses, so every turn reaches the crit roll.
+            let attacker = TestPokemonBuilder::new(Species::Pidgeot, 50)
+                .with_moves(vec![Move::Swift])
+                .build();
+            let defender = TestPokemonBuilder::new(Species::Snorlax, 50)
+                .with_moves(vec![Move::Splash])
+                .build();
+            let mut scripted_state = create_test_battle(attacker, defender);
+            scripted_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+            scripted_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+            let mut unscripted_state = scripted_state.clone();
+
+            // Act: the same seeded turn, with and without the crit forced.
+            let scripted_rng = TurnRng::from_rng(&mut StdRng::seed_from_u64(seed))
+                .with_override("Critical Hit Check", 1)
+                .with_trace();
+            let scripted_bus = resolve_turn(&mut scripted_state, scripted_rng);
+            let unscripted_rng = TurnRng::from_rng(&mut StdRng::seed_from_u64(seed)).with_trace();
+            let unscripted_bus = resolve_turn(&mut unscripted_state, unscripted_rng);
+
+            // Assert: the crit is guaranteed...
+            assert!(scripted_bus.events().iter().any(|e| matches!(
+                e,
+                BattleEvent::CriticalHit {
+                    move_used: Move::Swift,
+                    ..
+                }
+            )));
+            let non_crit_rolls = |trace: &[(String, u8)]| -> Vec<(String, u8)> {
+                trace
+                    .iter()
+                    .filter(|(reason, _)| reason != "Critical Hit Check")
+                    .cloned()
+                    .collect()
+            };
+            // ...while every other roll is exactly what the seed would have produced.
+            assert_eq!(
+                non_crit_rolls(scripted_bus.rng_trace().unwrap()),
+                non_crit_rolls(unscripted_bus.rng_trace().unwrap())
+            );
+        }
+    }
 }
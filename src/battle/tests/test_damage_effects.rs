@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests {
     use crate::battle::action_stack::ActionStack;
+    use crate::battle::commands::PlayerTarget;
     use crate::battle::engine::execute_attack_hit;
     use crate::battle::state::{BattleEvent, EventBus, TurnRng};
     use crate::battle::tests::common::{create_test_battle, TestPokemonBuilder};
@@ -24,8 +25,8 @@ mod tests {
 
         // Act
         execute_attack_hit(
-            0,
-            1,
+            PlayerTarget::from_index(0),
+            PlayerTarget::from_index(1),
             Move::Slash,
             0,
             &mut action_stack,
@@ -66,8 +67,8 @@ mod tests {
 
         // Act
         execute_attack_hit(
-            0,
-            1,
+            PlayerTarget::from_index(0),
+            PlayerTarget::from_index(1),
             Move::DoubleEdge,
             0,
             &mut action_stack,
@@ -123,8 +124,8 @@ mod tests {
 
         // Act
         execute_attack_hit(
-            0,
-            1,
+            PlayerTarget::from_index(0),
+            PlayerTarget::from_index(1),
             Move::MegaDrain,
             0,
             &mut action_stack,
@@ -162,6 +163,96 @@ mod tests {
             healing_to_attacker,
             "Should have applied healing to the attacker"
         );
+        assert!(bus.events().iter().any(|e| matches!(
+            e,
+            BattleEvent::DrainHeal {
+                target: Species::Victreebel
+            }
+        )));
+    }
+
+    #[test]
+    fn test_double_edge_emits_recoil_event() {
+        // Arrange
+        let attacker = TestPokemonBuilder::new(Species::Machamp, 10)
+            .with_moves(vec![Move::DoubleEdge])
+            .build();
+        let defender = TestPokemonBuilder::new(Species::Pidgey, 10).build();
+        let mut battle_state = create_test_battle(attacker, defender);
+
+        let mut bus = EventBus::new();
+        let mut rng = TurnRng::new_for_test(vec![50, 60, 90, 80]); // Rolls to ensure a hit
+        let mut action_stack = ActionStack::new();
+
+        // Act
+        execute_attack_hit(
+            PlayerTarget::from_index(0),
+            PlayerTarget::from_index(1),
+            Move::DoubleEdge,
+            0,
+            &mut action_stack,
+            &mut bus,
+            &mut rng,
+            &mut battle_state,
+        );
+
+        // Assert
+        bus.print_debug_with_message("Events for test_double_edge_emits_recoil_event:");
+        let recoil_event = bus
+            .events()
+            .iter()
+            .find(|e| matches!(e, BattleEvent::RecoilDamage { .. }))
+            .expect("Double-Edge should narrate its recoil");
+        assert_eq!(
+            recoil_event,
+            &BattleEvent::RecoilDamage {
+                target: Species::Machamp
+            }
+        );
+        assert_eq!(
+            recoil_event.format(&battle_state),
+            Some("Machamp is hit by recoil!".to_string())
+        );
+    }
+
+    #[test]
+    fn test_missed_high_jump_kick_emits_crash_event() {
+        // Arrange
+        let attacker = TestPokemonBuilder::new(Species::Hitmonlee, 50)
+            .with_moves(vec![Move::HighJumpKick])
+            .build();
+        let defender = TestPokemonBuilder::new(Species::Snorlax, 50).build();
+        let mut battle_state = create_test_battle(attacker, defender);
+
+        let mut bus = EventBus::new();
+        let mut rng = TurnRng::new_for_test(vec![95]); // HJK accuracy is 80, so 95 is a miss
+        let mut action_stack = ActionStack::new();
+
+        // Act
+        execute_attack_hit(
+            PlayerTarget::from_index(0),
+            PlayerTarget::from_index(1),
+            Move::HighJumpKick,
+            0,
+            &mut action_stack,
+            &mut bus,
+            &mut rng,
+            &mut battle_state,
+        );
+
+        // Assert
+        bus.print_debug_with_message("Events for test_missed_high_jump_kick_emits_crash_event:");
+        assert!(bus.events().iter().any(|e| matches!(
+            e,
+            BattleEvent::CrashDamage {
+                target: Species::Hitmonlee,
+                move_used: Move::HighJumpKick,
+            }
+        )));
+        assert!(!bus
+            .events()
+            .iter()
+            .any(|e| matches!(e, BattleEvent::RecoilDamage { .. })));
     }
 
     #[test]
@@ -183,8 +274,8 @@ mod tests {
 
         // Act
         execute_attack_hit(
-            0,
-            1,
+            PlayerTarget::from_index(0),
+            PlayerTarget::from_index(1),
             Move::DoubleEdge,
             0,
             &mut action_stack,
@@ -0,0 +1,219 @@
+#[cfg(test)]
+mod tests {
+    use crate::battle::commands::PlayerTarget;
+    use crate::battle::double::queue_slot_action;
+    use crate::battle::engine::resolve_turn;
+    use crate::battle::state::{BattleEvent, BattleFormat, BattleState, GameState, SlotAction};
+    use crate::battle::tests::common::{
+        create_test_battle, create_test_player, predictable_rng, TestPokemonBuilder,
+    };
+    use crate::player::PlayerAction;
+    use crate::pokemon::PokemonInst;
+    use crate::species::Species;
+    use pretty_assertions::assert_eq;
+    use schema::Move;
+
+    fn rattata(hp: Option<u16>) -> PokemonInst {
+        let builder = TestPokemonBuilder::new(Species::Rattata, 50).with_moves(vec![Move::Splash]);
+        match hp {
+            Some(hp) => builder.with_hp(hp).build(),
+            None => builder.build(),
+        }
+    }
+
+    fn double_battle(p1_team: Vec<PokemonInst>, p2_team: Vec<PokemonInst>) -> BattleState {
+        let player1 = create_test_player("p1", "Player 1", p1_team);
+        let player2 = create_test_player("p2", "Player 2", p2_team);
+        BattleState::new_double("test".to_string(), player1, player2)
+    }
+
+    fn queue(
+        battle_state: &mut BattleState,
+        side: usize,
+        slot: usize,
+        target: Option<PlayerTarget>,
+    ) {
+        let choice = SlotAction {
+            action: PlayerAction::UseMove { move_index: 0 },
+            target,
+        };
+        queue_slot_action(battle_state, PlayerTarget::new(side, slot), choice)
+            .expect("the move should be a legal choice");
+    }
+
+    fn hp_lost(battle_state: &BattleState, side: usize, slot: usize) -> u16 {
+        let pokemon = battle_state
+            .battler(PlayerTarget::new(side, slot))
+            .active_pokemon()
+            .unwrap();
+        pokemon.max_hp() - pokemon.current_hp()
+    }
+
+    /// How much one Tackle or Swift from a level 50 Pidgey takes off a level 50 Rattata
+    /// in a single battle.
+    fn single_battle_damage(move_used: Move) -> u16 {
+        let pidgey = TestPokemonBuilder::new(Species::Pidgey, 50)
+            .with_moves(vec![move_used])
+            .build();
+        let mut battle_state = create_test_battle(pidgey, rattata(None));
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        resolve_turn(&mut battle_state, predictable_rng());
+        hp_lost(&battle_state, 1, 0)
+    }
+
+    #[test]
+    fn test_double_battle_puts_two_pokemon_from_each_side_on_the_field() {
+        let pidgey = TestPokemonBuilder::new(Species::Pidgey, 50).build();
+        let slowpoke = TestPokemonBuilder::new(Species::Slowpoke, 50).build();
+        let battle_state =
+            double_battle(vec![pidgey, slowpoke], vec![rattata(None), rattata(None)]);
+
+        assert_eq!(battle_state.format, BattleFormat::Double);
+        assert_eq!(
+            battle_state
+                .battler(PlayerTarget::new(0, 1))
+                .active_pokemon()
+                .map(|p| p.species),
+            Some(Species::Slowpoke)
+        );
+        assert_eq!(battle_state.emit_start_events().len(), 4);
+    }
+
+    #[test]
+    fn test_spread_move_hits_both_foes_at_reduced_power() {
+        let pidgey = TestPokemonBuilder::new(Species::Pidgey, 50)
+            .with_moves(vec![Move::Swift])
+            .build();
+        let slowpoke = TestPokemonBuilder::new(Species::Slowpoke, 50)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let mut battle_state =
+            double_battle(vec![pidgey, slowpoke], vec![rattata(None), rattata(None)]);
+        for (side, slot) in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+            queue(&mut battle_state, side, slot, None);
+        }
+
+        let bus = resolve_turn(&mut battle_state, predictable_rng());
+        bus.print_debug_with_message("Events for Swift hitting both foes:");
+
+        let full_damage = single_battle_damage(Move::Swift);
+        let spread_damage = full_damage * 3 / 4;
+        assert!(spread_damage > 0 && spread_damage < full_damage);
+        assert_eq!(hp_lost(&battle_state, 1, 0), spread_damage);
+        assert_eq!(hp_lost(&battle_state, 1, 1), spread_damage);
+        assert_eq!(hp_lost(&battle_state, 0, 1), 0);
+    }
+
+    #[test]
+    fn test_single_target_move_hits_only_the_chosen_foe() {
+        let pidgey = TestPokemonBuilder::new(Species::Pidgey, 50)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let slowpoke = TestPokemonBuilder::new(Species::Slowpoke, 50)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let mut battle_state =
+            double_battle(vec![pidgey, slowpoke], vec![rattata(None), rattata(None)]);
+        queue(&mut battle_state, 0, 0, Some(PlayerTarget::new(1, 1)));
+        queue(&mut battle_state, 0, 1, None);
+        queue(&mut battle_state, 1, 0, None);
+        queue(&mut battle_state, 1, 1, None);
+
+        resolve_turn(&mut battle_state, predictable_rng());
+
+        assert_eq!(hp_lost(&battle_state, 1, 0), 0);
+        assert_eq!(
+            hp_lost(&battle_state, 1, 1),
+            single_battle_damage(Move::Tackle)
+        );
+    }
+
+    #[test]
+    fn test_single_target_move_can_target_an_ally() {
+        let pidgey = TestPokemonBuilder::new(Species::Pidgey, 50)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let rattata_ally = rattata(None);
+        let mut battle_state = double_battle(
+            vec![pidgey, rattata_ally],
+            vec![rattata(None), rattata(None)],
+        );
+        queue(&mut battle_state, 0, 0, Some(PlayerTarget::new(0, 1)));
+        queue(&mut battle_state, 0, 1, None);
+        queue(&mut battle_state, 1, 0, None);
+        queue(&mut battle_state, 1, 1, None);
+
+        resolve_turn(&mut battle_state, predictable_rng());
+
+        assert_eq!(
+            hp_lost(&battle_state, 0, 1),
+            single_battle_damage(Move::Tackle)
+        );
+        assert_eq!(hp_lost(&battle_state, 1, 0), 0);
+        assert_eq!(hp_lost(&battle_state, 1, 1), 0);
+        let pidgey = battle_state
+            .battler(PlayerTarget::new(0, 0))
+            .active_pokemon()
+            .unwrap();
+        let tackle = pidgey.moves[0].as_ref().unwrap();
+        assert_eq!(tackle.pp, tackle.max_pp() - 1);
+    }
+
+    #[test]
+    fn test_move_at_a_fallen_foe_is_redirected_to_the_other() {
+        let pidgey = TestPokemonBuilder::new(Species::Pidgey, 50)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let slowpoke = TestPokemonBuilder::new(Species::Slowpoke, 50)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let mut battle_state = double_battle(
+            vec![pidgey, slowpoke],
+            vec![rattata(None), rattata(Some(1))],
+        );
+        // Pidgey knocks out the weakened Rattata before the slower Slowpoke can hit it.
+        queue(&mut battle_state, 0, 0, Some(PlayerTarget::new(1, 1)));
+        queue(&mut battle_state, 0, 1, Some(PlayerTarget::new(1, 1)));
+        queue(&mut battle_state, 1, 0, None);
+        queue(&mut battle_state, 1, 1, None);
+
+        resolve_turn(&mut battle_state, predictable_rng());
+
+        assert!(battle_state
+            .battler(PlayerTarget::new(1, 1))
+            .active_pokemon()
+            .unwrap()
+            .is_fainted());
+        assert!(hp_lost(&battle_state, 1, 0) > 0);
+        assert!(!battle_state.is_over());
+    }
+
+    #[test]
+    fn test_both_sides_forfeiting_is_a_draw() {
+        let mut battle_state = double_battle(
+            vec![rattata(None), rattata(None)],
+            vec![rattata(None), rattata(None)],
+        );
+        // Each side forfeits from a different slot; the other slots Splash.
+        for (side, slot) in [(0, 0), (1, 1)] {
+            let forfeit = SlotAction {
+                action: PlayerAction::Forfeit,
+                target: None,
+            };
+            queue_slot_action(&mut battle_state, PlayerTarget::new(side, slot), forfeit)
+                .expect("forfeiting should be a legal choice");
+        }
+        queue(&mut battle_state, 0, 1, None);
+        queue(&mut battle_state, 1, 0, None);
+
+        let event_bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        event_bus.print_debug_with_message("Events for test_both_sides_forfeiting_is_a_draw:");
+        assert_eq!(battle_state.game_state, GameState::Draw);
+        assert!(event_bus
+            .events()
+            .iter()
+            .any(|e| matches!(e, BattleEvent::BattleEnded { winner: None })));
+    }
+}
@@ -1,9 +1,10 @@
 #[cfg(test)]
 mod tests {
     use crate::battle::action_stack::{ActionStack, BattleAction};
+    use crate::battle::commands::PlayerTarget;
     use crate::battle::conditions::{PokemonCondition, PokemonConditionType};
     use crate::battle::engine::{execute_battle_action, resolve_turn};
-    use crate::battle::state::{BattleEvent, EventBus, TurnRng};
+    use crate::battle::state::{ActionFailureReason, BattleEvent, EventBus, GenQuirks, TurnRng};
     use crate::battle::tests::common::{create_test_battle, predictable_rng, TestPokemonBuilder};
     use crate::player::PlayerAction;
     use crate::pokemon::StatusCondition;
@@ -103,6 +104,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_recharging_pokemon_still_takes_poison_damage() {
+        // Arrange: A poisoned Pokemon that must recharge can't act, but residual
+        // damage and condition timers still apply to it at the end of the turn.
+        let p1_pokemon = TestPokemonBuilder::new(Species::Charmander, 25)
+            .with_moves(vec![Move::HyperBeam])
+            .with_status(StatusCondition::Poison(0))
+            .build();
+        let p2_pokemon = TestPokemonBuilder::new(Species::Bulbasaur, 25)
+            .with_moves(vec![Move::Splash])
+            .build();
+
+        let mut player1 =
+            crate::battle::tests::common::create_test_player("p1", "Player 1", vec![p1_pokemon]);
+        player1.add_condition(PokemonCondition::Exhausted { turns_remaining: 1 });
+        let player2 =
+            crate::battle::tests::common::create_test_player("p2", "Player 2", vec![p2_pokemon]);
+        let mut battle_state =
+            crate::battle::state::BattleState::new("test".to_string(), player1, player2);
+        let max_hp = battle_state.players[0].team[0].as_ref().unwrap().max_hp();
+
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        // Act
+        let event_bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert
+        event_bus.print_debug_with_message(
+            "Events for test_recharging_pokemon_still_takes_poison_damage:",
+        );
+        assert!(event_bus.events().iter().any(|e| matches!(
+            e,
+            BattleEvent::ActionFailed {
+                reason: ActionFailureReason::IsExhausted { .. }
+            }
+        )));
+        assert!(!event_bus.events().iter().any(|e| matches!(
+            e,
+            BattleEvent::MoveUsed {
+                player_index: 0,
+                ..
+            }
+        )));
+        assert!(event_bus.events().iter().any(|e| matches!(
+            e,
+            BattleEvent::PokemonStatusDamage {
+                target: Species::Charmander,
+                status: StatusCondition::Poison(0),
+                ..
+            }
+        )));
+        let charmander = battle_state.players[0].team[0].as_ref().unwrap();
+        assert_eq!(charmander.current_hp(), max_hp - max_hp / 16);
+
+        // The recharge timer still ticks down.
+        assert!(matches!(
+            battle_state.players[0]
+                .active_pokemon_conditions
+                .get(&PokemonConditionType::Exhausted),
+            Some(PokemonCondition::Exhausted { turns_remaining: 0 })
+        ));
+    }
+
     #[test]
     fn test_active_condition_timers_decrement() {
         // Arrange
@@ -163,6 +228,57 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_condition_duration_reports_turns_remaining() {
+        // Arrange
+        let p1_pokemon = TestPokemonBuilder::new(Species::Pikachu, 25)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let p2_pokemon = TestPokemonBuilder::new(Species::Rattata, 25)
+            .with_moves(vec![Move::Splash])
+            .build();
+
+        let mut player1 =
+            crate::battle::tests::common::create_test_player("p1", "Player 1", vec![p1_pokemon]);
+        player1.add_condition(PokemonCondition::Confused { turns_remaining: 3 });
+        player1.add_condition(PokemonCondition::Seeded);
+
+        let player2 =
+            crate::battle::tests::common::create_test_player("p2", "Player 2", vec![p2_pokemon]);
+        let mut battle_state =
+            crate::battle::state::BattleState::new("test".to_string(), player1, player2);
+
+        // Freshly applied confusion reports its full duration.
+        assert_eq!(
+            battle_state.players[0].condition_duration(PokemonConditionType::Confused),
+            Some(3)
+        );
+        // Conditions without a timer, or that aren't present, report nothing.
+        assert_eq!(
+            battle_state.players[0].condition_duration(PokemonConditionType::Seeded),
+            None
+        );
+        assert_eq!(
+            battle_state.players[0].condition_duration(PokemonConditionType::Trapped),
+            None
+        );
+
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        // Act
+        let event_bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert
+        event_bus.print_debug_with_message(
+            "Events for test_condition_duration_reports_turns_remaining:",
+        );
+        assert_eq!(
+            battle_state.players[0].condition_duration(PokemonConditionType::Confused),
+            Some(2)
+        );
+    }
+
     #[test]
     fn test_status_damage_causes_fainting() {
         // Arrange: Level 50 Magikarp's HP is low enough to be KO'd by poison.
@@ -216,6 +332,8 @@ mod tests {
             .build();
         let defender = TestPokemonBuilder::new(Species::Charmander, 25).build();
         let mut battle_state = create_test_battle(attacker, defender);
+        // Chance-based thawing only exists outside authentic Gen 1 rules.
+        battle_state.rules.gen_quirks = GenQuirks::QualityOfLife;
 
         let mut bus = EventBus::new();
         let mut rng = TurnRng::new_for_test(vec![rng_val, 100, 100, 100]);
@@ -224,8 +342,8 @@ mod tests {
         // Act
         execute_battle_action(
             BattleAction::AttackHit {
-                attacker_index: 0,
-                defender_index: 1,
+                attacker: PlayerTarget::from_index(0),
+                defender: PlayerTarget::from_index(1),
                 move_used: Move::Tackle,
                 hit_number: 0,
             },
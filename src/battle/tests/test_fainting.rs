@@ -1,15 +1,40 @@
 #[cfg(test)]
 mod tests {
-    use crate::battle::engine::{collect_npc_actions, resolve_turn};
-    use crate::battle::state::{ActionFailureReason, BattleEvent, GameState};
+    use crate::battle::action_stack::{ActionStack, BattleAction};
+    use crate::battle::commands::PlayerTarget;
+    use crate::battle::engine::{collect_npc_actions, execute_battle_action, resolve_turn};
+    use crate::battle::state::{ActionFailureReason, BattleEvent, EventBus, GameState};
     use crate::battle::tests::common::{
         create_test_battle, create_test_player, predictable_rng, TestPokemonBuilder,
     };
-    use crate::player::PlayerAction;
+    use crate::player::{BattlePlayer, PlayerAction};
     use crate::pokemon::StatusCondition;
     use crate::species::Species;
     use pretty_assertions::assert_eq;
-    use schema::Move;
+    use rstest::rstest;
+    use schema::{Move, StatType};
+
+    // --- Unit Tests for Battle Outcome Accessors ---
+
+    #[rstest]
+    #[case(GameState::WaitingForActions, None)]
+    #[case(GameState::WaitingForPlayer1Replacement, None)]
+    #[case(GameState::Player1Win, Some(Some(0)))]
+    #[case(GameState::Player2Win, Some(Some(1)))]
+    #[case(GameState::Draw, Some(None))]
+    fn test_battle_outcome_accessors(
+        #[case] game_state: GameState,
+        #[case] expected_winner: Option<Option<usize>>,
+    ) {
+        let mut battle_state = create_test_battle(
+            TestPokemonBuilder::new(Species::Pikachu, 10).build(),
+            TestPokemonBuilder::new(Species::Charmander, 10).build(),
+        );
+        battle_state.game_state = game_state;
+
+        assert_eq!(battle_state.winner(), expected_winner);
+        assert_eq!(battle_state.is_over(), expected_winner.is_some());
+    }
 
     // --- Unit Tests for PokemonInst Fainting Logic ---
 
@@ -256,4 +281,384 @@ mod tests {
             "Player 1 should not have switched"
         );
     }
+
+    #[rstest]
+    #[case("Swords Dance targets the user and proceeds", Move::SwordsDance, true)]
+    #[case("Tackle targets the fainted opponent and fails", Move::Tackle, false)]
+    fn test_move_against_fainted_opponent_keyed_on_target(
+        #[case] desc: &str,
+        #[case] move_used: Move,
+        #[case] should_proceed: bool,
+    ) {
+        // Arrange: The opponent has just fainted and has not been replaced yet.
+        let attacker = TestPokemonBuilder::new(Species::Machamp, 30)
+            .with_moves(vec![move_used])
+            .build();
+        let fainted_defender = TestPokemonBuilder::new(Species::Rattata, 30)
+            .with_moves(vec![Move::Tackle])
+            .with_hp(0)
+            .build();
+        let backup = TestPokemonBuilder::new(Species::Pidgey, 30)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let player1 = create_test_player("p1", "Player 1", vec![attacker]);
+        let player2 = create_test_player("p2", "Player 2", vec![fainted_defender, backup]);
+        let mut battle_state =
+            crate::battle::state::BattleState::new("test".to_string(), player1, player2);
+
+        let mut bus = EventBus::new();
+        let mut action_stack = ActionStack::new();
+        let mut rng = predictable_rng();
+
+        // Act
+        execute_battle_action(
+            BattleAction::AttackHit {
+                attacker: PlayerTarget::from_index(0),
+                defender: PlayerTarget::from_index(1),
+                move_used,
+                hit_number: 0,
+            },
+            &mut battle_state,
+            &mut action_stack,
+            &mut bus,
+            &mut rng,
+        );
+
+        // Assert
+        bus.print_debug_with_message(&format!(
+            "Events for test_move_against_fainted_opponent_keyed_on_target [{}]:",
+            desc
+        ));
+        let no_enemy_failure = bus.events().iter().any(|e| {
+            matches!(
+                e,
+                BattleEvent::ActionFailed {
+                    reason: ActionFailureReason::NoEnemyPresent
+                }
+            )
+        });
+
+        if should_proceed {
+            assert!(!no_enemy_failure, "{}", desc);
+            assert_eq!(battle_state.players[0].get_stat_stage(StatType::Atk), 2);
+        } else {
+            assert!(no_enemy_failure, "{}", desc);
+            assert_eq!(battle_state.players[0].get_stat_stage(StatType::Atk), 0);
+        }
+    }
+
+    #[test]
+    fn test_attack_into_unreplaced_fainted_opponent_fails_cleanly() {
+        use crate::battle::conditions::PokemonCondition;
+
+        // Arrange: A confused Machamp attacks an opponent that fainted earlier this turn.
+        let attacker = TestPokemonBuilder::new(Species::Machamp, 30)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let fainted_defender = TestPokemonBuilder::new(Species::Rattata, 30)
+            .with_hp(0)
+            .build();
+        let backup = TestPokemonBuilder::new(Species::Pidgey, 30).build();
+        let mut player1 = create_test_player("p1", "Player 1", vec![attacker]);
+        player1.add_condition(PokemonCondition::Confused { turns_remaining: 2 });
+        let player2 = create_test_player("p2", "Player 2", vec![fainted_defender, backup]);
+        let mut battle_state =
+            crate::battle::state::BattleState::new("test".to_string(), player1, player2);
+        let before = battle_state.clone();
+
+        let mut bus = EventBus::new();
+        let mut action_stack = ActionStack::new();
+        // An empty RNG panics if the failed action rolls for anything (e.g. confusion).
+        let mut rng = crate::battle::state::TurnRng::new_for_test(vec![]);
+
+        // Act
+        execute_battle_action(
+            BattleAction::AttackHit {
+                attacker: PlayerTarget::from_index(0),
+                defender: PlayerTarget::from_index(1),
+                move_used: Move::Tackle,
+                hit_number: 0,
+            },
+            &mut battle_state,
+            &mut action_stack,
+            &mut bus,
+            &mut rng,
+        );
+
+        // Assert: Only the failure is reported, and nothing but the acted flag changes.
+        bus.print_debug_with_message(
+            "Events for test_attack_into_unreplaced_fainted_opponent_fails_cleanly:",
+        );
+        assert_eq!(
+            bus.events(),
+            &[BattleEvent::ActionFailed {
+                reason: ActionFailureReason::NoEnemyPresent
+            }]
+        );
+        assert!(action_stack.pop_front().is_none());
+        assert_eq!(battle_state.players[0].team, before.players[0].team);
+        assert_eq!(
+            battle_state.players[0].last_move,
+            before.players[0].last_move
+        );
+        assert_eq!(
+            battle_state.players[0].active_pokemon_conditions,
+            before.players[0].active_pokemon_conditions
+        );
+        assert_eq!(battle_state.players[1].team, before.players[1].team);
+        battle_state.assert_invariants();
+    }
+
+    #[test]
+    fn test_fainted_participant_still_counts_toward_exp_split() {
+        use crate::battle::commands::{execute_command, BattleCommand};
+        use crate::battle::progression::calculate_progression_commands;
+        use crate::battle::state::BattleType;
+        use crate::player::PlayerType;
+        use crate::progression::RewardCalculator;
+
+        // Player 1 leads with Pikachu, which faints to Rattata; Charmander then finishes it off.
+        let pikachu = TestPokemonBuilder::new(Species::Pikachu, 10)
+            .with_hp(0)
+            .build();
+        let charmander = TestPokemonBuilder::new(Species::Charmander, 10).build();
+        let rattata = TestPokemonBuilder::new(Species::Rattata, 10).build();
+        let mut battle_state = create_test_battle(rattata.clone(), rattata);
+        battle_state.players[0] =
+            create_test_player("p1", "Player 1", vec![pikachu, charmander.clone()]);
+        battle_state.players[0].player_type = PlayerType::Human;
+        battle_state.battle_type = BattleType::Trainer;
+        battle_state
+            .participation_tracker
+            .record_participation(0, 0);
+
+        execute_command(
+            BattleCommand::SwitchPokemon {
+                target: PlayerTarget::from_index(0),
+                new_pokemon_index: 1,
+            },
+            &mut battle_state,
+            &mut EventBus::new(),
+            &mut ActionStack::new(),
+        )
+        .unwrap();
+
+        // Both party members faced Rattata, even though Pikachu is now fainted.
+        assert_eq!(
+            battle_state
+                .participation_tracker
+                .get_participants_against(1, 0),
+            vec![0, 1]
+        );
+
+        let commands = calculate_progression_commands(
+            PlayerTarget::from_index(1),
+            Species::Rattata,
+            &battle_state,
+        );
+        let base_exp = RewardCalculator
+            .calculate_base_exp(Species::Rattata)
+            .unwrap();
+
+        // The split counts both participants; only the conscious one collects its half.
+        let recipients = commands
+            .iter()
+            .find_map(|command| match command {
+                BattleCommand::AwardExperience { recipients } => Some(recipients.clone()),
+                _ => None,
+            })
+            .expect("Charmander should be awarded experience");
+        assert_eq!(
+            recipients,
+            vec![(PlayerTarget::from_index(0), 1, base_exp / 2)]
+        );
+    }
+
+    #[test]
+    fn test_poison_tick_faint_is_processed_exactly_once() {
+        use crate::battle::calculators::sweep_faints;
+
+        // Arrange: Weedle is one poison tick away from fainting.
+        let weedle = TestPokemonBuilder::new(Species::Weedle, 10)
+            .with_moves(vec![Move::Splash])
+            .with_status(StatusCondition::Poison(0))
+            .with_hp(1)
+            .build();
+        let caterpie = TestPokemonBuilder::new(Species::Caterpie, 10)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let mut battle_state = create_test_battle(weedle, caterpie);
+
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        // Act
+        let event_bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert
+        event_bus.print_debug_with_message("Events for poison tick faint:");
+        let faint_events = event_bus
+            .events()
+            .iter()
+            .filter(|e| {
+                matches!(
+                    e,
+                    BattleEvent::PokemonFainted {
+                        player_index: 0,
+                        ..
+                    }
+                )
+            })
+            .count();
+        assert_eq!(faint_events, 1);
+        assert!(battle_state.faint_handled[0]);
+        assert!(
+            sweep_faints(&battle_state).is_empty(),
+            "A processed faint should not be swept again"
+        );
+    }
+
+    #[rstest]
+    #[case("attack KO")]
+    #[case("poison KO")]
+    #[case("Leech Seed KO")]
+    fn test_damage_is_reported_before_faint(#[case] desc: &str) {
+        use crate::battle::conditions::PokemonCondition;
+
+        // Arrange: The fainting Pokemon is at 1 HP and the other side uses Splash unless attacking.
+        let (mut battle_state, fainted_player, fainted_species) = match desc {
+            "attack KO" => {
+                let snorlax = TestPokemonBuilder::new(Species::Snorlax, 50)
+                    .with_moves(vec![Move::Tackle])
+                    .build();
+                let rattata = TestPokemonBuilder::new(Species::Rattata, 10)
+                    .with_moves(vec![Move::Splash])
+                    .with_hp(1)
+                    .build();
+                (create_test_battle(snorlax, rattata), 1, Species::Rattata)
+            }
+            "poison KO" => {
+                let weedle = TestPokemonBuilder::new(Species::Weedle, 10)
+                    .with_moves(vec![Move::Splash])
+                    .with_status(StatusCondition::Poison(0))
+                    .with_hp(1)
+                    .build();
+                let caterpie = TestPokemonBuilder::new(Species::Caterpie, 10)
+                    .with_moves(vec![Move::Splash])
+                    .build();
+                (create_test_battle(weedle, caterpie), 0, Species::Weedle)
+            }
+            "Leech Seed KO" => {
+                let rattata = TestPokemonBuilder::new(Species::Rattata, 10)
+                    .with_moves(vec![Move::Splash])
+                    .with_hp(1)
+                    .build();
+                let bulbasaur = TestPokemonBuilder::new(Species::Bulbasaur, 10)
+                    .with_moves(vec![Move::Splash])
+                    .build();
+                let mut battle_state = create_test_battle(rattata, bulbasaur);
+                battle_state.players[0].add_condition(PokemonCondition::Seeded);
+                (battle_state, 0, Species::Rattata)
+            }
+            _ => unreachable!("unknown case {}", desc),
+        };
+
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        // Act
+        let event_bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert
+        event_bus.print_debug_with_message(&format!(
+            "Events for test_damage_is_reported_before_faint [{}]:",
+            desc
+        ));
+        let events = event_bus.events();
+        let damage_index = events
+            .iter()
+            .position(|e| match e {
+                BattleEvent::DamageDealt { target, .. }
+                | BattleEvent::PokemonStatusDamage { target, .. }
+                | BattleEvent::StatusDamage { target, .. } => *target == fainted_species,
+                _ => false,
+            })
+            .expect("the knockout damage should be reported");
+        let faint_indices: Vec<usize> = events
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| {
+                matches!(e, BattleEvent::PokemonFainted { player_index, .. } if *player_index == fainted_player)
+            })
+            .map(|(index, _)| index)
+            .collect();
+
+        assert_eq!(faint_indices.len(), 1, "{}", desc);
+        assert!(
+            damage_index < faint_indices[0],
+            "{}: damage should be reported before the faint",
+            desc
+        );
+    }
+
+    #[test]
+    fn test_lifetime_participation_and_faints_carry_across_battles() {
+        // Battle 1: Pikachu knocks out a 1 HP Magikarp; Rattata never leaves the bench.
+        let pikachu = TestPokemonBuilder::new(Species::Pikachu, 50)
+            .with_moves(vec![Move::ThunderPunch])
+            .build();
+        let rattata = TestPokemonBuilder::new(Species::Rattata, 5)
+            .with_moves(vec![Move::Tackle])
+            .with_hp(1)
+            .build();
+        let player1 = create_test_player("p1", "Player 1", vec![pikachu, rattata]);
+        let magikarp = TestPokemonBuilder::new(Species::Magikarp, 5)
+            .with_moves(vec![Move::Splash])
+            .with_hp(1)
+            .build();
+        let player2 = create_test_player("p2", "Player 2", vec![magikarp]);
+        let mut battle_state =
+            crate::battle::state::BattleState::new("battle_1".to_string(), player1, player2);
+
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        resolve_turn(&mut battle_state, predictable_rng());
+        assert_eq!(battle_state.game_state, GameState::Player1Win);
+
+        // Battle 2: the saved team loses both Pokemon to Machamp.
+        let save = battle_state.players[0].persistent_state();
+        let mut player1 = BattlePlayer::from_save(save, "p1".to_string(), "Player 1".to_string());
+        player1.active_pokemon_mut().unwrap().set_hp(1);
+        let machamp = TestPokemonBuilder::new(Species::Machamp, 50)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let player2 = create_test_player("p2", "Player 2", vec![machamp]);
+        let mut battle_state =
+            crate::battle::state::BattleState::new("battle_2".to_string(), player1, player2);
+
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        resolve_turn(&mut battle_state, predictable_rng());
+        assert_eq!(
+            battle_state.game_state,
+            GameState::WaitingForPlayer1Replacement
+        );
+
+        battle_state.action_queue[0] = Some(PlayerAction::SwitchPokemon { team_index: 1 });
+        resolve_turn(&mut battle_state, predictable_rng());
+
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        resolve_turn(&mut battle_state, predictable_rng());
+        assert_eq!(battle_state.game_state, GameState::Player2Win);
+
+        // Assert: the lifetime counts survive the save and reflect both battles.
+        let team = battle_state.players[0].persistent_state().team;
+        assert_eq!(team[0].species, Species::Pikachu);
+        assert_eq!(team[0].times_participated, 2);
+        assert_eq!(team[0].times_fainted, 1);
+        assert_eq!(team[1].species, Species::Rattata);
+        assert_eq!(team[1].times_participated, 1);
+        assert_eq!(team[1].times_fainted, 1);
+    }
 }
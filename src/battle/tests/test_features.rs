@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod tests {
+    use crate::battle::engine::resolve_turn;
+    use crate::battle::features::{FEATURE_LENGTH, SIDE_FEATURE_LENGTH};
+    use crate::battle::tests::common::{create_test_battle, predictable_rng, TestPokemonBuilder};
+    use crate::player::PlayerAction;
+    use crate::pokemon::StatusCondition;
+    use crate::species::Species;
+    use pretty_assertions::assert_eq;
+    use schema::{Move, PokemonType, StatType};
+
+    #[test]
+    fn test_feature_snapshot_has_constant_length() {
+        let mut battle_state = create_test_battle(
+            TestPokemonBuilder::new(Species::Snorlax, 50)
+                .with_moves(vec![Move::Tackle])
+                .build(),
+            TestPokemonBuilder::new(Species::Snorlax, 50)
+                .with_moves(vec![Move::Tackle])
+                .build(),
+        );
+        assert_eq!(battle_state.feature_snapshot().len(), FEATURE_LENGTH);
+
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        resolve_turn(&mut battle_state, predictable_rng());
+
+        assert_eq!(battle_state.feature_snapshot().len(), FEATURE_LENGTH);
+    }
+
+    #[test]
+    fn test_feature_snapshot_encodes_known_state() {
+        // Arrange: a burned Charmander at half HP with +2 Attack against a healthy Squirtle.
+        let mut charmander = TestPokemonBuilder::new(Species::Charmander, 50)
+            .with_moves(vec![Move::Scratch])
+            .with_status(StatusCondition::Burn)
+            .build();
+        let half_hp = charmander.max_hp() / 2;
+        charmander.set_current_hp(half_hp);
+        let squirtle = TestPokemonBuilder::new(Species::Squirtle, 50)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let mut battle_state = create_test_battle(charmander, squirtle);
+        battle_state.players[0].set_stat_stage(StatType::Atk, 2);
+
+        // Act
+        let snapshot = battle_state.feature_snapshot();
+        let (p1, p2) = snapshot.values().split_at(SIDE_FEATURE_LENGTH);
+
+        // Assert: Player 1's block.
+        let max_hp = battle_state.players[0].active_pokemon().unwrap().max_hp();
+        assert_eq!(p1[0], f64::from(half_hp) / f64::from(max_hp));
+        let fire = 1 + PokemonType::ALL
+            .iter()
+            .position(|t| *t == PokemonType::Fire)
+            .unwrap();
+        assert_eq!(p1[fire], 1.0);
+        assert_eq!(p1[1..16].iter().sum::<f64>(), 1.0);
+        assert_eq!(p1[16], 2.0 / 6.0, "Atk stage should be scaled to 1/3");
+        assert_eq!(&p1[23..29], &[0.0, 0.0, 1.0, 0.0, 0.0, 0.0], "Burn one-hot");
+        assert_eq!(p1[29], 1.0 / 6.0);
+
+        // Assert: Player 2's block.
+        assert_eq!(p2[0], 1.0);
+        assert_eq!(&p2[16..23], &[0.0; 7]);
+        assert_eq!(&p2[23..29], &[0.0; 6]);
+    }
+}
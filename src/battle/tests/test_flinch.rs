@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests {
+    use crate::battle::conditions::{PokemonCondition, PokemonConditionType};
+    use crate::battle::engine::resolve_turn;
+    use crate::battle::state::{ActionFailureReason, BattleEvent, TurnRng};
+    use crate::battle::tests::common::{create_test_battle, TestPokemonBuilder};
+    use crate::player::PlayerAction;
+    use crate::species::Species;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use schema::Move;
+
+    #[rstest]
+    #[case(
+        "faster user flinches the target",
+        Species::Pikachu,
+        Species::Snorlax,
+        true
+    )]
+    #[case(
+        "slower user can't flinch a target that already moved",
+        Species::Snorlax,
+        Species::Pikachu,
+        false
+    )]
+    fn test_flinch_requires_target_to_move_later(
+        #[case] desc: &str,
+        #[case] flincher: Species,
+        #[case] target: Species,
+        #[case] expect_flinch: bool,
+    ) {
+        // Arrange: Headbutt has a 30% flinch chance, and every roll is 20.
+        let flincher_pokemon = TestPokemonBuilder::new(flincher, 50)
+            .with_moves(vec![Move::Headbutt])
+            .build();
+        let target_pokemon = TestPokemonBuilder::new(target, 50)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let mut battle_state = create_test_battle(flincher_pokemon, target_pokemon);
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        // Act
+        let bus = resolve_turn(&mut battle_state, TurnRng::new_for_test(vec![20; 100]));
+        bus.print_debug_with_message(&format!("Events for {}:", desc));
+
+        // Assert
+        let flinch_applied = bus.events().iter().any(|e| {
+            matches!(
+                e,
+                BattleEvent::StatusApplied {
+                    target: applied_to,
+                    status: PokemonCondition::Flinched,
+                } if *applied_to == target
+            )
+        });
+        let target_flinched = bus.events().iter().any(|e| {
+            matches!(
+                e,
+                BattleEvent::ActionFailed {
+                    reason: ActionFailureReason::IsFlinching { .. }
+                }
+            )
+        });
+        assert_eq!(flinch_applied, expect_flinch, "{}", desc);
+        assert_eq!(target_flinched, expect_flinch, "{}", desc);
+        assert!(!battle_state.players[1].has_condition_type(PokemonConditionType::Flinched));
+    }
+}
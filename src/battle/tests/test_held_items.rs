@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod tests {
+    use crate::battle::engine::resolve_turn;
+    use crate::battle::state::BattleEvent;
+    use crate::battle::tests::common::{create_test_battle, predictable_rng, TestPokemonBuilder};
+    use crate::player::PlayerAction;
+    use crate::species::Species;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use schema::{Item, Move};
+
+    /// Sonic Boom always deals 20 damage, so the holder's HP after the hit is exact.
+    #[rstest]
+    #[case("drops to half or less", |max_hp: u16| max_hp / 2 + 15, true)]
+    #[case("stays above half", |max_hp: u16| max_hp / 2 + 80, false)]
+    #[case("lethal hit", |_: u16| 15, false)]
+    fn test_oran_berry_heals_at_half_hp(
+        #[case] desc: &str,
+        #[case] starting_hp: fn(u16) -> u16,
+        #[case] expect_trigger: bool,
+    ) {
+        // Arrange
+        let attacker = TestPokemonBuilder::new(Species::Voltorb, 20)
+            .with_moves(vec![Move::SonicBoom])
+            .build();
+        let holder = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![Move::Splash])
+            .with_held_item(Item::OranBerry)
+            .build();
+        let starting_hp = starting_hp(holder.max_hp());
+        let mut battle_state = create_test_battle(attacker, holder);
+        battle_state.players[1].team[0]
+            .as_mut()
+            .unwrap()
+            .set_current_hp(starting_hp);
+
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        // Act
+        let event_bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert
+        event_bus.print_debug_with_message(&format!(
+            "Events for test_oran_berry_heals_at_half_hp [{}]:",
+            desc
+        ));
+        let consumed = event_bus.events().iter().any(|e| {
+            matches!(
+                e,
+                BattleEvent::HeldItemConsumed {
+                    target: Species::Snorlax,
+                    item: Item::OranBerry,
+                }
+            )
+        });
+        let holder = battle_state.players[1].team[0].as_ref().unwrap();
+
+        assert_eq!(consumed, expect_trigger, "{}", desc);
+        if expect_trigger {
+            assert_eq!(holder.held_item, None);
+            assert_eq!(holder.current_hp(), starting_hp - 20 + 10);
+        } else {
+            assert_eq!(holder.held_item, Some(Item::OranBerry));
+            assert_eq!(holder.current_hp(), starting_hp.saturating_sub(20));
+        }
+    }
+}
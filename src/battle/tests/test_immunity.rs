@@ -2,9 +2,12 @@
 
 #[cfg(test)]
 mod tests {
+    use crate::battle::conditions::PokemonCondition;
     use crate::battle::engine::resolve_turn;
-    use crate::battle::state::BattleEvent;
-    use crate::battle::tests::common::{create_test_battle, TestPokemonBuilder};
+    use crate::battle::state::{ActionFailureReason, BattleEvent, BattleState};
+    use crate::battle::tests::common::{
+        create_test_battle, create_test_player, predictable_rng, TestPokemonBuilder,
+    };
     use crate::player::PlayerAction;
     use crate::species::Species;
     use rstest::rstest;
@@ -94,4 +97,96 @@ mod tests {
             );
         }
     }
+
+    #[rstest]
+    #[case(
+        "Grass types can't be seeded",
+        Move::LeechSeed,
+        Species::Bulbasaur,
+        None
+    )]
+    #[case(
+        "Substitute blocks confusion",
+        Move::ConfuseRay,
+        Species::Alakazam,
+        Some(PokemonCondition::Substitute { hp: 25 })
+    )]
+    #[case(
+        "Already confused",
+        Move::ConfuseRay,
+        Species::Alakazam,
+        Some(PokemonCondition::Confused { turns_remaining: 3 })
+    )]
+    fn test_condition_immunity_fails_status_move(
+        #[case] desc: &str,
+        #[case] attacking_move: Move,
+        #[case] defender_species: Species,
+        #[case] existing_condition: Option<PokemonCondition>,
+    ) {
+        // Arrange
+        let attacker = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![attacking_move])
+            .build();
+        let defender = TestPokemonBuilder::new(defender_species, 50)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let player1 = create_test_player("p1", "Player 1", vec![attacker]);
+        let mut player2 = create_test_player("p2", "Player 2", vec![defender]);
+        if let Some(condition) = existing_condition.clone() {
+            player2.add_condition(condition);
+        }
+        let mut battle_state = BattleState::new("test".to_string(), player1, player2);
+
+        // The immunity is decided by the one shared check.
+        let condition = match attacking_move {
+            Move::LeechSeed => PokemonCondition::Seeded,
+            _ => PokemonCondition::Confused { turns_remaining: 1 },
+        };
+        let target_player = &battle_state.players[1];
+        assert!(
+            !condition.can_apply_to(
+                target_player.active_pokemon().unwrap(),
+                target_player.battler(0)
+            ),
+            "{}",
+            desc
+        );
+
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        // Act
+        let event_bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert: the status move reports failure and nothing new lands.
+        event_bus.print_debug_with_message(&format!(
+            "Events for test_condition_immunity_fails_status_move [{}]:",
+            desc
+        ));
+        assert!(
+            event_bus.events().iter().any(|e| matches!(
+                e,
+                BattleEvent::ActionFailed {
+                    reason: ActionFailureReason::NoEffect { move_used }
+                } if *move_used == attacking_move
+            )),
+            "{}",
+            desc
+        );
+        assert!(!event_bus
+            .events()
+            .iter()
+            .any(|e| matches!(e, BattleEvent::StatusApplied { .. })));
+    }
+
+    #[test]
+    fn test_condition_can_apply_to_unprotected_target() {
+        let defender = TestPokemonBuilder::new(Species::Alakazam, 50).build();
+        let player = create_test_player("p2", "Player 2", vec![defender]);
+        let pokemon = player.active_pokemon().unwrap();
+
+        assert!(PokemonCondition::Seeded.can_apply_to(pokemon, player.battler(0)));
+        assert!(PokemonCondition::Confused { turns_remaining: 1 }
+            .can_apply_to(pokemon, player.battler(0)));
+    }
 }
@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod tests {
+    use crate::battle::engine::resolve_turn;
+    use crate::battle::log::transcript;
+    use crate::battle::state::GameState;
+    use crate::battle::tests::common::{create_test_battle, predictable_rng, TestPokemonBuilder};
+    use crate::player::PlayerAction;
+    use crate::species::Species;
+    use pretty_assertions::assert_eq;
+    use schema::Move;
+
+    #[test]
+    fn test_transcript_of_two_turn_battle() {
+        // Arrange: Rattata hangs on at 1 HP; Snorlax waits a turn and then finishes it.
+        let p1_pokemon = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![Move::Splash, Move::Tackle])
+            .build();
+        let p2_pokemon = TestPokemonBuilder::new(Species::Rattata, 10)
+            .with_moves(vec![Move::Splash])
+            .with_hp(1)
+            .build();
+        let mut battle_state = create_test_battle(p1_pokemon, p2_pokemon);
+        let initial_state = battle_state.clone();
+
+        // Act
+        let mut buses = Vec::new();
+        for move_index in [0, 1] {
+            battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index });
+            battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+            let bus = resolve_turn(&mut battle_state, predictable_rng());
+            bus.print_debug_with_message("Events for test_transcript_of_two_turn_battle:");
+            buses.push(bus);
+        }
+        let text = transcript(&initial_state, &buses);
+        println!("{}", text);
+
+        // Assert
+        assert_eq!(battle_state.game_state, GameState::Player1Win);
+        assert!(text.starts_with("Player 1 sent out Snorlax!\nPlayer 2 sent out Rattata!\n"));
+        assert!(text.contains("=== Turn 1 ==="));
+        assert!(text.contains("=== Turn 2 ==="));
+        assert!(text.contains("Player 1's Snorlax used Splash!"));
+        assert!(text.contains("Player 1's Snorlax used Tackle!"));
+        assert!(text.contains("Rattata fainted!"));
+        assert!(text.contains("Player 1 has won the battle!"));
+        assert!(!text.contains("The battle has not finished."));
+    }
+}
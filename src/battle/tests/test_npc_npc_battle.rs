@@ -1,10 +1,16 @@
 #[cfg(test)]
 mod tests {
-    use crate::battle::engine::{collect_npc_actions, ready_for_turn_resolution, resolve_turn};
-    use crate::battle::state::{BattleState, GameState, TurnRng};
+    use crate::battle::engine::{
+        advance_battle, advance_battle_with_cap, collect_npc_actions, ready_for_turn_resolution,
+        resolve_turn, AdvanceOutcome,
+    };
+    use crate::battle::state::{BattleEvent, BattleState, GameState, TurnRng};
+    use crate::battle::tests::common::{create_test_player, TestPokemonBuilder};
     use crate::player::PlayerType;
+    use crate::species::Species;
     use crate::teams::create_battle_player_from_team;
-    use pretty_assertions::assert_ne;
+    use pretty_assertions::{assert_eq, assert_ne};
+    use schema::Move;
 
     #[test]
     fn test_full_npc_battle_completes_without_crashing() {
@@ -78,4 +84,98 @@ mod tests {
             "Battle failed to complete within the turn limit (100 turns)"
         );
     }
+
+    #[test]
+    fn test_advance_battle_runs_npc_battle_to_completion() {
+        // Arrange
+        let mut player1 = create_battle_player_from_team(
+            "demo_venusaur",
+            "npc_trainer_1".to_string(),
+            "AI Trainer Red".to_string(),
+        )
+        .expect("Failed to create Player 1");
+        player1.player_type = PlayerType::NPC;
+
+        let mut player2 = create_battle_player_from_team(
+            "demo_charizard",
+            "npc_trainer_2".to_string(),
+            "AI Trainer Blue".to_string(),
+        )
+        .expect("Failed to create Player 2");
+        player2.player_type = PlayerType::NPC;
+
+        let mut battle_state = BattleState::new("advance_test".to_string(), player1, player2);
+
+        // Act
+        let result = advance_battle(&mut battle_state, TurnRng::new_random);
+
+        // Assert
+        println!("Final Game State: {:?}", battle_state.game_state);
+        assert_eq!(result.outcome, AdvanceOutcome::BattleOver);
+        assert!(matches!(
+            battle_state.game_state,
+            GameState::Player1Win | GameState::Player2Win | GameState::Draw
+        ));
+        assert!(!result.events.is_empty());
+    }
+
+    #[test]
+    fn test_advance_battle_stops_for_human_input() {
+        // Arrange
+        let mut player1 = create_battle_player_from_team(
+            "demo_venusaur",
+            "human_player".to_string(),
+            "Player".to_string(),
+        )
+        .expect("Failed to create Player 1");
+        player1.player_type = PlayerType::Human;
+
+        let mut player2 = create_battle_player_from_team(
+            "demo_charizard",
+            "npc_trainer".to_string(),
+            "AI Trainer".to_string(),
+        )
+        .expect("Failed to create Player 2");
+        player2.player_type = PlayerType::NPC;
+
+        let mut battle_state = BattleState::new("advance_test".to_string(), player1, player2);
+
+        // Act
+        let result = advance_battle(&mut battle_state, TurnRng::new_random);
+
+        // Assert: Nothing can happen until the human picks an action,
+        // but the NPC has already queued theirs.
+        assert_eq!(result.outcome, AdvanceOutcome::AwaitingInput(vec![0]));
+        assert!(result.events.is_empty());
+        assert_eq!(battle_state.game_state, GameState::WaitingForActions);
+        assert!(battle_state.action_queue[0].is_none());
+        assert!(battle_state.action_queue[1].is_some());
+    }
+
+    #[test]
+    fn test_advance_battle_ends_an_npc_stalemate() {
+        // Arrange: Two NPC Magikarp that can only Splash, with no turn limit set.
+        let splash_npc = |id: &str| {
+            let magikarp = TestPokemonBuilder::new(Species::Magikarp, 20)
+                .with_moves(vec![Move::Splash])
+                .build();
+            let mut player = create_test_player(id, id, vec![magikarp]);
+            player.player_type = PlayerType::NPC;
+            player
+        };
+        let mut battle_state =
+            BattleState::new("stalemate".to_string(), splash_npc("p1"), splash_npc("p2"));
+
+        // Act: A small cap, so the stall is cut off long before Splash runs out of PP.
+        let result = advance_battle_with_cap(&mut battle_state, TurnRng::new_random, 10);
+
+        // Assert: The cap steps in and the untouched sides draw on HP share.
+        assert_eq!(result.outcome, AdvanceOutcome::BattleOver);
+        assert_eq!(battle_state.game_state, GameState::Draw);
+        assert_eq!(battle_state.turn_number, 11);
+        assert!(result
+            .events
+            .iter()
+            .any(|e| matches!(e, BattleEvent::TurnLimitReached { .. })));
+    }
 }
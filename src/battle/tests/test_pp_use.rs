@@ -1,11 +1,13 @@
 #[cfg(test)]
 mod tests {
     use crate::battle::engine::resolve_turn;
-    use crate::battle::state::BattleEvent;
+    use crate::battle::state::BattleState;
+    use crate::battle::state::{BattleEvent, TurnRng};
     use crate::battle::tests::common::{create_test_battle, predictable_rng, TestPokemonBuilder};
-    use crate::player::PlayerAction;
+    use crate::player::{BattlePlayer, PlayerAction, PlayerType};
     use crate::species::Species;
     use pretty_assertions::assert_eq;
+    use rstest::rstest;
     use schema::Move;
 
     #[test]
@@ -144,4 +146,214 @@ mod tests {
             "Recoil damage should be 50% of the damage dealt."
         );
     }
+
+    #[test]
+    fn test_struggle_can_ko_a_ghost() {
+        // Arrange: Snorlax is out of PP, and Gengar is hanging on with 1 HP.
+        let mut p1_pokemon = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![Move::BodySlam])
+            .build();
+        p1_pokemon.moves[0].as_mut().unwrap().pp = 0;
+
+        let p2_pokemon = TestPokemonBuilder::new(Species::Gengar, 50)
+            .with_moves(vec![Move::Splash])
+            .with_hp(1)
+            .build();
+        let mut battle_state = create_test_battle(p1_pokemon, p2_pokemon);
+
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        // Act
+        let event_bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert
+        event_bus.print_debug_with_message("Events for test_struggle_can_ko_a_ghost:");
+        assert!(event_bus.events().iter().any(|e| matches!(
+            e,
+            BattleEvent::MoveUsed {
+                player_index: 0,
+                move_used: Move::Struggle,
+                ..
+            }
+        )));
+        assert!(event_bus.events().iter().any(|e| matches!(
+            e,
+            BattleEvent::PokemonFainted {
+                pokemon: Species::Gengar,
+                ..
+            }
+        )));
+    }
+
+    /// Returns the non-Metronome move player 0 used in a turn, if any.
+    fn metronome_called_move(events: &[BattleEvent]) -> Option<Move> {
+        events.iter().find_map(|e| match e {
+            BattleEvent::MoveUsed {
+                player_index: 0,
+                move_used,
+                ..
+            } if *move_used != Move::Metronome => Some(*move_used),
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn test_metronome_uses_its_own_pp_only() {
+        // Arrange: Metronome's pick depends only on the RNG, so find out which move
+        // this RNG selects, then give that move to the user alongside Metronome.
+        let rng_values = vec![50; 40];
+        let probe = TestPokemonBuilder::new(Species::Clefairy, 25)
+            .with_moves(vec![Move::Metronome])
+            .build();
+        let opponent = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let mut probe_state = create_test_battle(probe, opponent.clone());
+        probe_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        probe_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        let probe_bus = resolve_turn(&mut probe_state, TurnRng::new_for_test(rng_values.clone()));
+        let called_move = metronome_called_move(probe_bus.events())
+            .expect("Metronome should have called another move");
+
+        let p1_pokemon = TestPokemonBuilder::new(Species::Clefairy, 25)
+            .with_moves(vec![Move::Metronome, called_move])
+            .build();
+        let initial_metronome_pp = p1_pokemon.moves[0].as_ref().unwrap().pp;
+        let initial_called_pp = p1_pokemon.moves[1].as_ref().unwrap().pp;
+        let mut battle_state = create_test_battle(p1_pokemon, opponent);
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        // Act
+        let event_bus = resolve_turn(&mut battle_state, TurnRng::new_for_test(rng_values));
+
+        // Assert
+        event_bus.print_debug_with_message("Events for test_metronome_uses_its_own_pp_only:");
+        assert_eq!(metronome_called_move(event_bus.events()), Some(called_move));
+        let moves = &battle_state.players[0].active_pokemon().unwrap().moves;
+        assert_eq!(
+            moves[0].as_ref().unwrap().pp,
+            initial_metronome_pp - 1,
+            "Metronome should use exactly one of its own PP"
+        );
+        assert_eq!(
+            moves[1].as_ref().unwrap().pp,
+            initial_called_pp,
+            "The move called by Metronome should not use any PP"
+        );
+    }
+
+    #[test]
+    fn test_mirror_move_uses_its_own_pp_only() {
+        // Arrange: Pidgeot knows the move it will mirror, so we can check it isn't charged PP.
+        let p1_pokemon = TestPokemonBuilder::new(Species::Pidgeot, 25)
+            .with_moves(vec![Move::MirrorMove, Move::Tackle])
+            .build();
+        let initial_mirror_pp = p1_pokemon.moves[0].as_ref().unwrap().pp;
+        let initial_tackle_pp = p1_pokemon.moves[1].as_ref().unwrap().pp;
+        let p2_pokemon = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let mut battle_state = create_test_battle(p1_pokemon, p2_pokemon);
+        battle_state.players[1].last_move = Some(Move::Tackle);
+
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        // Act
+        let event_bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert
+        event_bus.print_debug_with_message("Events for test_mirror_move_uses_its_own_pp_only:");
+        assert!(event_bus.events().iter().any(|e| matches!(
+            e,
+            BattleEvent::MoveUsed {
+                player_index: 0,
+                move_used: Move::Tackle,
+                ..
+            }
+        )));
+        let moves = &battle_state.players[0].active_pokemon().unwrap().moves;
+        assert_eq!(moves[0].as_ref().unwrap().pp, initial_mirror_pp - 1);
+        assert_eq!(moves[1].as_ref().unwrap().pp, initial_tackle_pp);
+    }
+
+    #[rstest]
+    #[case::human(PlayerType::Human)]
+    #[case::npc(PlayerType::NPC)]
+    fn test_fully_depleted_pokemon_struggles_for_any_player_type(#[case] player_type: PlayerType) {
+        // Arrange: Snorlax has two moves, both out of PP.
+        let mut p1_pokemon = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![Move::QuickAttack, Move::HyperBeam])
+            .build();
+        for slot in p1_pokemon.moves.iter_mut().flatten() {
+            slot.pp = 0;
+        }
+        let p2_pokemon = TestPokemonBuilder::new(Species::Charmander, 50)
+            .with_moves(vec![Move::Splash])
+            .build();
+
+        let player1 = BattlePlayer::new_with_player_type(
+            "p1".to_string(),
+            "Player 1".to_string(),
+            vec![p1_pokemon],
+            player_type,
+        );
+        let player2 = BattlePlayer::new("p2".to_string(), "Player 2".to_string(), vec![p2_pokemon]);
+        let mut battle_state = BattleState::new("test".to_string(), player1, player2);
+        assert!(battle_state.players[0].must_struggle());
+        assert_eq!(
+            battle_state.players[0].get_valid_moves(),
+            vec![PlayerAction::UseMove { move_index: 0 }]
+        );
+
+        // Act: Pick the second slot, which also has no PP left.
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 1 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        let event_bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert: Struggle is used, with recoil (the only damage Snorlax can take), and no depleted move runs.
+        event_bus
+            .print_debug_with_message(&format!("Events for depleted {:?} player:", player_type));
+        assert!(event_bus.events().iter().any(|e| matches!(
+            e,
+            BattleEvent::MoveUsed {
+                player_index: 0,
+                move_used: Move::Struggle,
+                ..
+            }
+        )));
+        assert!(!event_bus.events().iter().any(|e| matches!(
+            e,
+            BattleEvent::MoveUsed {
+                player_index: 0,
+                move_used: Move::QuickAttack | Move::HyperBeam,
+                ..
+            }
+        )));
+        assert!(event_bus.events().iter().any(|e| matches!(
+            e,
+            BattleEvent::DamageDealt {
+                target: Species::Snorlax,
+                ..
+            }
+        )));
+    }
+
+    #[test]
+    fn test_must_struggle_is_false_while_any_move_has_pp() {
+        let mut pokemon = TestPokemonBuilder::new(Species::Pikachu, 25)
+            .with_moves(vec![Move::Tackle, Move::ThunderWave])
+            .build();
+        pokemon.moves[0].as_mut().unwrap().pp = 0;
+        let battle_state = create_test_battle(
+            pokemon,
+            TestPokemonBuilder::new(Species::Charmander, 25)
+                .with_moves(vec![Move::Scratch])
+                .build(),
+        );
+
+        assert!(!battle_state.players[0].must_struggle());
+    }
 }
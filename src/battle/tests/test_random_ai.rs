@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod tests {
+    use crate::battle::ai::random_legal_action;
+    use crate::battle::conditions::PokemonCondition;
+    use crate::battle::state::{BattleState, TurnRng};
+    use crate::battle::tests::common::{create_test_player, TestPokemonBuilder};
+    use crate::player::{BattlePlayer, PlayerAction};
+    use crate::species::Species;
+    use pretty_assertions::assert_eq;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use schema::Move;
+
+    /// Pikachu with one move out of PP and one disabled, a fainted Charmander and a healthy Squirtle.
+    fn constrained_player() -> BattlePlayer {
+        let mut pikachu = TestPokemonBuilder::new(Species::Pikachu, 25)
+            .with_moves(vec![Move::Tackle, Move::ThunderPunch, Move::Growl])
+            .build();
+        pikachu.moves[0].as_mut().unwrap().pp = 0;
+        let charmander = TestPokemonBuilder::new(Species::Charmander, 25)
+            .with_moves(vec![Move::Scratch])
+            .with_hp(0)
+            .build();
+        let squirtle = TestPokemonBuilder::new(Species::Squirtle, 25)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let mut player = create_test_player("p1", "Player 1", vec![pikachu, charmander, squirtle]);
+        player.add_condition(PokemonCondition::Disabled {
+            pokemon_move: Move::Growl,
+            turns_remaining: 3,
+        });
+        player
+    }
+
+    /// A battle with `player` as Player 1 against a lone Rattata.
+    fn battle_for(player: BattlePlayer) -> BattleState {
+        let rattata = TestPokemonBuilder::new(Species::Rattata, 25)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let opponent = create_test_player("p2", "Player 2", vec![rattata]);
+        BattleState::new("test".to_string(), player, opponent)
+    }
+
+    /// Every distinct action Player 1 can be handed, across all possible rolls.
+    fn all_choices(battle_state: &BattleState) -> Vec<PlayerAction> {
+        let mut choices = Vec::new();
+        for roll in 1..=100 {
+            let action =
+                random_legal_action(0, battle_state, &mut TurnRng::new_for_test(vec![roll]));
+            if !choices.contains(&action) {
+                choices.push(action);
+            }
+        }
+        choices
+    }
+
+    #[test]
+    fn test_random_legal_action_is_reproducible_under_a_seed() {
+        let battle_state = battle_for(constrained_player());
+
+        let pick = |seed: u64| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (0..20)
+                .map(|_| random_legal_action(0, &battle_state, &mut TurnRng::from_rng(&mut rng)))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(pick(7), pick(7));
+    }
+
+    #[test]
+    fn test_random_legal_action_only_picks_legal_actions() {
+        let mut battle_state = battle_for(constrained_player());
+
+        // Only the move with PP that isn't disabled, and the healthy bench Pokemon.
+        assert_eq!(
+            all_choices(&battle_state),
+            vec![
+                PlayerAction::UseMove { move_index: 1 },
+                PlayerAction::SwitchPokemon { team_index: 2 },
+            ]
+        );
+
+        // Trapped: no switching at all.
+        battle_state.players[0].add_condition(PokemonCondition::Trapped { turns_remaining: 2 });
+        assert_eq!(
+            all_choices(&battle_state),
+            vec![PlayerAction::UseMove { move_index: 1 }]
+        );
+    }
+
+    #[test]
+    fn test_random_legal_action_only_switches_within_format() {
+        let mut battle_state = battle_for(constrained_player());
+        // Squirtle sits in the third slot, outside a 2v2 format.
+        battle_state.rules.format_team_size = 2;
+
+        assert_eq!(
+            all_choices(&battle_state),
+            vec![PlayerAction::UseMove { move_index: 1 }]
+        );
+    }
+
+    #[test]
+    fn test_random_legal_action_struggles_when_nothing_is_usable() {
+        let mut pikachu = TestPokemonBuilder::new(Species::Pikachu, 25)
+            .with_moves(vec![Move::Tackle, Move::ThunderPunch])
+            .build();
+        for slot in pikachu.moves.iter_mut().flatten() {
+            slot.pp = 0;
+        }
+        let battle_state = battle_for(create_test_player("p1", "Player 1", vec![pikachu]));
+
+        assert_eq!(
+            random_legal_action(0, &battle_state, &mut TurnRng::new_for_test(vec![50])),
+            PlayerAction::UseMove { move_index: 0 }
+        );
+    }
+}
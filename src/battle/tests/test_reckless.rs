@@ -1,12 +1,16 @@
 #[cfg(test)]
 mod tests {
-    use crate::battle::engine::resolve_turn;
-    use crate::battle::state::{BattleEvent, TurnRng};
+    use crate::battle::action_stack::ActionStack;
+    use crate::battle::commands::PlayerTarget;
+    use crate::battle::engine::{execute_attack_hit, resolve_turn};
+    use crate::battle::state::{BattleEvent, EventBus, TurnRng};
     use crate::battle::tests::common::{create_test_battle, TestPokemonBuilder};
+    use crate::move_data::get_move_data;
     use crate::player::PlayerAction;
     use crate::species::Species;
+    use pretty_assertions::assert_eq;
     use rstest::rstest;
-    use schema::Move;
+    use schema::{Move, MoveEffect};
 
     #[rstest]
     #[case(
@@ -174,4 +178,67 @@ mod tests {
             "Non-reckless moves should not cause recoil damage on miss"
         );
     }
+
+    #[rstest]
+    #[case(Move::JumpKick, 20)]
+    #[case(Move::HighJumpKick, 50)]
+    fn test_jump_kicks_carry_reckless(#[case] move_used: Move, #[case] expected_percent: u8) {
+        let move_data = get_move_data(move_used).unwrap();
+        assert!(move_data
+            .effects
+            .iter()
+            .any(|effect| matches!(effect, MoveEffect::Reckless(p) if *p == expected_percent)));
+    }
+
+    #[rstest]
+    #[case("miss crashes for half max HP", Species::Snorlax, 95, true)] // HJK accuracy is 80
+    #[case("hit does not crash", Species::Snorlax, 50, false)]
+    #[case("immune target does not crash", Species::Gastly, 50, false)]
+    fn test_high_jump_kick_crash_amount(
+        #[case] desc: &str,
+        #[case] defender_species: Species,
+        #[case] accuracy_rng: u8,
+        #[case] should_crash: bool,
+    ) {
+        // Arrange
+        let attacker = TestPokemonBuilder::new(Species::Hitmonlee, 50)
+            .with_moves(vec![Move::HighJumpKick])
+            .build();
+        let max_hp = attacker.max_hp();
+        let defender = TestPokemonBuilder::new(defender_species, 50).build();
+        let mut battle_state = create_test_battle(attacker, defender);
+
+        let mut bus = EventBus::new();
+        let mut rng = TurnRng::new_for_test(vec![accuracy_rng, 90, 90, 90]);
+        let mut action_stack = ActionStack::new();
+
+        // Act
+        execute_attack_hit(
+            PlayerTarget::from_index(0),
+            PlayerTarget::from_index(1),
+            Move::HighJumpKick,
+            0,
+            &mut action_stack,
+            &mut bus,
+            &mut rng,
+            &mut battle_state,
+        );
+
+        // Assert
+        bus.print_debug_with_message(&format!(
+            "Events for test_high_jump_kick_crash_amount [{}]:",
+            desc
+        ));
+        let hp_lost = max_hp
+            - battle_state.players[0]
+                .active_pokemon()
+                .unwrap()
+                .current_hp();
+        let expected_crash = if should_crash {
+            (max_hp as f32 * 0.5).ceil() as u16
+        } else {
+            0
+        };
+        assert_eq!(hp_lost, expected_crash, "{}", desc);
+    }
 }
@@ -110,10 +110,11 @@ mod tests {
                 modified_damage < baseline_damage,
                 "Damage should have been reduced"
             );
-            // Check if damage is roughly halved, allowing for rounding
+            // Check if damage is roughly halved. The formula's flat +2 isn't halved and
+            // is scaled by type effectiveness, so allow a little over half for that and rounding.
             let expected_damage = baseline_damage / 2;
             assert!(
-                (modified_damage as i16 - expected_damage as i16).abs() <= 2,
+                (modified_damage as i16 - expected_damage as i16).abs() <= 3,
                 "Damage reduction should be approx. 50%"
             );
         } else {
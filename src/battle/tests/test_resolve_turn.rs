@@ -1,11 +1,14 @@
 #[cfg(test)]
 mod tests {
+    use crate::battle::action_stack::{ActionStack, BattleAction};
     use crate::battle::engine::resolve_turn;
-    use crate::battle::state::{BattleEvent, GameState};
+    use crate::battle::state::{BattleEvent, GameState, TurnRng};
     use crate::battle::tests::common::{create_test_battle, predictable_rng, TestPokemonBuilder};
-    use crate::player::PlayerAction;
+    use crate::player::{PlayerAction, StatType};
+    use crate::pokemon::StatusCondition;
     use crate::species::Species;
     use pretty_assertions::assert_eq;
+    use rstest::rstest;
     use schema::Move;
 
     #[test]
@@ -57,4 +60,223 @@ mod tests {
             "Action queue should be cleared after the turn is resolved"
         );
     }
+
+    #[rstest]
+    #[case("low roll keeps player 0 first", 50, 0)]
+    #[case("high roll puts player 1 first", 51, 1)]
+    fn test_speed_tie_consults_rng(
+        #[case] desc: &str,
+        #[case] tie_roll: u8,
+        #[case] expected_first: usize,
+    ) {
+        // Arrange: identical Pokemon using the same move tie on priority and speed.
+        let p1_pokemon = TestPokemonBuilder::new(Species::Pikachu, 25)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let p2_pokemon = TestPokemonBuilder::new(Species::Pikachu, 25)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let mut battle_state = create_test_battle(p1_pokemon, p2_pokemon);
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        // Act: Only the tie-break roll is supplied, so an extra roll would panic.
+        let mut rng = TurnRng::new_for_test(vec![tie_roll]);
+        let mut action_stack = ActionStack::build_initial(&battle_state, &mut rng);
+
+        // Assert
+        let first_attacker = match action_stack.pop_front() {
+            Some(BattleAction::AttackHit { attacker, .. }) => attacker.to_index(),
+            other => panic!("Expected an AttackHit action, got {:?}", other),
+        };
+        assert_eq!(first_attacker, expected_first, "{}", desc);
+    }
+
+    #[rstest]
+    #[case(
+        "Quick Attack outprioritizes a faster Tackle",
+        Move::QuickAttack,
+        Move::Tackle,
+        0
+    )]
+    #[case(
+        "equal priority falls back to speed",
+        Move::QuickAttack,
+        Move::QuickAttack,
+        1
+    )]
+    #[case("plain moves fall back to speed", Move::Tackle, Move::Tackle, 1)]
+    fn test_move_priority_beats_speed(
+        #[case] desc: &str,
+        #[case] slow_move: Move,
+        #[case] fast_move: Move,
+        #[case] expected_first: usize,
+    ) {
+        // Arrange: Snorlax (player 0) is far slower than Pikachu (player 1).
+        let snorlax = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![slow_move])
+            .build();
+        let pikachu = TestPokemonBuilder::new(Species::Pikachu, 50)
+            .with_moves(vec![fast_move])
+            .build();
+        let mut battle_state = create_test_battle(snorlax, pikachu);
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        // Act: no tie-break roll is supplied, so ordering must not need the RNG.
+        let mut rng = TurnRng::new_for_test(vec![]);
+        let mut action_stack = ActionStack::build_initial(&battle_state, &mut rng);
+
+        // Assert
+        let first_attacker = match action_stack.pop_front() {
+            Some(BattleAction::AttackHit { attacker, .. }) => attacker.to_index(),
+            other => panic!("Expected an AttackHit action, got {:?}", other),
+        };
+        assert_eq!(first_attacker, expected_first, "{}", desc);
+    }
+
+    #[test]
+    fn test_quick_attack_moves_first_in_resolved_turn() {
+        // Arrange: Snorlax is kept low enough that Pikachu survives to act.
+        let snorlax = TestPokemonBuilder::new(Species::Snorlax, 40)
+            .with_moves(vec![Move::QuickAttack])
+            .build();
+        let pikachu = TestPokemonBuilder::new(Species::Pikachu, 50)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let mut battle_state = create_test_battle(snorlax, pikachu);
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        // Act
+        let event_bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert
+        event_bus
+            .print_debug_with_message("Events for test_quick_attack_moves_first_in_resolved_turn:");
+        let move_order: Vec<usize> = event_bus
+            .events()
+            .iter()
+            .filter_map(|e| match e {
+                BattleEvent::MoveUsed { player_index, .. } => Some(*player_index),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(move_order, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_double_forfeit_is_a_draw() {
+        // Arrange
+        let p1_pokemon = TestPokemonBuilder::new(Species::Pikachu, 25)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let p2_pokemon = TestPokemonBuilder::new(Species::Pikachu, 25)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let mut battle_state = create_test_battle(p1_pokemon, p2_pokemon);
+        battle_state.action_queue[0] = Some(PlayerAction::Forfeit);
+        battle_state.action_queue[1] = Some(PlayerAction::Forfeit);
+
+        // Act
+        let event_bus = resolve_turn(&mut battle_state, TurnRng::new_for_test(vec![]));
+
+        // Assert
+        event_bus.print_debug_with_message("Events for test_double_forfeit_is_a_draw:");
+        assert_eq!(battle_state.game_state, GameState::Draw);
+        assert!(event_bus
+            .events()
+            .iter()
+            .any(|e| matches!(e, BattleEvent::BattleEnded { winner: None })));
+        assert!(!event_bus
+            .events()
+            .iter()
+            .any(|e| matches!(e, BattleEvent::PlayerDefeated { .. })));
+    }
+
+    #[test]
+    fn test_rng_trace_lists_reasons_in_order() {
+        // Arrange
+        let pikachu = TestPokemonBuilder::new(Species::Pikachu, 12)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let charmander = TestPokemonBuilder::new(Species::Charmander, 10)
+            .with_moves(vec![Move::Scratch])
+            .build();
+        let mut battle_state = create_test_battle(pikachu, charmander);
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        // Act
+        let event_bus = resolve_turn(&mut battle_state, predictable_rng().with_trace());
+
+        // Assert: Pikachu attacks first, then Charmander, each rolling hit, crit and damage.
+        let trace = event_bus.rng_trace().expect("trace should be recorded");
+        let reasons: Vec<&str> = trace.iter().map(|(reason, _)| reason.as_str()).collect();
+        assert_eq!(
+            reasons,
+            vec![
+                "Hit/Miss Check",
+                "Critical Hit Check",
+                "Random Damage Multiplier Roll",
+                "Hit/Miss Check",
+                "Critical Hit Check",
+                "Random Damage Multiplier Roll",
+            ]
+        );
+        assert!(trace.iter().all(|(_, value)| *value == 50));
+    }
+
+    #[test]
+    fn test_rng_trace_is_absent_unless_requested() {
+        let pikachu = TestPokemonBuilder::new(Species::Pikachu, 12)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let charmander = TestPokemonBuilder::new(Species::Charmander, 10)
+            .with_moves(vec![Move::Scratch])
+            .build();
+        let mut battle_state = create_test_battle(pikachu, charmander);
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        let event_bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        assert!(event_bus.rng_trace().is_none());
+    }
+
+    #[test]
+    fn test_faster_side_reports_current_speed_order() {
+        // Arrange: Pikachu naturally outspeeds a same-level Charmander.
+        let pikachu = TestPokemonBuilder::new(Species::Pikachu, 25)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let charmander = TestPokemonBuilder::new(Species::Charmander, 25)
+            .with_moves(vec![Move::Scratch])
+            .build();
+        let battle_state = create_test_battle(pikachu.clone(), charmander.clone());
+        assert_eq!(battle_state.faster_side(), Some(0));
+
+        // Paralysis cuts Pikachu's speed enough for Charmander to move first.
+        let mut paralyzed_pikachu = pikachu.clone();
+        paralyzed_pikachu.status = Some(StatusCondition::Paralysis);
+        let battle_state = create_test_battle(paralyzed_pikachu, charmander.clone());
+        assert_eq!(battle_state.faster_side(), Some(1));
+
+        // Stat stages count too.
+        let mut battle_state = create_test_battle(pikachu, charmander);
+        battle_state.players[1].set_stat_stage(StatType::Spe, 6);
+        assert_eq!(battle_state.faster_side(), Some(1));
+    }
+
+    #[test]
+    fn test_faster_side_is_none_on_a_tie() {
+        let make = || {
+            TestPokemonBuilder::new(Species::Pikachu, 25)
+                .with_moves(vec![Move::Tackle])
+                .build()
+        };
+        let battle_state = create_test_battle(make(), make());
+
+        assert_eq!(battle_state.faster_side(), None);
+    }
 }
@@ -186,4 +186,78 @@ mod tests {
             "No damage should be dealt to the opponent"
         );
     }
+
+    #[test]
+    fn test_rest_clears_badly_poisoned_state() {
+        // Arrange: Snorlax has a built-up Toxic counter.
+        let max_hp = TestPokemonBuilder::new(Species::Snorlax, 10)
+            .build()
+            .max_hp();
+        let p1_pokemon = TestPokemonBuilder::new(Species::Snorlax, 10)
+            .with_moves(vec![Move::Rest])
+            .with_hp(max_hp / 2)
+            .with_status(StatusCondition::Poison(4))
+            .build();
+        let p2_pokemon = TestPokemonBuilder::new(Species::Pikachu, 10)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let mut battle_state = create_test_battle(p1_pokemon, p2_pokemon);
+
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        // Act
+        let event_bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert: Snorlax is asleep, with no Toxic counter left to tick.
+        event_bus.print_debug_with_message("Events for test_rest_clears_badly_poisoned_state:");
+        assert_eq!(
+            battle_state.players[0].active_pokemon().unwrap().status,
+            Some(StatusCondition::Sleep(2))
+        );
+        assert!(!event_bus
+            .events()
+            .iter()
+            .any(|e| matches!(e, BattleEvent::PokemonStatusDamage { .. })));
+    }
+
+    #[test]
+    fn test_rest_is_exempt_from_sleep_clause() {
+        // Arrange: the sleep clause is on and Snorlax already put Pikachu to sleep.
+        let max_hp = TestPokemonBuilder::new(Species::Snorlax, 10)
+            .build()
+            .max_hp();
+        let p1_pokemon = TestPokemonBuilder::new(Species::Snorlax, 10)
+            .with_moves(vec![Move::Rest])
+            .with_hp(max_hp / 2)
+            .build();
+        let p2_pokemon = TestPokemonBuilder::new(Species::Pikachu, 10)
+            .with_moves(vec![Move::Splash])
+            .with_status(StatusCondition::Sleep(3))
+            .build();
+        let mut battle_state = create_test_battle(p1_pokemon, p2_pokemon);
+        battle_state.rules.sleep_clause = true;
+        battle_state.opponent_induced_sleep[1] = Some(0);
+
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        // Act
+        let event_bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert: Rest still works, and its sleep isn't recorded against the opponent.
+        event_bus.print_debug_with_message("Events for test_rest_is_exempt_from_sleep_clause:");
+        assert_eq!(
+            battle_state.players[0].active_pokemon().unwrap().status,
+            Some(StatusCondition::Sleep(2))
+        );
+        assert_eq!(
+            battle_state.players[0]
+                .active_pokemon()
+                .unwrap()
+                .current_hp(),
+            max_hp
+        );
+        assert_eq!(battle_state.opponent_induced_sleep[0], None);
+    }
 }
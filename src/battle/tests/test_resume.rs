@@ -0,0 +1,162 @@
+#[cfg(test)]
+mod tests {
+    use crate::battle::engine::resolve_turn;
+    use crate::battle::state::{BattleEvent, BattleRules, BattleState, GameState};
+    use crate::battle::tests::common::{create_test_player, predictable_rng, TestPokemonBuilder};
+    use crate::player::PlayerAction;
+    use crate::species::Species;
+    use pretty_assertions::assert_eq;
+    use schema::Move;
+
+    #[test]
+    fn test_battle_saved_mid_replacement_resumes_exactly() {
+        // Arrange: Turn 7, Player 1's Pikachu has fainted and a replacement is owed.
+        let mut pikachu = TestPokemonBuilder::new(Species::Pikachu, 25)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        pikachu.set_current_hp(0);
+        let charmander = TestPokemonBuilder::new(Species::Charmander, 25)
+            .with_moves(vec![Move::Scratch])
+            .build();
+        let rattata = TestPokemonBuilder::new(Species::Rattata, 25)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let player1 = create_test_player("p1", "Player 1", vec![pikachu, charmander]);
+        let player2 = create_test_player("p2", "Player 2", vec![rattata]);
+        let saved = BattleState::with_state(
+            "resume".to_string(),
+            player1,
+            player2,
+            7,
+            GameState::WaitingForPlayer1Replacement,
+            [None, None],
+        );
+
+        // Act: Round-trip through a save and reload.
+        let json = serde_json::to_string(&saved).unwrap();
+        let mut battle_state: BattleState = serde_json::from_str(&json).unwrap();
+
+        // Assert: It comes back exactly where it left off.
+        assert_eq!(battle_state.turn_number, 7);
+        assert_eq!(
+            battle_state.game_state,
+            GameState::WaitingForPlayer1Replacement
+        );
+        assert_eq!(battle_state.faint_handled, [true, false]);
+
+        // Act: Send in the replacement, then play the next turn.
+        battle_state.action_queue[0] = Some(PlayerAction::SwitchPokemon { team_index: 1 });
+        let replacement_bus = resolve_turn(&mut battle_state, predictable_rng());
+        replacement_bus.print_debug_with_message("Events for resumed replacement:");
+
+        // Assert: The replacement doesn't advance the turn, and the faint isn't reported again.
+        assert_eq!(battle_state.players[0].active_pokemon_index, 1);
+        assert_eq!(battle_state.game_state, GameState::WaitingForActions);
+        assert_eq!(battle_state.turn_number, 7);
+        assert!(!replacement_bus
+            .events()
+            .iter()
+            .any(|e| matches!(e, BattleEvent::PokemonFainted { .. })));
+
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        let turn_bus = resolve_turn(&mut battle_state, predictable_rng());
+        turn_bus.print_debug_with_message("Events for resumed turn 7:");
+
+        assert!(turn_bus
+            .events()
+            .iter()
+            .any(|e| matches!(e, BattleEvent::TurnStarted { turn_number: 7 })));
+        assert_eq!(battle_state.turn_number, 8);
+        assert_eq!(battle_state.game_state, GameState::WaitingForActions);
+    }
+
+    #[test]
+    fn test_start_events_name_both_leading_pokemon() {
+        // Arrange: A reloaded battle where Player 1 has already switched to its second slot.
+        let pikachu = TestPokemonBuilder::new(Species::Pikachu, 25).build();
+        let charmander = TestPokemonBuilder::new(Species::Charmander, 25).build();
+        let rattata = TestPokemonBuilder::new(Species::Rattata, 25).build();
+        let mut player1 = create_test_player("p1", "Player 1", vec![pikachu, charmander]);
+        player1.active_pokemon_index = 1;
+        let player2 = create_test_player("p2", "Player 2", vec![rattata]);
+        let saved = BattleState::with_state(
+            "resume".to_string(),
+            player1,
+            player2,
+            4,
+            GameState::WaitingForActions,
+            [None, None],
+        );
+        let json = serde_json::to_string(&saved).unwrap();
+        let battle_state: BattleState = serde_json::from_str(&json).unwrap();
+
+        // Act
+        let bus = battle_state.emit_start_events();
+
+        // Assert
+        assert_eq!(
+            bus.events(),
+            &[
+                BattleEvent::PokemonSentOut {
+                    player_index: 0,
+                    pokemon: Species::Charmander,
+                },
+                BattleEvent::PokemonSentOut {
+                    player_index: 1,
+                    pokemon: Species::Rattata,
+                },
+            ]
+        );
+        assert_eq!(
+            crate::battle::log::opening_lines(&battle_state),
+            vec![
+                "Player 1 sent out Charmander!".to_string(),
+                "Player 2 sent out Rattata!".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_battle_saved_before_rules_and_bookkeeping_still_loads() {
+        // Arrange: A save from before the ruleset and per-turn bookkeeping were stored.
+        let pikachu = TestPokemonBuilder::new(Species::Pikachu, 25)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let rattata = TestPokemonBuilder::new(Species::Rattata, 25)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let saved = BattleState::with_state(
+            "resume".to_string(),
+            create_test_player("p1", "Player 1", vec![pikachu]),
+            create_test_player("p2", "Player 2", vec![rattata]),
+            3,
+            GameState::WaitingForActions,
+            [None, None],
+        );
+        let mut json = serde_json::to_value(&saved).unwrap();
+        let fields = json.as_object_mut().unwrap();
+        for key in [
+            "rules",
+            "faint_handled",
+            "opponent_induced_sleep",
+            "acted_this_turn",
+            "revealed_moves",
+            "format",
+            "double_action_queue",
+        ] {
+            fields.remove(key);
+        }
+
+        // Act
+        let mut battle_state: BattleState = serde_json::from_value(json).unwrap();
+
+        // Assert: Missing settings fall back to the defaults and the battle plays on.
+        assert_eq!(battle_state.rules, BattleRules::default());
+        assert_eq!(battle_state.faint_handled, [false, false]);
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        resolve_turn(&mut battle_state, predictable_rng());
+        assert_eq!(battle_state.turn_number, 4);
+    }
+}
@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use crate::battle::engine::resolve_turn;
+    use crate::battle::state::{BattleEvent, BattleType, GameState};
+    use crate::battle::tests::common::{create_test_battle, predictable_rng, TestPokemonBuilder};
+    use crate::player::{PlayerAction, PlayerType};
+    use crate::progression::RewardCalculator;
+    use crate::species::Species;
+    use pretty_assertions::assert_eq;
+    use schema::Move;
+
+    #[test]
+    fn test_previewed_rewards_match_awarded_rewards() {
+        // Arrange: A human trainer's Snorlax faces an NPC's last Rattata at 1 HP.
+        let p1_pokemon = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let p2_pokemon = TestPokemonBuilder::new(Species::Rattata, 12)
+            .with_moves(vec![Move::Splash])
+            .with_hp(1)
+            .build();
+        let mut battle_state = create_test_battle(p1_pokemon, p2_pokemon);
+        battle_state.battle_type = BattleType::Trainer;
+        battle_state.players[0].player_type = PlayerType::Human;
+
+        let calculator = RewardCalculator;
+        let previewed_prize =
+            calculator.preview_prize(battle_state.players[1].team.iter().flatten());
+        let previewed_exp = calculator.preview_experience(Species::Rattata, 1).unwrap();
+        assert_eq!(previewed_prize, 12 * 20);
+        assert_eq!(battle_state.players[0].get_ante(), 0);
+
+        // Act
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        let event_bus = resolve_turn(&mut battle_state, predictable_rng());
+        event_bus
+            .print_debug_with_message("Events for test_previewed_rewards_match_awarded_rewards:");
+
+        // Assert
+        assert_eq!(battle_state.game_state, GameState::Player1Win);
+        let awarded_exp = event_bus.events().iter().find_map(|event| match event {
+            BattleEvent::ExperienceGained {
+                pokemon: Species::Snorlax,
+                amount,
+            } => Some(*amount),
+            _ => None,
+        });
+        assert_eq!(awarded_exp, Some(previewed_exp));
+        let awarded_prize = event_bus.events().iter().find_map(|event| match event {
+            BattleEvent::PrizeMoneyAwarded {
+                player_index: 0,
+                amount,
+            } => Some(*amount),
+            _ => None,
+        });
+        assert_eq!(awarded_prize, Some(previewed_prize));
+        assert_eq!(battle_state.players[0].get_ante(), previewed_prize);
+    }
+
+    #[test]
+    fn test_no_prize_outside_trainer_battles() {
+        // Arrange: Same matchup, but as a tournament battle.
+        let p1_pokemon = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let p2_pokemon = TestPokemonBuilder::new(Species::Rattata, 12)
+            .with_moves(vec![Move::Splash])
+            .with_hp(1)
+            .build();
+        let mut battle_state = create_test_battle(p1_pokemon, p2_pokemon);
+        battle_state.players[0].player_type = PlayerType::Human;
+
+        // Act
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        let event_bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert
+        assert_eq!(battle_state.game_state, GameState::Player1Win);
+        assert!(!event_bus
+            .events()
+            .iter()
+            .any(|event| matches!(event, BattleEvent::PrizeMoneyAwarded { .. })));
+        assert_eq!(battle_state.players[0].get_ante(), 0);
+    }
+}
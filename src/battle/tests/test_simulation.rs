@@ -0,0 +1,23 @@
+#[cfg(test)]
+mod tests {
+    use crate::battle::simulation::simulate_random_battles;
+    use crate::teams::create_team_from_template;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_simulate_random_battles_between_prefab_teams() {
+        let team_a = create_team_from_template("demo_venusaur").unwrap();
+        let team_b = create_team_from_template("demo_charizard").unwrap();
+
+        let results = simulate_random_battles(&team_a, &team_b, 100, 42);
+
+        assert_eq!(results.battles, 100);
+        assert_eq!(
+            results.team_a_wins + results.team_b_wins + results.draws + results.unfinished,
+            100
+        );
+        // Six-Pokemon teams can't be swept in a single turn.
+        assert!(results.average_turns() > 1.0);
+        assert!(results.team_a_wins + results.team_b_wins > 0);
+    }
+}
@@ -2,15 +2,16 @@
 mod tests {
     use crate::battle::conditions::{PokemonCondition, PokemonConditionType};
     use crate::battle::engine::{collect_npc_actions, resolve_turn};
-    use crate::battle::state::{BattleEvent, TurnRng};
+    use crate::battle::state::{ActionFailureReason, BattleEvent, BattleState, TurnRng};
     use crate::battle::tests::common::{
         create_test_battle, create_test_player, predictable_rng, TestPokemonBuilder,
     };
-    use crate::player::PlayerAction;
+    use crate::player::{DamageRecord, PlayerAction, StatType};
+    use crate::pokemon::{MoveInstance, PokemonInst};
     use crate::species::Species;
     use pretty_assertions::assert_eq;
     use rstest::rstest;
-    use schema::{Move, PokemonType};
+    use schema::{Move, MoveCategory, PokemonType};
 
     // --- Integration Tests for Multi-Turn and Forced Moves ---
 
@@ -159,6 +160,8 @@ mod tests {
     #[case("succeeds with valid last move", Some(Move::Tackle), true)]
     #[case("fails with no last move", None, false)]
     #[case("fails when copying Mirror Move", Some(Move::MirrorMove), false)]
+    #[case("succeeds copying a status move", Some(Move::Growl), true)]
+    #[case("fails when copying a non-mirrorable move", Some(Move::Counter), false)]
     fn test_mirror_move_outcomes(
         #[case] desc: &str,
         #[case] opponent_last_move: Option<Move>,
@@ -208,6 +211,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_self_destruct_faints_user_even_on_miss() {
+        // Arrange: Snorlax's maxed evasion drops Self-Destruct's accuracy to 33.
+        let p1_pokemon = TestPokemonBuilder::new(Species::Electrode, 50)
+            .with_moves(vec![Move::SelfDestruct])
+            .build();
+        let p2_pokemon = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let mut battle_state = create_test_battle(p1_pokemon, p2_pokemon);
+        battle_state.players[1].set_stat_stage(StatType::Eva, 6);
+        let target_hp = battle_state.players[1]
+            .active_pokemon()
+            .unwrap()
+            .current_hp();
+
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        // Act
+        let event_bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert
+        event_bus
+            .print_debug_with_message("Events for test_self_destruct_faints_user_even_on_miss:");
+        assert!(event_bus.events().iter().any(|e| matches!(
+            e,
+            BattleEvent::MoveMissed {
+                move_used: Move::SelfDestruct,
+                ..
+            }
+        )));
+        assert!(battle_state.players[0]
+            .active_pokemon()
+            .unwrap()
+            .is_fainted());
+        assert_eq!(
+            battle_state.players[1]
+                .active_pokemon()
+                .unwrap()
+                .current_hp(),
+            target_hp
+        );
+    }
+
     #[test]
     fn test_explosion_faints_user_and_deals_damage() {
         // Arrange
@@ -368,8 +416,14 @@ mod tests {
         let mut player = create_test_player("p1", "Player 1", vec![ditto]);
 
         // Test Transformed
-        player.add_condition(PokemonCondition::Transformed { target: charizard });
-        let transformed_types = player.active_pokemon().unwrap().get_current_types(&player);
+        player.add_condition(PokemonCondition::Transformed {
+            target: charizard,
+            original_moves: Default::default(),
+        });
+        let transformed_types = player
+            .active_pokemon()
+            .unwrap()
+            .get_current_types(player.battler(0));
         assert_eq!(
             transformed_types,
             vec![PokemonType::Fire, PokemonType::Flying],
@@ -380,7 +434,10 @@ mod tests {
         player.add_condition(PokemonCondition::Converted {
             pokemon_type: PokemonType::Electric,
         });
-        let converted_types = player.active_pokemon().unwrap().get_current_types(&player);
+        let converted_types = player
+            .active_pokemon()
+            .unwrap()
+            .get_current_types(player.battler(0));
         assert_eq!(
             converted_types,
             vec![PokemonType::Electric],
@@ -388,6 +445,140 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_transform_reverts_on_switch_out() {
+        // Arrange: Ditto transforms into Charizard, then leaves and comes back.
+        let ditto = TestPokemonBuilder::new(Species::Ditto, 50)
+            .with_moves(vec![Move::Transform])
+            .build();
+        let pikachu = TestPokemonBuilder::new(Species::Pikachu, 50)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let charizard = TestPokemonBuilder::new(Species::Charizard, 50)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let player1 = create_test_player("p1", "Player 1", vec![ditto, pikachu]);
+        let player2 = create_test_player("p2", "Player 2", vec![charizard]);
+        let mut battle_state =
+            crate::battle::state::BattleState::new("test".to_string(), player1, player2);
+
+        // --- TURN 1: Transform ---
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        resolve_turn(&mut battle_state, predictable_rng());
+        assert!(battle_state.players[0].has_condition_type(PokemonConditionType::Transformed));
+        let ditto = battle_state.players[0].active_pokemon().unwrap();
+        assert_eq!(
+            ditto.get_current_types(battle_state.players[0].battler(0)),
+            vec![PokemonType::Fire, PokemonType::Flying]
+        );
+
+        // --- TURN 2: Switch out to Pikachu ---
+        battle_state.action_queue[0] = Some(PlayerAction::SwitchPokemon { team_index: 1 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        resolve_turn(&mut battle_state, predictable_rng());
+        assert!(!battle_state.players[0].has_condition_type(PokemonConditionType::Transformed));
+
+        // --- TURN 3: Switch Ditto back in ---
+        battle_state.action_queue[0] = Some(PlayerAction::SwitchPokemon { team_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert: Ditto is back to its own typing, stats, and moves.
+        let player = &battle_state.players[0];
+        let ditto = player.active_pokemon().unwrap();
+        assert_eq!(ditto.species, Species::Ditto);
+        assert!(!player.has_condition_type(PokemonConditionType::Transformed));
+        assert_eq!(
+            ditto.get_current_types(player.battler(0)),
+            vec![PokemonType::Normal]
+        );
+        assert_eq!(
+            crate::battle::stats::effective_speed(ditto, player.battler(0)),
+            ditto.stats.speed,
+            "Speed should come from Ditto's own stats again"
+        );
+        let moves: Vec<Move> = ditto.moves.iter().flatten().map(|m| m.move_).collect();
+        assert_eq!(moves, vec![Move::Transform]);
+    }
+
+    #[test]
+    fn test_transform_copies_target_moves_at_five_pp() {
+        // Arrange: Ditto transforms into a Charizard that knows two moves.
+        let ditto = TestPokemonBuilder::new(Species::Ditto, 50)
+            .with_moves(vec![Move::Transform])
+            .build();
+        let charizard = TestPokemonBuilder::new(Species::Charizard, 50)
+            .with_moves(vec![Move::Splash, Move::Slash])
+            .build();
+        let mut battle_state = create_test_battle(ditto, charizard);
+
+        // Act
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert: The usable-move list shows the copies, each with 5 of 5 PP.
+        let player = &battle_state.players[0];
+        let opponent = battle_state.players[1].active_pokemon();
+        let moves: Vec<(Move, u8, u8)> = player
+            .usable_moves(opponent)
+            .into_iter()
+            .map(|m| (m.move_, m.pp, m.max_pp))
+            .collect();
+        assert_eq!(moves, vec![(Move::Splash, 5, 5), (Move::Slash, 5, 5)]);
+
+        // The saved moveset (with Transform's spent PP) is kept for when it wears off.
+        let transform_pp = MoveInstance::new(Move::Transform).pp - 1;
+        assert!(player.active_pokemon_conditions.values().any(|c| matches!(
+            c,
+            PokemonCondition::Transformed {
+                original_moves: [Some(MoveInstance { move_: Move::Transform, pp }), None, None, None],
+                ..
+            } if *pp == transform_pp
+        )));
+    }
+
+    #[test]
+    fn test_conversion_reverts_on_faint() {
+        // Arrange: a Converted Porygon on 1 HP gets knocked out.
+        let porygon = TestPokemonBuilder::new(Species::Porygon, 50)
+            .with_moves(vec![Move::Splash])
+            .with_hp(1)
+            .build();
+        let pikachu = TestPokemonBuilder::new(Species::Pikachu, 50)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let attacker = TestPokemonBuilder::new(Species::Machamp, 50)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let mut player1 = create_test_player("p1", "Player 1", vec![porygon, pikachu]);
+        player1.add_condition(PokemonCondition::Converted {
+            pokemon_type: PokemonType::Fighting,
+        });
+        let player2 = create_test_player("p2", "Player 2", vec![attacker]);
+        let mut battle_state =
+            crate::battle::state::BattleState::new("test".to_string(), player1, player2);
+
+        // Act
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert: the conversion is gone along with the rest of the volatile state.
+        let player = &battle_state.players[0];
+        let porygon = &player.team[0];
+        assert!(porygon.as_ref().unwrap().is_fainted());
+        assert!(!player.has_condition_type(PokemonConditionType::Converted));
+        assert_eq!(
+            porygon
+                .as_ref()
+                .unwrap()
+                .get_current_types(player.battler(0)),
+            vec![PokemonType::Normal]
+        );
+    }
+
     // --- Integration Tests for Special Conditions ---
 
     #[rstest]
@@ -481,6 +672,61 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case("only the user has a substitute", false)]
+    #[case("both sides have substitutes", true)]
+    fn test_substitute_does_not_block_self_targeting_moves(
+        #[case] desc: &str,
+        #[case] opponent_has_substitute: bool,
+    ) {
+        // Arrange: Snorlax sits behind a substitute at half HP.
+        let mut p1_pokemon = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![Move::SwordsDance, Move::Recover, Move::Fly])
+            .build();
+        let half_hp = p1_pokemon.max_hp() / 2;
+        p1_pokemon.set_hp(half_hp);
+        let p2_pokemon = TestPokemonBuilder::new(Species::Rattata, 5)
+            .with_moves(vec![Move::Splash])
+            .build();
+
+        let mut player1 = create_test_player("p1", "Player 1", vec![p1_pokemon]);
+        player1.add_condition(PokemonCondition::Substitute { hp: 50 });
+        let mut player2 = create_test_player("p2", "Player 2", vec![p2_pokemon]);
+        if opponent_has_substitute {
+            player2.add_condition(PokemonCondition::Substitute { hp: 50 });
+        }
+        let mut battle_state =
+            crate::battle::state::BattleState::new("test".to_string(), player1, player2);
+
+        // Act & Assert: Swords Dance raises Attack.
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        let bus = resolve_turn(&mut battle_state, predictable_rng());
+        bus.print_debug_with_message(&format!("Swords Dance events [{}]:", desc));
+        assert_eq!(battle_state.players[0].get_stat_stage(StatType::Atk), 2);
+
+        // Act & Assert: Recover restores HP.
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 1 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        let bus = resolve_turn(&mut battle_state, predictable_rng());
+        bus.print_debug_with_message(&format!("Recover events [{}]:", desc));
+        assert!(
+            battle_state.players[0]
+                .active_pokemon()
+                .unwrap()
+                .current_hp()
+                > half_hp
+        );
+
+        // Act & Assert: Fly still takes to the air on its first turn.
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 2 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        let bus = resolve_turn(&mut battle_state, predictable_rng());
+        bus.print_debug_with_message(&format!("Fly events [{}]:", desc));
+        assert!(battle_state.players[0].has_condition_type(PokemonConditionType::InAir));
+        assert!(battle_state.players[0].has_condition_type(PokemonConditionType::Substitute));
+    }
+
     #[rstest]
     #[case("succeeds and lowers hp", 100, true)]
     #[case("fails when hp is too low", 25, false)]
@@ -574,4 +820,284 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_bide_release_hits_switched_in_pokemon() {
+        // Arrange: Player 1 has been biding and has stored damage from Player 2's Rattata.
+        let p1_pokemon = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![Move::Bide])
+            .build();
+        let rattata = TestPokemonBuilder::new(Species::Rattata, 50)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let pidgey = TestPokemonBuilder::new(Species::Pidgey, 50)
+            .with_moves(vec![Move::Tackle])
+            .build();
+
+        let mut player1 = create_test_player("p1", "Player 1", vec![p1_pokemon]);
+        player1.add_condition(PokemonCondition::Biding {
+            turns_remaining: 0,
+            damage: 20,
+        });
+        let player2 = create_test_player("p2", "Player 2", vec![rattata, pidgey]);
+
+        let mut battle_state =
+            crate::battle::state::BattleState::new("test".to_string(), player1, player2);
+        let rattata_hp = battle_state.players[1].team[0]
+            .as_ref()
+            .unwrap()
+            .current_hp();
+        let pidgey_hp = battle_state.players[1].team[1]
+            .as_ref()
+            .unwrap()
+            .current_hp();
+
+        // Player 2 switches out the Rattata that dealt the damage before Bide releases.
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::SwitchPokemon { team_index: 1 });
+
+        // Act
+        let event_bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert
+        event_bus
+            .print_debug_with_message("Events for test_bide_release_hits_switched_in_pokemon:");
+        assert_eq!(battle_state.players[1].active_pokemon_index, 1);
+        assert_eq!(
+            battle_state.players[1].team[1]
+                .as_ref()
+                .unwrap()
+                .current_hp(),
+            pidgey_hp.saturating_sub(40),
+            "Bide should release double the stored damage on the switched-in Pokemon"
+        );
+        assert_eq!(
+            battle_state.players[1].team[0]
+                .as_ref()
+                .unwrap()
+                .current_hp(),
+            rattata_hp,
+            "The benched Pokemon that dealt the damage should be untouched"
+        );
+        assert!(event_bus.events().iter().any(|e| matches!(
+            e,
+            BattleEvent::DamageDealt {
+                target: Species::Pidgey,
+                damage: 40,
+                ..
+            }
+        )));
+        assert!(!battle_state.players[0].has_condition_type(PokemonConditionType::Biding));
+    }
+
+    fn bide_release_against(defender: PokemonInst, stored_damage: u16) -> BattleState {
+        let mut player1 = create_test_player(
+            "p1",
+            "Player 1",
+            vec![TestPokemonBuilder::new(Species::Snorlax, 50)
+                .with_moves(vec![Move::Bide])
+                .build()],
+        );
+        player1.add_condition(PokemonCondition::Biding {
+            turns_remaining: 0,
+            damage: stored_damage,
+        });
+        let player2 = create_test_player("p2", "Player 2", vec![defender]);
+        let mut battle_state = BattleState::new("test".to_string(), player1, player2);
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state
+    }
+
+    #[test]
+    fn test_bide_release_ignores_ghost_immunity() {
+        // Arrange: Gastly is immune to Normal moves, but Bide's release is typeless.
+        let gastly = TestPokemonBuilder::new(Species::Gastly, 50)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let mut battle_state = bide_release_against(gastly, 25);
+        let gastly_hp = battle_state.players[1]
+            .active_pokemon()
+            .unwrap()
+            .current_hp();
+
+        // Act
+        let event_bus = resolve_turn(&mut battle_state, predictable_rng());
+        event_bus.print_debug_with_message("Events for test_bide_release_ignores_ghost_immunity:");
+
+        // Assert: Exactly double the stored damage lands on the Ghost.
+        assert_eq!(
+            battle_state.players[1]
+                .active_pokemon()
+                .unwrap()
+                .current_hp(),
+            gastly_hp - 50
+        );
+        assert!(event_bus.events().iter().any(|e| matches!(
+            e,
+            BattleEvent::DamageDealt {
+                target: Species::Gastly,
+                damage: 50,
+                ..
+            }
+        )));
+        assert!(!event_bus
+            .events()
+            .iter()
+            .any(|e| matches!(e, BattleEvent::AttackTypeEffectiveness { .. })));
+    }
+
+    #[test]
+    fn test_bide_release_bypasses_substitute() {
+        // Arrange: The defender is hiding behind a substitute.
+        let rattata = TestPokemonBuilder::new(Species::Rattata, 50)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let mut battle_state = bide_release_against(rattata, 10);
+        battle_state.players[1].add_condition(PokemonCondition::Substitute { hp: 30 });
+        let rattata_hp = battle_state.players[1]
+            .active_pokemon()
+            .unwrap()
+            .current_hp();
+
+        // Act
+        let event_bus = resolve_turn(&mut battle_state, predictable_rng());
+        event_bus.print_debug_with_message("Events for test_bide_release_bypasses_substitute:");
+
+        // Assert: The damage goes to the Pokemon; the substitute is untouched.
+        assert_eq!(
+            battle_state.players[1]
+                .active_pokemon()
+                .unwrap()
+                .current_hp(),
+            rattata_hp - 20
+        );
+        assert!(battle_state.players[1]
+            .active_pokemon_conditions
+            .values()
+            .any(|c| matches!(c, PokemonCondition::Substitute { hp: 30 })));
+    }
+
+    // --- Damage Record and Counter ---
+
+    fn damage_dealt_to(event_bus: &crate::battle::state::EventBus, species: Species) -> u16 {
+        event_bus
+            .events()
+            .iter()
+            .filter_map(|e| match e {
+                BattleEvent::DamageDealt { target, damage, .. } if *target == species => {
+                    Some(*damage)
+                }
+                _ => None,
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_last_damage_taken_updates_per_hit_and_resets_each_turn() {
+        // Arrange
+        let pikachu = TestPokemonBuilder::new(Species::Pikachu, 50)
+            .with_moves(vec![Move::Tackle, Move::Lightning, Move::Splash])
+            .build();
+        let snorlax = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let mut battle_state = create_test_battle(pikachu, snorlax);
+
+        // Act / Assert: each hit overwrites the record with its own move and damage.
+        for (move_index, move_used, category) in [
+            (0, Move::Tackle, MoveCategory::Physical),
+            (1, Move::Lightning, MoveCategory::Special),
+        ] {
+            battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index });
+            battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+            let event_bus = resolve_turn(&mut battle_state, predictable_rng());
+
+            assert_eq!(
+                battle_state.players[1].last_damage_taken,
+                Some(DamageRecord {
+                    move_used,
+                    category,
+                    damage: damage_dealt_to(&event_bus, Species::Snorlax),
+                })
+            );
+            assert_eq!(battle_state.players[0].last_damage_taken, None);
+        }
+
+        // A turn without any hits clears the record.
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 2 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        resolve_turn(&mut battle_state, predictable_rng());
+        assert_eq!(battle_state.players[1].last_damage_taken, None);
+    }
+
+    #[rstest]
+    #[case("physical hit is countered", Move::Tackle, true)]
+    #[case("special hit is not countered", Move::Lightning, false)]
+    fn test_counter_reads_last_damage_category(
+        #[case] desc: &str,
+        #[case] incoming_move: Move,
+        #[case] expect_counter: bool,
+    ) {
+        // Arrange
+        let snorlax = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![Move::Counter])
+            .build();
+        let pikachu = TestPokemonBuilder::new(Species::Pikachu, 50)
+            .with_moves(vec![incoming_move])
+            .build();
+        let mut battle_state = create_test_battle(snorlax, pikachu);
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        // Act
+        let event_bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert
+        event_bus.print_debug_with_message(&format!(
+            "Events for test_counter_reads_last_damage_category [{}]:",
+            desc
+        ));
+        let record = battle_state.players[0]
+            .last_damage_taken
+            .expect("Snorlax should have been hit");
+        assert_eq!(record.move_used, incoming_move, "{}", desc);
+
+        let retaliation = damage_dealt_to(&event_bus, Species::Pikachu);
+        if expect_counter {
+            assert_eq!(retaliation, record.damage * 2, "{}", desc);
+        } else {
+            assert_eq!(retaliation, 0, "{}", desc);
+        }
+    }
+
+    #[test]
+    fn test_counter_fails_without_a_hit_this_turn() {
+        // Arrange: Pikachu only Splashes, so Snorlax has nothing to counter.
+        let snorlax = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![Move::Counter])
+            .build();
+        let pikachu = TestPokemonBuilder::new(Species::Pikachu, 50)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let mut battle_state = create_test_battle(snorlax, pikachu);
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        // Act
+        let event_bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert
+        event_bus
+            .print_debug_with_message("Events for test_counter_fails_without_a_hit_this_turn:");
+        assert!(event_bus.events().iter().any(|e| matches!(
+            e,
+            BattleEvent::ActionFailed {
+                reason: ActionFailureReason::MoveFailedToExecute {
+                    move_used: Move::Counter
+                }
+            }
+        )));
+        assert_eq!(damage_dealt_to(&event_bus, Species::Pikachu), 0);
+    }
 }
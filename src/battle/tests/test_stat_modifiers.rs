@@ -2,12 +2,14 @@
 mod tests {
     use crate::battle::engine::resolve_turn;
     use crate::battle::state::{BattleEvent, TurnRng};
-    use crate::battle::tests::common::{create_test_battle, predictable_rng, TestPokemonBuilder};
-    use crate::player::PlayerAction;
+    use crate::battle::tests::common::{
+        create_test_battle, create_test_player, predictable_rng, TestPokemonBuilder,
+    };
+    use crate::player::{PlayerAction, StageChangeResult};
     use crate::species::Species;
     use pretty_assertions::assert_eq;
     use rstest::rstest;
-    use schema::Move;
+    use schema::{Move, StatType};
 
     /// Helper to run a turn and extract damage dealt to Player 2.
     fn get_damage_from_turn(
@@ -266,4 +268,36 @@ mod tests {
             "Turn 3: Wartortle should now be faster and move first"
         );
     }
+
+    #[rstest]
+    #[case("normal raise", 0, 2, 2, false)]
+    #[case("raise cut short at +6", 5, 2, 6, true)]
+    #[case("won't go any higher", 6, 1, 6, true)]
+    #[case("won't go any lower", -6, -1, -6, true)]
+    fn test_adjust_stat_stage(
+        #[case] desc: &str,
+        #[case] start: i8,
+        #[case] delta: i8,
+        #[case] expected_stage: i8,
+        #[case] expect_clamped: bool,
+    ) {
+        let pokemon = TestPokemonBuilder::new(Species::Pikachu, 25).build();
+        let mut player = create_test_player("p1", "Player 1", vec![pokemon]);
+        player.set_stat_stage(StatType::Atk, start);
+
+        let result = player.adjust_stat_stage(StatType::Atk, delta);
+
+        assert_eq!(
+            result,
+            StageChangeResult {
+                old_stage: start,
+                new_stage: expected_stage,
+                clamped: expect_clamped,
+            },
+            "{}",
+            desc
+        );
+        assert_eq!(result.changed(), start != expected_stage, "{}", desc);
+        assert_eq!(player.get_stat_stage(StatType::Atk), expected_stage);
+    }
 }
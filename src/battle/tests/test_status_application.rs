@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests {
     use crate::battle::action_stack::ActionStack;
+    use crate::battle::commands::PlayerTarget;
     use crate::battle::engine::execute_attack_hit;
     use crate::battle::state::{BattleEvent, EventBus, TurnRng};
     use crate::battle::tests::common::{create_test_battle, TestPokemonBuilder};
@@ -203,8 +204,8 @@ mod tests {
         let mut rng = TurnRng::new_for_test(vec![50, rng_roll, 50, 50, 50, 50, 50, 50, 50, 50, 50]);
         // Act
         execute_attack_hit(
-            0,
-            1,
+            PlayerTarget::from_index(0),
+            PlayerTarget::from_index(1),
             attacker_move,
             0,
             &mut action_stack,
@@ -1,13 +1,17 @@
 #[cfg(test)]
 mod tests {
     use crate::battle::action_stack::ActionStack;
-    use crate::battle::engine::execute_attack_hit;
-    use crate::battle::state::{BattleEvent, EventBus, TurnRng};
-    use crate::battle::tests::common::{create_test_battle, TestPokemonBuilder};
-    use crate::player::StatType;
+    use crate::battle::commands::PlayerTarget;
+    use crate::battle::engine::{execute_attack_hit, resolve_turn};
+    use crate::battle::state::{ActionFailureReason, BattleEvent, BattleState, EventBus, TurnRng};
+    use crate::battle::tests::common::{
+        create_test_battle, create_test_player, predictable_rng, TestPokemonBuilder,
+    };
+    use crate::player::{PlayerAction, StatType};
     use crate::pokemon::StatusCondition;
     use crate::species::Species;
     use pretty_assertions::assert_eq;
+    use rstest::rstest;
     use schema::Move;
 
     #[test]
@@ -27,8 +31,8 @@ mod tests {
 
         // Act
         execute_attack_hit(
-            0,
-            1,
+            PlayerTarget::from_index(0),
+            PlayerTarget::from_index(1),
             Move::SwordsDance,
             0,
             &mut action_stack,
@@ -77,8 +81,8 @@ mod tests {
 
         // Act
         execute_attack_hit(
-            0,
-            1,
+            PlayerTarget::from_index(0),
+            PlayerTarget::from_index(1),
             Move::Harden,
             0,
             &mut action_stack,
@@ -133,8 +137,8 @@ mod tests {
 
         // Act
         execute_attack_hit(
-            0,
-            1,
+            PlayerTarget::from_index(0),
+            PlayerTarget::from_index(1),
             Move::ThunderWave,
             0,
             &mut action_stack,
@@ -166,4 +170,259 @@ mod tests {
             "A PokemonStatusApplied event for Paralysis should have been emitted"
         );
     }
+
+    /// Pikachu uses its only move against a Splashing Charmander; returns the events and Pikachu's remaining PP.
+    fn use_move_on_charmander(
+        move_used: Move,
+        setup: impl FnOnce(&mut BattleState),
+    ) -> (BattleState, EventBus, u8) {
+        let attacker = TestPokemonBuilder::new(Species::Pikachu, 25)
+            .with_moves(vec![move_used])
+            .build();
+        let defender = TestPokemonBuilder::new(Species::Charmander, 25)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let mut battle_state = create_test_battle(attacker, defender);
+        setup(&mut battle_state);
+
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        let bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        let pp = battle_state.players[0].active_pokemon().unwrap().moves[0]
+            .as_ref()
+            .unwrap()
+            .pp;
+        (battle_state, bus, pp)
+    }
+
+    fn max_pp(move_used: Move) -> u8 {
+        TestPokemonBuilder::new(Species::Pikachu, 25)
+            .with_moves(vec![move_used])
+            .build()
+            .moves[0]
+            .as_ref()
+            .unwrap()
+            .pp
+    }
+
+    fn failed_with_no_effect(bus: &EventBus, move_used: Move) -> bool {
+        bus.events().iter().any(|e| {
+            matches!(
+                e,
+                BattleEvent::ActionFailed {
+                    reason: ActionFailureReason::NoEffect { move_used: m }
+                } if *m == move_used
+            )
+        })
+    }
+
+    #[test]
+    fn test_growl_fails_at_minimum_attack_but_uses_pp() {
+        let (battle_state, bus, pp) = use_move_on_charmander(Move::Growl, |state| {
+            state.players[1].set_stat_stage(StatType::Atk, -6);
+        });
+        bus.print_debug_with_message("Events for Growl at -6 Attack:");
+
+        assert!(failed_with_no_effect(&bus, Move::Growl));
+        assert!(!bus
+            .events()
+            .iter()
+            .any(|e| matches!(e, BattleEvent::StatStageChanged { .. })));
+        assert_eq!(battle_state.players[1].get_stat_stage(StatType::Atk), -6);
+        assert_eq!(pp, max_pp(Move::Growl) - 1);
+    }
+
+    #[rstest]
+    #[case("both stats maxed out", 6, true)]
+    #[case("only Special maxed out", 0, false)]
+    fn test_growth_settles_failure_across_both_stat_changes(
+        #[case] desc: &str,
+        #[case] attack_stage: i8,
+        #[case] expect_failure: bool,
+    ) {
+        let (battle_state, bus, _) = use_move_on_charmander(Move::Growth, |state| {
+            state.players[0].set_stat_stage(StatType::SpAtk, 6);
+            state.players[0].set_stat_stage(StatType::Atk, attack_stage);
+        });
+        bus.print_debug_with_message(&format!("Events for Growth with {}:", desc));
+
+        let failures = bus
+            .events()
+            .iter()
+            .filter(|e| {
+                matches!(
+                    e,
+                    BattleEvent::ActionFailed {
+                        reason: ActionFailureReason::NoEffect {
+                            move_used: Move::Growth
+                        }
+                    }
+                )
+            })
+            .count();
+        assert_eq!(failures, usize::from(expect_failure), "{}", desc);
+        assert_eq!(
+            battle_state.players[0].get_stat_stage(StatType::Atk),
+            if expect_failure { 6 } else { 1 },
+            "{}",
+            desc
+        );
+    }
+
+    #[test]
+    fn test_thunder_wave_fails_on_paralyzed_target_but_uses_pp() {
+        let (battle_state, bus, pp) = use_move_on_charmander(Move::ThunderWave, |state| {
+            state.players[1].active_pokemon_mut().unwrap().status =
+                Some(StatusCondition::Paralysis);
+        });
+        bus.print_debug_with_message("Events for Thunder Wave on a paralyzed target:");
+
+        assert!(failed_with_no_effect(&bus, Move::ThunderWave));
+        assert!(!bus
+            .events()
+            .iter()
+            .any(|e| matches!(e, BattleEvent::PokemonStatusApplied { .. })));
+        assert_eq!(
+            battle_state.players[1].active_pokemon().unwrap().status,
+            Some(StatusCondition::Paralysis)
+        );
+        assert_eq!(pp, max_pp(Move::ThunderWave) - 1);
+    }
+
+    #[test]
+    fn test_damaging_move_secondary_effect_does_not_fail_when_no_op() {
+        // Ember can't burn a Pokemon that already has a status, but the hit itself still lands.
+        let (_, bus, _) = use_move_on_charmander(Move::Ember, |state| {
+            state.players[1].active_pokemon_mut().unwrap().status =
+                Some(StatusCondition::Paralysis);
+        });
+
+        assert!(!failed_with_no_effect(&bus, Move::Ember));
+        assert!(bus
+            .events()
+            .iter()
+            .any(|e| matches!(e, BattleEvent::DamageDealt { .. })));
+    }
+
+    #[rstest]
+    #[case("benched Pokemon we put to sleep blocks a second sleep", Some(1), true)]
+    #[case("sleep we didn't inflict doesn't count", None, false)]
+    fn test_sleep_clause_blocks_second_induced_sleep(
+        #[case] desc: &str,
+        #[case] induced_sleep: Option<usize>,
+        #[case] expect_blocked: bool,
+    ) {
+        // Arrange: player 2's benched Charmander is already asleep.
+        let attacker = TestPokemonBuilder::new(Species::Venusaur, 50)
+            .with_moves(vec![Move::SleepPowder])
+            .build();
+        let active = TestPokemonBuilder::new(Species::Pikachu, 50)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let benched = TestPokemonBuilder::new(Species::Charmander, 50)
+            .with_status(StatusCondition::Sleep(3))
+            .build();
+        let player1 = create_test_player("p1", "Player 1", vec![attacker]);
+        let player2 = create_test_player("p2", "Player 2", vec![active, benched]);
+        let mut battle_state = BattleState::new("test".to_string(), player1, player2);
+        battle_state.rules.sleep_clause = true;
+        battle_state.opponent_induced_sleep[1] = induced_sleep;
+
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        // Act
+        let bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert
+        bus.print_debug_with_message(&format!(
+            "Events for test_sleep_clause_blocks_second_induced_sleep [{}]:",
+            desc
+        ));
+        let pikachu_status = battle_state.players[1].active_pokemon().unwrap().status;
+        assert_eq!(
+            failed_with_no_effect(&bus, Move::SleepPowder),
+            expect_blocked,
+            "{}",
+            desc
+        );
+        if expect_blocked {
+            assert_eq!(pikachu_status, None, "{}", desc);
+        } else {
+            assert!(
+                matches!(pikachu_status, Some(StatusCondition::Sleep(_))),
+                "{}",
+                desc
+            );
+            assert_eq!(battle_state.opponent_induced_sleep[1], Some(0), "{}", desc);
+        }
+    }
+
+    #[test]
+    fn test_sleep_clause_forgets_induced_sleep_once_the_pokemon_wakes() {
+        // Arrange: Pikachu is hurt, so Rest will work once it's awake.
+        let attacker = TestPokemonBuilder::new(Species::Venusaur, 50)
+            .with_moves(vec![Move::SleepPowder, Move::Splash])
+            .build();
+        let pikachu = TestPokemonBuilder::new(Species::Pikachu, 50)
+            .with_moves(vec![Move::Splash, Move::Rest])
+            .with_hp(20)
+            .build();
+        let charmander = TestPokemonBuilder::new(Species::Charmander, 50)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let player1 = create_test_player("p1", "Player 1", vec![attacker]);
+        let player2 = create_test_player("p2", "Player 2", vec![pikachu, charmander]);
+        let mut battle_state = BattleState::new("test".to_string(), player1, player2);
+        battle_state.rules.sleep_clause = true;
+        let take_turn = |state: &mut BattleState, p1: PlayerAction, p2: PlayerAction| {
+            state.action_queue[0] = Some(p1);
+            state.action_queue[1] = Some(p2);
+            resolve_turn(state, predictable_rng())
+        };
+        // Venusaur knows Sleep Powder then Splash; Pikachu knows Splash then Rest.
+        let use_move = |move_index| PlayerAction::UseMove { move_index };
+
+        // Act: put Pikachu to sleep and wait for it to wake up.
+        take_turn(&mut battle_state, use_move(0), use_move(0));
+        assert_eq!(battle_state.opponent_induced_sleep[1], Some(0));
+        for _ in 0..5 {
+            if battle_state.players[1]
+                .active_pokemon()
+                .unwrap()
+                .status
+                .is_none()
+            {
+                break;
+            }
+            take_turn(&mut battle_state, use_move(1), use_move(0));
+        }
+        assert_eq!(
+            battle_state.players[1].active_pokemon().unwrap().status,
+            None
+        );
+        assert_eq!(battle_state.opponent_induced_sleep[1], None);
+
+        // Pikachu puts itself back to sleep, then makes way for Charmander.
+        take_turn(&mut battle_state, use_move(1), use_move(1));
+        assert!(matches!(
+            battle_state.players[1].active_pokemon().unwrap().status,
+            Some(StatusCondition::Sleep(_))
+        ));
+        let bus = take_turn(
+            &mut battle_state,
+            use_move(0),
+            PlayerAction::SwitchPokemon { team_index: 1 },
+        );
+
+        // Assert: Rest doesn't count, so Charmander can still be put to sleep.
+        bus.print_debug_with_message("Events for sleep clause after waking and Resting:");
+        assert!(!failed_with_no_effect(&bus, Move::SleepPowder));
+        assert!(matches!(
+            battle_state.players[1].active_pokemon().unwrap().status,
+            Some(StatusCondition::Sleep(_))
+        ));
+        assert_eq!(battle_state.opponent_induced_sleep[1], Some(1));
+    }
 }
@@ -0,0 +1,199 @@
+#[cfg(test)]
+mod tests {
+    use crate::battle::conditions::{PokemonCondition, PokemonConditionType};
+    use crate::battle::engine::resolve_turn;
+    use crate::battle::state::{BattleEvent, BattleState, TurnRng};
+    use crate::battle::tests::common::{create_test_player, predictable_rng, TestPokemonBuilder};
+    use crate::player::PlayerAction;
+    use crate::pokemon::StatusCondition;
+    use crate::species::Species;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use schema::{Move, StatType, TeamCondition};
+
+    const SUBSTITUTE_HP: u8 = 50;
+
+    /// Player 1's Snorlax sits behind a substitute; Player 2's Rattata is unprotected.
+    fn substituted_snorlax_battle(snorlax_move: Move, rattata_move: Move) -> BattleState {
+        let snorlax = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![snorlax_move])
+            .build();
+        let rattata = TestPokemonBuilder::new(Species::Rattata, 50)
+            .with_moves(vec![rattata_move])
+            .build();
+        let mut player1 = create_test_player("p1", "Player 1", vec![snorlax]);
+        player1.add_condition(PokemonCondition::Substitute { hp: SUBSTITUTE_HP });
+        let player2 = create_test_player("p2", "Player 2", vec![rattata]);
+        let mut battle_state = BattleState::new("test".to_string(), player1, player2);
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state
+    }
+
+    fn substitute_hp(battle_state: &BattleState) -> Option<u8> {
+        match battle_state.players[0]
+            .active_pokemon_conditions
+            .get(&PokemonConditionType::Substitute)
+        {
+            Some(PokemonCondition::Substitute { hp }) => Some(*hp),
+            _ => None,
+        }
+    }
+
+    fn snorlax_hp_lost(battle_state: &BattleState) -> u16 {
+        let snorlax = battle_state.players[0].active_pokemon().unwrap();
+        snorlax.max_hp() - snorlax.current_hp()
+    }
+
+    #[test]
+    fn test_opposing_attack_hits_substitute() {
+        let mut battle_state = substituted_snorlax_battle(Move::Splash, Move::Tackle);
+
+        let bus = resolve_turn(&mut battle_state, predictable_rng());
+        bus.print_debug_with_message("Events for opposing Tackle into a substitute:");
+
+        assert!(bus
+            .events()
+            .iter()
+            .any(|e| matches!(e, BattleEvent::SubstituteDamaged { .. })));
+        assert!(substitute_hp(&battle_state).unwrap() < SUBSTITUTE_HP);
+        assert_eq!(snorlax_hp_lost(&battle_state), 0);
+    }
+
+    #[test]
+    fn test_confusion_self_hit_bypasses_substitute() {
+        // Arrange: Every roll is 20, so the confusion check (< 50) makes Snorlax hit itself.
+        let mut battle_state = substituted_snorlax_battle(Move::Tackle, Move::Splash);
+        battle_state.players[0].add_condition(PokemonCondition::Confused { turns_remaining: 2 });
+
+        let bus = resolve_turn(&mut battle_state, TurnRng::new_for_test(vec![20; 100]));
+        bus.print_debug_with_message("Events for confusion self-hit behind a substitute:");
+
+        assert!(!bus
+            .events()
+            .iter()
+            .any(|e| matches!(e, BattleEvent::SubstituteDamaged { .. })));
+        assert_eq!(substitute_hp(&battle_state), Some(SUBSTITUTE_HP));
+        assert!(snorlax_hp_lost(&battle_state) > 0);
+    }
+
+    #[test]
+    fn test_recoil_bypasses_substitute() {
+        let mut battle_state = substituted_snorlax_battle(Move::TakeDown, Move::Splash);
+
+        let bus = resolve_turn(&mut battle_state, predictable_rng());
+        bus.print_debug_with_message("Events for recoil behind a substitute:");
+
+        assert!(bus
+            .events()
+            .iter()
+            .any(|e| matches!(e, BattleEvent::RecoilDamage { .. })));
+        let recoil = bus
+            .events()
+            .iter()
+            .find_map(|e| match e {
+                BattleEvent::DamageDealt {
+                    target: Species::Snorlax,
+                    damage,
+                    ..
+                } => Some(*damage),
+                _ => None,
+            })
+            .expect("Recoil should be dealt to Snorlax itself");
+        assert_eq!(substitute_hp(&battle_state), Some(SUBSTITUTE_HP));
+        assert_eq!(snorlax_hp_lost(&battle_state), recoil);
+    }
+
+    fn rattata_pp(battle_state: &BattleState) -> u8 {
+        battle_state.players[1].active_pokemon().unwrap().moves[0]
+            .as_ref()
+            .unwrap()
+            .pp
+    }
+
+    #[test]
+    fn test_status_effect_blocked_but_damage_hits_substitute() {
+        // Arrange: Every roll is 1, so Shock hits and its 20% paralysis chance would land.
+        let mut battle_state = substituted_snorlax_battle(Move::Splash, Move::Shock);
+        let initial_pp = rattata_pp(&battle_state);
+
+        let bus = resolve_turn(&mut battle_state, TurnRng::new_for_test(vec![1; 100]));
+        bus.print_debug_with_message("Events for Shock into a substitute:");
+
+        assert!(bus
+            .events()
+            .iter()
+            .any(|e| matches!(e, BattleEvent::SubstituteDamaged { .. })));
+        assert!(substitute_hp(&battle_state).unwrap() < SUBSTITUTE_HP);
+        assert_eq!(snorlax_hp_lost(&battle_state), 0);
+        assert_eq!(rattata_pp(&battle_state), initial_pp - 1);
+        assert_eq!(
+            battle_state.players[0].active_pokemon().unwrap().status,
+            None
+        );
+    }
+
+    #[test]
+    fn test_move_can_miss_substitute_and_still_uses_pp() {
+        // Arrange: A roll of 99 misses Fire Blast's 70% accuracy.
+        let mut battle_state = substituted_snorlax_battle(Move::Splash, Move::FireBlast);
+        let initial_pp = rattata_pp(&battle_state);
+
+        let bus = resolve_turn(&mut battle_state, TurnRng::new_for_test(vec![99; 100]));
+        bus.print_debug_with_message("Events for Fire Blast missing a substitute:");
+
+        assert!(bus
+            .events()
+            .iter()
+            .any(|e| matches!(e, BattleEvent::MoveMissed { .. })));
+        assert_eq!(substitute_hp(&battle_state), Some(SUBSTITUTE_HP));
+        assert_eq!(rattata_pp(&battle_state), initial_pp - 1);
+    }
+
+    fn healed(battle_state: &BattleState) -> bool {
+        snorlax_hp_lost(battle_state) == 0
+    }
+
+    fn attack_raised(battle_state: &BattleState) -> bool {
+        battle_state.players[0].get_stat_stage(StatType::Atk) == 2
+    }
+
+    fn sp_def_raised(battle_state: &BattleState) -> bool {
+        battle_state.players[0].get_stat_stage(StatType::SpDef) == 2
+    }
+
+    fn asleep_at_full_hp(battle_state: &BattleState) -> bool {
+        let snorlax = battle_state.players[0].active_pokemon().unwrap();
+        healed(battle_state) && matches!(snorlax.status, Some(StatusCondition::Sleep(_)))
+    }
+
+    fn reflect_up(battle_state: &BattleState) -> bool {
+        battle_state.players[0].has_team_condition(&TeamCondition::Reflect)
+    }
+
+    #[rstest]
+    #[case::recover(Move::Recover, healed as fn(&BattleState) -> bool)]
+    #[case::swords_dance(Move::SwordsDance, attack_raised)]
+    #[case::amnesia(Move::Amnesia, sp_def_raised)]
+    #[case::rest(Move::Rest, asleep_at_full_hp)]
+    #[case::reflect(Move::Reflect, reflect_up)]
+    fn test_self_targeting_effects_work_behind_own_substitute(
+        #[case] self_move: Move,
+        #[case] applied: fn(&BattleState) -> bool,
+    ) {
+        let mut battle_state = substituted_snorlax_battle(self_move, Move::Splash);
+        battle_state.players[0].team[0]
+            .as_mut()
+            .unwrap()
+            .take_damage(60);
+
+        let bus = resolve_turn(&mut battle_state, predictable_rng());
+        bus.print_debug_with_message(&format!(
+            "Events for {:?} behind the user's substitute:",
+            self_move
+        ));
+
+        assert!(applied(&battle_state));
+        assert_eq!(substitute_hp(&battle_state), Some(SUBSTITUTE_HP));
+    }
+}
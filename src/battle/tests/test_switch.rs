@@ -1,12 +1,18 @@
 #[cfg(test)]
 mod tests {
     use crate::battle::action_stack::{ActionStack, BattleAction};
-    use crate::battle::engine::execute_battle_action;
-    use crate::battle::state::{BattleEvent, EventBus, TurnRng};
-    use crate::battle::tests::common::{create_test_player, TestPokemonBuilder};
+    use crate::battle::conditions::{PokemonCondition, PokemonConditionType};
+    use crate::battle::engine::{execute_battle_action, resolve_turn};
+    use crate::battle::state::{
+        ActionFailureReason, BattleEvent, BattleState, EventBus, GameState, TurnRng,
+    };
+    use crate::battle::tests::common::{create_test_player, predictable_rng, TestPokemonBuilder};
+    use crate::player::PlayerAction;
+    use crate::pokemon::StatusCondition;
     use crate::species::Species;
     use pretty_assertions::assert_eq;
-    use schema::Move;
+    use rstest::rstest;
+    use schema::{Move, StatType};
 
     #[test]
     fn test_switch_event_shows_correct_pokemon() {
@@ -174,4 +180,465 @@ mod tests {
             panic!("Expected PokemonSwitched event");
         }
     }
+
+    #[test]
+    fn test_toxic_counter_resets_on_switch_in() {
+        // Arrange: Pikachu is badly poisoned with a built-up Toxic counter.
+        let pokemon1 = TestPokemonBuilder::new(Species::Pikachu, 25)
+            .with_moves(vec![Move::Tackle])
+            .with_status(StatusCondition::Poison(3))
+            .build();
+        let pokemon2 = TestPokemonBuilder::new(Species::Charmander, 25)
+            .with_moves(vec![Move::Scratch])
+            .build();
+        let max_hp = pokemon1.max_hp();
+        let player1 = create_test_player("p1", "Player 1", vec![pokemon1, pokemon2]);
+
+        let defender = TestPokemonBuilder::new(Species::Bulbasaur, 25)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let player2 = create_test_player("p2", "Player 2", vec![defender]);
+
+        let mut battle_state =
+            crate::battle::state::BattleState::new("test".to_string(), player1, player2);
+
+        // Act: Switch Pikachu out, then back in on the next turn.
+        battle_state.action_queue[0] = Some(PlayerAction::SwitchPokemon { team_index: 1 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        resolve_turn(&mut battle_state, predictable_rng());
+
+        battle_state.action_queue[0] = Some(PlayerAction::SwitchPokemon { team_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        let bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert: Pikachu is back to regular poison and takes 1/16 of its max HP.
+        bus.print_debug_with_message("Events for test_toxic_counter_resets_on_switch_in:");
+        assert!(bus.events().iter().any(|e| matches!(
+            e,
+            BattleEvent::PokemonStatusDamage {
+                target: Species::Pikachu,
+                status: StatusCondition::Poison(0),
+                damage,
+                ..
+            } if *damage == max_hp / 16
+        )));
+        assert_eq!(
+            battle_state.players[0].active_pokemon().unwrap().status,
+            Some(StatusCondition::Poison(0))
+        );
+    }
+
+    #[test]
+    fn test_switch_to_out_of_format_slot_is_rejected() {
+        // Arrange: A four-Pokemon team playing a 3v3 format.
+        let team: Vec<_> = [
+            Species::Pikachu,
+            Species::Charmander,
+            Species::Squirtle,
+            Species::Bulbasaur,
+        ]
+        .into_iter()
+        .map(|species| {
+            TestPokemonBuilder::new(species, 25)
+                .with_moves(vec![Move::Tackle])
+                .build()
+        })
+        .collect();
+        let player1 = create_test_player("p1", "Player 1", team);
+        let player2 = create_test_player(
+            "p2",
+            "Player 2",
+            vec![TestPokemonBuilder::new(Species::Rattata, 25)
+                .with_moves(vec![Move::Tackle])
+                .build()],
+        );
+        let mut battle_state = BattleState::new("test".to_string(), player1, player2);
+        battle_state.rules.format_team_size = 3;
+        let player = &battle_state.players[0];
+
+        // Assert
+        assert!(player
+            .validate_action_in_format(
+                &PlayerAction::SwitchPokemon { team_index: 2 },
+                battle_state.rules.format_team_size
+            )
+            .is_ok());
+        assert!(player
+            .validate_action_in_format(
+                &PlayerAction::SwitchPokemon { team_index: 3 },
+                battle_state.rules.format_team_size
+            )
+            .is_err());
+        assert_eq!(
+            player.get_valid_switches_in_format(battle_state.rules.format_team_size),
+            vec![
+                PlayerAction::SwitchPokemon { team_index: 1 },
+                PlayerAction::SwitchPokemon { team_index: 2 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_queued_out_of_format_switch_fails_in_engine() {
+        // Arrange: A four-Pokemon team playing a 3v3 format.
+        let team: Vec<_> = [
+            Species::Pikachu,
+            Species::Charmander,
+            Species::Squirtle,
+            Species::Bulbasaur,
+        ]
+        .into_iter()
+        .map(|species| {
+            TestPokemonBuilder::new(species, 25)
+                .with_moves(vec![Move::Tackle])
+                .build()
+        })
+        .collect();
+        let player1 = create_test_player("p1", "Player 1", team);
+        let player2 = create_test_player(
+            "p2",
+            "Player 2",
+            vec![TestPokemonBuilder::new(Species::Rattata, 25)
+                .with_moves(vec![Move::Tackle])
+                .build()],
+        );
+        let mut battle_state = BattleState::new("test".to_string(), player1, player2);
+        battle_state.rules.format_team_size = 3;
+
+        // Act: Queue a switch to the fourth slot directly, skipping validation.
+        battle_state.action_queue[0] = Some(PlayerAction::SwitchPokemon { team_index: 3 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        let bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert: The engine refuses and Pikachu stays in.
+        assert!(bus.events().iter().any(|event| matches!(
+            event,
+            BattleEvent::ActionFailed {
+                reason: ActionFailureReason::NotInFormat {
+                    pokemon: Species::Bulbasaur
+                }
+            }
+        )));
+        assert_eq!(battle_state.players[0].active_pokemon_index, 0);
+    }
+
+    #[test]
+    fn test_one_on_one_ends_when_the_single_pokemon_faints() {
+        // Arrange: Player 1 brings a backup, but the format only allows one Pokemon.
+        let lead = TestPokemonBuilder::new(Species::Pikachu, 25)
+            .with_moves(vec![Move::Splash])
+            .with_hp(1)
+            .build();
+        let bench = TestPokemonBuilder::new(Species::Charmander, 25)
+            .with_moves(vec![Move::Scratch])
+            .build();
+        let player1 = create_test_player("p1", "Player 1", vec![lead, bench]);
+        let player2 = create_test_player(
+            "p2",
+            "Player 2",
+            vec![TestPokemonBuilder::new(Species::Snorlax, 50)
+                .with_moves(vec![Move::Tackle])
+                .build()],
+        );
+        let mut battle_state = BattleState::new("test".to_string(), player1, player2);
+        battle_state.rules.format_team_size = 1;
+
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        // Act
+        let event_bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert: No replacement is requested; Player 2 wins outright.
+        event_bus.print_debug_with_message(
+            "Events for test_one_on_one_ends_when_the_single_pokemon_faints:",
+        );
+        assert_eq!(battle_state.game_state, GameState::Player2Win);
+        assert!(event_bus
+            .events()
+            .iter()
+            .any(|e| matches!(e, BattleEvent::BattleEnded { winner: Some(1) })));
+    }
+
+    #[rstest]
+    #[case::natural_cure_on(true, None)]
+    #[case::natural_cure_off(false, Some(StatusCondition::Poison(0)))]
+    fn test_natural_cure_on_switch(
+        #[case] natural_cure_on_switch: bool,
+        #[case] expected_status: Option<StatusCondition>,
+    ) {
+        // Arrange: Pikachu is poisoned and about to switch out.
+        let pokemon1 = TestPokemonBuilder::new(Species::Pikachu, 25)
+            .with_moves(vec![Move::Tackle])
+            .with_status(StatusCondition::Poison(0))
+            .build();
+        let pokemon2 = TestPokemonBuilder::new(Species::Charmander, 25)
+            .with_moves(vec![Move::Scratch])
+            .build();
+        let player1 = create_test_player("p1", "Player 1", vec![pokemon1, pokemon2]);
+        let player2 = create_test_player(
+            "p2",
+            "Player 2",
+            vec![TestPokemonBuilder::new(Species::Bulbasaur, 25)
+                .with_moves(vec![Move::Splash])
+                .build()],
+        );
+        let mut battle_state = BattleState::new("test".to_string(), player1, player2);
+        battle_state.rules.natural_cure_on_switch = natural_cure_on_switch;
+
+        // Act
+        battle_state.action_queue[0] = Some(PlayerAction::SwitchPokemon { team_index: 1 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        let bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert: Pikachu sits on the bench with or without its poison.
+        bus.print_debug_with_message("Events for test_natural_cure_on_switch:");
+        assert_eq!(
+            battle_state.players[0].team[0].as_ref().unwrap().status,
+            expected_status
+        );
+    }
+
+    fn two_pokemon_battle() -> BattleState {
+        let player1 = create_test_player(
+            "p1",
+            "Player 1",
+            vec![
+                TestPokemonBuilder::new(Species::Pikachu, 25)
+                    .with_moves(vec![Move::Tackle])
+                    .build(),
+                TestPokemonBuilder::new(Species::Charmander, 25)
+                    .with_moves(vec![Move::Scratch])
+                    .build(),
+            ],
+        );
+        let player2 = create_test_player(
+            "p2",
+            "Player 2",
+            vec![TestPokemonBuilder::new(Species::Rattata, 25)
+                .with_moves(vec![Move::Tackle])
+                .build()],
+        );
+        BattleState::new("test".to_string(), player1, player2)
+    }
+
+    #[test]
+    fn test_switch_to_active_pokemon_is_rejected() {
+        // Arrange: Pikachu is active and carries some state a switch would wipe.
+        let mut battle_state = two_pokemon_battle();
+        battle_state.players[0].add_condition(PokemonCondition::Confused { turns_remaining: 3 });
+        battle_state.players[0].set_stat_stage(StatType::Atk, 2);
+
+        // Assert: Validation refuses the switch up front.
+        assert!(battle_state.players[0]
+            .validate_action(&PlayerAction::SwitchPokemon { team_index: 0 })
+            .is_err());
+
+        // Act: Queue it anyway and resolve the turn.
+        battle_state.action_queue[0] = Some(PlayerAction::SwitchPokemon { team_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        let bus = resolve_turn(&mut battle_state, predictable_rng());
+        bus.print_debug_with_message("Events for test_switch_to_active_pokemon_is_rejected:");
+
+        // Assert: The engine also refuses, and Pikachu keeps its conditions and stages.
+        assert!(bus.events().iter().any(|event| matches!(
+            event,
+            BattleEvent::ActionFailed {
+                reason: ActionFailureReason::AlreadyInBattle {
+                    pokemon: Species::Pikachu
+                }
+            }
+        )));
+        assert!(!bus
+            .events()
+            .iter()
+            .any(|event| matches!(event, BattleEvent::PokemonSwitched { .. })));
+        let player = &battle_state.players[0];
+        assert_eq!(player.active_pokemon_index, 0);
+        assert!(player.has_condition_type(PokemonConditionType::Confused));
+        assert_eq!(player.get_stat_stage(StatType::Atk), 2);
+    }
+
+    #[test]
+    fn test_replacement_to_active_slot_is_rejected() {
+        // Arrange: Pikachu has fainted and Player 1 must send in a replacement.
+        let mut battle_state = two_pokemon_battle();
+        battle_state.players[0].team[0]
+            .as_mut()
+            .unwrap()
+            .set_current_hp(0);
+        battle_state.game_state = GameState::WaitingForPlayer1Replacement;
+
+        // Assert: Validation refuses the fainted active slot but accepts the backup.
+        let player = &battle_state.players[0];
+        assert!(player
+            .validate_action(&PlayerAction::SwitchPokemon { team_index: 0 })
+            .is_err());
+        assert!(player
+            .validate_action(&PlayerAction::SwitchPokemon { team_index: 1 })
+            .is_ok());
+
+        // Act: Queue the active slot anyway and resolve the replacement phase.
+        battle_state.action_queue[0] = Some(PlayerAction::SwitchPokemon { team_index: 0 });
+        let bus = resolve_turn(&mut battle_state, TurnRng::new_for_test(vec![]));
+        bus.print_debug_with_message("Events for test_replacement_to_active_slot_is_rejected:");
+
+        // Assert: No switch happens and the fainted Pokemon stays in the slot.
+        assert!(bus.events().iter().any(|event| matches!(
+            event,
+            BattleEvent::ActionFailed {
+                reason: ActionFailureReason::PokemonFainted
+            }
+        )));
+        assert!(!bus
+            .events()
+            .iter()
+            .any(|event| matches!(event, BattleEvent::PokemonSwitched { .. })));
+        assert_eq!(battle_state.players[0].active_pokemon_index, 0);
+    }
+
+    #[test]
+    fn test_voluntary_switch_waits_out_opponent_replacement() {
+        // Arrange: Player 2's Rattata just fainted, and Player 1 picks a switch while
+        // Player 2 is choosing a replacement.
+        let player1 = create_test_player(
+            "p1",
+            "Player 1",
+            vec![
+                TestPokemonBuilder::new(Species::Pikachu, 25)
+                    .with_moves(vec![Move::Tackle])
+                    .build(),
+                TestPokemonBuilder::new(Species::Charmander, 25)
+                    .with_moves(vec![Move::Scratch])
+                    .build(),
+            ],
+        );
+        let player2 = create_test_player(
+            "p2",
+            "Player 2",
+            vec![
+                TestPokemonBuilder::new(Species::Rattata, 25)
+                    .with_moves(vec![Move::Tackle])
+                    .build(),
+                TestPokemonBuilder::new(Species::Pidgey, 25)
+                    .with_moves(vec![Move::Tackle])
+                    .build(),
+            ],
+        );
+        let mut battle_state = BattleState::new("test".to_string(), player1, player2);
+        battle_state.players[1].team[0]
+            .as_mut()
+            .unwrap()
+            .set_current_hp(0);
+        battle_state.game_state = GameState::WaitingForPlayer2Replacement;
+
+        battle_state.action_queue[0] = Some(PlayerAction::SwitchPokemon { team_index: 1 });
+        battle_state.action_queue[1] = Some(PlayerAction::SwitchPokemon { team_index: 1 });
+
+        // Act: Resolve the replacement phase.
+        let bus = resolve_turn(&mut battle_state, TurnRng::new_for_test(vec![]));
+        bus.print_debug_with_message("Events for replacement phase:");
+
+        // Assert: Only the replacement happened; Player 1's switch is held for next turn.
+        assert_eq!(battle_state.players[1].active_pokemon_index, 1);
+        assert_eq!(battle_state.players[0].active_pokemon_index, 0);
+        assert_eq!(battle_state.game_state, GameState::WaitingForActions);
+        assert_eq!(
+            battle_state.action_queue[0],
+            Some(PlayerAction::SwitchPokemon { team_index: 1 })
+        );
+        assert_eq!(battle_state.action_queue[1], None);
+
+        // Act: Player 2 attacks with the replacement on the following turn.
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        let bus = resolve_turn(&mut battle_state, predictable_rng());
+        bus.print_debug_with_message("Events for turn after replacement:");
+
+        // Assert: Player 1's switch resolves first and Pidgey's Tackle hits Charmander.
+        assert_eq!(battle_state.players[0].active_pokemon_index, 1);
+        assert_eq!(battle_state.players[1].active_pokemon_index, 1);
+        assert!(bus.events().iter().any(|event| matches!(
+            event,
+            BattleEvent::PokemonSwitched {
+                player_index: 0,
+                new_pokemon: Species::Charmander,
+                ..
+            }
+        )));
+        let charmander = battle_state.players[0].team[1].as_ref().unwrap();
+        assert!(charmander.current_hp() < charmander.max_hp());
+    }
+
+    fn six_pokemon_battle() -> BattleState {
+        let team = [
+            Species::Pikachu,
+            Species::Charmander,
+            Species::Squirtle,
+            Species::Bulbasaur,
+            Species::Rattata,
+            Species::Pidgey,
+        ]
+        .into_iter()
+        .map(|species| {
+            TestPokemonBuilder::new(species, 25)
+                .with_moves(vec![Move::Tackle])
+                .build()
+        })
+        .collect();
+        let player1 = create_test_player("p1", "Player 1", team);
+        let player2 = create_test_player(
+            "p2",
+            "Player 2",
+            vec![TestPokemonBuilder::new(Species::Rattata, 25)
+                .with_moves(vec![Move::Tackle])
+                .build()],
+        );
+        BattleState::new("test".to_string(), player1, player2)
+    }
+
+    #[rstest]
+    #[case(PokemonCondition::Trapped { turns_remaining: 2 })]
+    #[case(PokemonCondition::SwitchLocked { turns_remaining: 2 })]
+    fn test_legal_switch_targets_empty_when_blocked(#[case] condition: PokemonCondition) {
+        let mut battle_state = six_pokemon_battle();
+        assert_eq!(
+            battle_state.players[0].legal_switch_targets(&battle_state),
+            vec![1, 2, 3, 4, 5]
+        );
+
+        battle_state.players[0].add_condition(condition);
+
+        assert!(battle_state.players[0]
+            .legal_switch_targets(&battle_state)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_legal_switch_targets_respect_format_size() {
+        let mut battle_state = six_pokemon_battle();
+        battle_state.rules.format_team_size = 3;
+
+        assert_eq!(
+            battle_state.players[0].legal_switch_targets(&battle_state),
+            vec![1, 2]
+        );
+    }
+
+    #[test]
+    fn test_legal_switch_targets_exclude_fainted() {
+        let mut battle_state = six_pokemon_battle();
+        battle_state.players[0].team[2]
+            .as_mut()
+            .unwrap()
+            .set_current_hp(0);
+        battle_state.players[0].team[4]
+            .as_mut()
+            .unwrap()
+            .set_current_hp(0);
+
+        assert_eq!(
+            battle_state.players[0].legal_switch_targets(&battle_state),
+            vec![1, 3, 5]
+        );
+    }
 }
@@ -0,0 +1,205 @@
+#[cfg(test)]
+mod tests {
+    use crate::battle::calculators::calculate_action_prevention;
+    use crate::battle::commands::PlayerTarget;
+    use crate::battle::engine::resolve_turn;
+    use crate::battle::state::{ActionFailureReason, BattleEvent, GenQuirks, TurnRng};
+    use crate::battle::tests::common::{create_test_battle, predictable_rng, TestPokemonBuilder};
+    use crate::player::PlayerAction;
+    use crate::pokemon::StatusCondition;
+    use crate::species::Species;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use schema::Move;
+
+    #[rstest]
+    #[case("flagged move thaws the target", Move::Bubblehammer, true)]
+    #[case("damaging Fire move thaws the target", Move::Ember, true)]
+    #[case("ordinary move leaves the target frozen", Move::Tackle, false)]
+    fn test_move_thaws_frozen_target(
+        #[case] desc: &str,
+        #[case] move_used: Move,
+        #[case] expect_thaw: bool,
+    ) {
+        // Arrange
+        let attacker = TestPokemonBuilder::new(Species::Squirtle, 10)
+            .with_moves(vec![move_used])
+            .build();
+        let defender = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![Move::Splash])
+            .with_status(StatusCondition::Freeze)
+            .build();
+        let mut battle_state = create_test_battle(attacker, defender);
+
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        // Act
+        let event_bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert
+        event_bus.print_debug_with_message(&format!(
+            "Events for test_move_thaws_frozen_target [{}]:",
+            desc
+        ));
+        let thawed = event_bus.events().iter().any(|e| {
+            matches!(
+                e,
+                BattleEvent::PokemonStatusRemoved {
+                    target: Species::Snorlax,
+                    status: StatusCondition::Freeze,
+                }
+            )
+        });
+        let final_status = battle_state.players[1].active_pokemon().unwrap().status;
+
+        assert_eq!(thawed, expect_thaw, "Thaw event mismatch for {}", desc);
+        if expect_thaw {
+            assert_eq!(final_status, None);
+        } else {
+            assert_eq!(final_status, Some(StatusCondition::Freeze));
+        }
+    }
+
+    #[rstest]
+    #[case("thaws_user move lets a frozen user act", Move::BlazeKick, true)]
+    #[case("unflagged move leaves the user frozen", Move::Ember, false)]
+    fn test_thaws_user_move_lets_frozen_user_act(
+        #[case] desc: &str,
+        #[case] move_used: Move,
+        #[case] expect_action: bool,
+    ) {
+        // Arrange
+        let attacker = TestPokemonBuilder::new(Species::Charmander, 25)
+            .with_moves(vec![move_used])
+            .with_status(StatusCondition::Freeze)
+            .build();
+        let defender = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let mut battle_state = create_test_battle(attacker, defender);
+
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        // Act
+        let event_bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert
+        event_bus.print_debug_with_message(&format!(
+            "Events for test_thaws_user_move_lets_frozen_user_act [{}]:",
+            desc
+        ));
+        let used_move = event_bus.events().iter().any(|e| {
+            matches!(e, BattleEvent::MoveUsed { player_index: 0, move_used: m, .. } if *m == move_used)
+        });
+        let stayed_frozen = event_bus.events().iter().any(|e| {
+            matches!(
+                e,
+                BattleEvent::ActionFailed {
+                    reason: ActionFailureReason::IsFrozen { .. }
+                }
+            )
+        });
+        let final_status = battle_state.players[0].active_pokemon().unwrap().status;
+
+        assert_eq!(used_move, expect_action);
+        assert_eq!(stayed_frozen, !expect_action);
+        if expect_action {
+            assert_eq!(final_status, None);
+        } else {
+            assert_eq!(final_status, Some(StatusCondition::Freeze));
+        }
+    }
+
+    /// Count how many of the 100 possible RNG rolls let a frozen Tackle user thaw out.
+    fn count_self_thaws(gen_quirks: GenQuirks) -> usize {
+        let attacker = TestPokemonBuilder::new(Species::Pikachu, 25)
+            .with_moves(vec![Move::Tackle])
+            .with_status(StatusCondition::Freeze)
+            .build();
+        let defender = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let mut battle_state = create_test_battle(attacker, defender);
+        battle_state.rules.gen_quirks = gen_quirks;
+
+        (1..=100)
+            .filter(|&roll| {
+                let mut rng = TurnRng::new_for_test(vec![roll]);
+                let (failure, _) = calculate_action_prevention(
+                    PlayerTarget::from_index(0),
+                    &battle_state,
+                    &mut rng,
+                    Move::Tackle,
+                );
+                failure.is_none()
+            })
+            .count()
+    }
+
+    #[test]
+    fn test_authentic_freeze_never_self_thaws() {
+        assert_eq!(GenQuirks::default(), GenQuirks::Authentic);
+        assert_eq!(count_self_thaws(GenQuirks::Authentic), 0);
+
+        // No roll is even drawn: an empty RNG would panic if one were.
+        let frozen = TestPokemonBuilder::new(Species::Pikachu, 25)
+            .with_moves(vec![Move::Tackle])
+            .with_status(StatusCondition::Freeze)
+            .build();
+        let other = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let battle_state = create_test_battle(frozen, other);
+        let (failure, _) = calculate_action_prevention(
+            PlayerTarget::from_index(0),
+            &battle_state,
+            &mut TurnRng::new_for_test(vec![]),
+            Move::Tackle,
+        );
+        assert!(matches!(
+            failure,
+            Some(ActionFailureReason::IsFrozen { .. })
+        ));
+    }
+
+    #[test]
+    fn test_quality_of_life_freeze_thaws_about_a_quarter_of_the_time() {
+        // Rolls 1-24 out of 1-100 thaw.
+        assert_eq!(count_self_thaws(GenQuirks::QualityOfLife), 24);
+    }
+
+    #[test]
+    fn test_haze_thaws_frozen_target() {
+        // Arrange
+        let attacker = TestPokemonBuilder::new(Species::Koffing, 30)
+            .with_moves(vec![Move::Haze])
+            .build();
+        let defender = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![Move::Splash])
+            .with_status(StatusCondition::Freeze)
+            .build();
+        let mut battle_state = create_test_battle(attacker, defender);
+
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        // Act
+        let event_bus = resolve_turn(&mut battle_state, predictable_rng());
+
+        // Assert
+        event_bus.print_debug_with_message("Events for test_haze_thaws_frozen_target:");
+        assert!(event_bus.events().iter().any(|e| matches!(
+            e,
+            BattleEvent::PokemonStatusRemoved {
+                target: Species::Snorlax,
+                status: StatusCondition::Freeze,
+            }
+        )));
+        assert_eq!(
+            battle_state.players[1].active_pokemon().unwrap().status,
+            None
+        );
+    }
+}
@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod tests {
+    use crate::battle::engine::resolve_turn;
+    use crate::battle::state::{
+        BattleEvent, BattleState, BattleType, GameState, TimeoutResolution,
+    };
+    use crate::battle::tests::common::{create_test_battle, predictable_rng, TestPokemonBuilder};
+    use crate::player::{PlayerAction, PlayerType};
+    use crate::progression::RewardCalculator;
+    use crate::species::Species;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use schema::Move;
+
+    /// Two Snorlax that only Splash; Player 2's starts at half HP.
+    fn stalled_battle(max_turns: u32, timeout_resolution: TimeoutResolution) -> BattleState {
+        let p1_pokemon = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![Move::Splash])
+            .build();
+        let mut p2_pokemon = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![Move::Splash])
+            .build();
+        p2_pokemon.set_hp_percent(50.0);
+        let mut battle_state = create_test_battle(p1_pokemon, p2_pokemon);
+        battle_state.rules.max_turns = Some(max_turns);
+        battle_state.rules.timeout_resolution = timeout_resolution;
+        battle_state
+    }
+
+    fn play_turn(battle_state: &mut BattleState) -> Vec<BattleEvent> {
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        let bus = resolve_turn(battle_state, predictable_rng());
+        bus.print_debug_with_message("Events for turn limit turn:");
+        bus.events().to_vec()
+    }
+
+    #[rstest]
+    #[case(
+        "HP share decides the winner",
+        TimeoutResolution::HpPercentage,
+        GameState::Player1Win,
+        Some(0)
+    )]
+    #[case("draw policy", TimeoutResolution::Draw, GameState::Draw, None)]
+    fn test_battle_is_decided_at_the_turn_cap(
+        #[case] desc: &str,
+        #[case] timeout_resolution: TimeoutResolution,
+        #[case] expected_state: GameState,
+        #[case] expected_winner: Option<usize>,
+    ) {
+        // Arrange
+        let mut battle_state = stalled_battle(3, timeout_resolution);
+
+        // Act: The first two turns play out normally.
+        for _ in 0..2 {
+            let events = play_turn(&mut battle_state);
+            assert!(
+                !events
+                    .iter()
+                    .any(|e| matches!(e, BattleEvent::TurnLimitReached { .. })),
+                "{}",
+                desc
+            );
+            assert_eq!(battle_state.game_state, GameState::WaitingForActions);
+        }
+        let events = play_turn(&mut battle_state);
+
+        // Assert: The third turn hits the cap and settles the battle.
+        assert!(events
+            .iter()
+            .any(|e| matches!(e, BattleEvent::TurnLimitReached { turn_number: 3 })));
+        assert!(events.iter().any(|e| matches!(
+            e,
+            BattleEvent::BattleEnded { winner } if *winner == expected_winner
+        )));
+        assert_eq!(battle_state.game_state, expected_state, "{}", desc);
+    }
+
+    #[test]
+    fn test_timeout_win_pays_out_prize_money() {
+        // Arrange: A human trainer against an NPC, as in a knockout win.
+        let mut battle_state = stalled_battle(1, TimeoutResolution::HpPercentage);
+        battle_state.battle_type = BattleType::Trainer;
+        battle_state.players[0].player_type = PlayerType::Human;
+        let prize = RewardCalculator.preview_prize(battle_state.players[1].team.iter().flatten());
+
+        // Act
+        let events = play_turn(&mut battle_state);
+
+        // Assert
+        assert_eq!(battle_state.game_state, GameState::Player1Win);
+        assert!(events.iter().any(|e| matches!(
+            e,
+            BattleEvent::PrizeMoneyAwarded {
+                player_index: 0,
+                amount,
+            } if *amount == prize
+        )));
+        assert_eq!(battle_state.players[0].get_ante(), prize);
+    }
+
+    #[test]
+    fn test_equal_hp_shares_draw_at_the_turn_cap() {
+        // Arrange: Both sides at full HP.
+        let mut battle_state = stalled_battle(1, TimeoutResolution::HpPercentage);
+        battle_state.players[1].team[0]
+            .as_mut()
+            .unwrap()
+            .set_hp_percent(100.0);
+
+        // Act
+        play_turn(&mut battle_state);
+
+        // Assert
+        assert_eq!(battle_state.game_state, GameState::Draw);
+    }
+
+    #[test]
+    fn test_no_turn_cap_when_unset() {
+        let mut battle_state = stalled_battle(1, TimeoutResolution::Draw);
+        battle_state.rules.max_turns = None;
+
+        for _ in 0..5 {
+            play_turn(&mut battle_state);
+        }
+
+        assert_eq!(battle_state.game_state, GameState::WaitingForActions);
+    }
+}
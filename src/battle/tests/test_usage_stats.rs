@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod tests {
+    use crate::battle::engine::resolve_turn;
+    use crate::battle::state::TurnRng;
+    use crate::battle::tests::common::{create_test_battle, predictable_rng, TestPokemonBuilder};
+    use crate::battle::usage_stats::{BattleStats, MoveUsage};
+    use crate::player::PlayerAction;
+    use crate::species::Species;
+    use pretty_assertions::assert_eq;
+    use schema::Move;
+
+    #[test]
+    fn test_battle_stats_counts_move_usage_over_scripted_battle() {
+        // Arrange: bulky mirror match so nobody faints over three turns.
+        let p1_pokemon = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![Move::Tackle])
+            .build();
+        let p2_pokemon = TestPokemonBuilder::new(Species::Snorlax, 50)
+            .with_moves(vec![Move::Swift])
+            .build();
+        let mut battle_state = create_test_battle(p1_pokemon, p2_pokemon);
+        let mut stats = BattleStats::new();
+
+        // Turn 1: ordinary hits. Turn 2: low rolls force critical hits.
+        // Turn 3: high rolls make Tackle (90% accuracy) miss while Swift (no accuracy check) still hits.
+        let turn_rngs = vec![
+            predictable_rng(),
+            TurnRng::new_for_test(vec![1; 30]),
+            TurnRng::new_for_test(vec![100; 30]),
+        ];
+
+        // Act
+        for rng in turn_rngs {
+            battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+            battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+            let event_bus = resolve_turn(&mut battle_state, rng);
+            event_bus.print_debug_with_message("Events for usage stats turn:");
+            stats.record_turn(&event_bus);
+        }
+
+        // Assert
+        assert_eq!(
+            stats.usage(Move::Tackle),
+            MoveUsage {
+                used: 3,
+                hit: 2,
+                missed: 1,
+                critical_hits: 1,
+            }
+        );
+        assert_eq!(
+            stats.usage(Move::Swift),
+            MoveUsage {
+                used: 3,
+                hit: 3,
+                missed: 0,
+                critical_hits: 1,
+            }
+        );
+        assert_eq!(stats.usage(Move::Splash), MoveUsage::default());
+    }
+
+    #[test]
+    fn test_battle_stats_merge_sums_tallies() {
+        let mut first = BattleStats::new();
+        let mut second = BattleStats::new();
+        let used = crate::battle::state::BattleEvent::MoveUsed {
+            player_index: 0,
+            pokemon: Species::Pikachu,
+            move_used: Move::Tackle,
+        };
+        first.record_event(&used);
+        second.record_event(&used);
+        second.record_event(&used);
+
+        first.merge(&second);
+
+        assert_eq!(first.usage(Move::Tackle).used, 3);
+    }
+}
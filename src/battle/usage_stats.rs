@@ -0,0 +1,71 @@
+use crate::battle::state::{BattleEvent, EventBus};
+use schema::Move;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Per-move tallies gathered by `BattleStats`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MoveUsage {
+    pub used: u32,
+    pub hit: u32,
+    pub missed: u32,
+    pub critical_hits: u32,
+}
+
+/// Optional accumulator of move usage across a battle, for offline balance analysis.
+///
+/// Nothing in the engine feeds this automatically; callers record each turn's
+/// `EventBus` after `resolve_turn`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BattleStats {
+    moves: HashMap<Move, MoveUsage>,
+}
+
+impl BattleStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record every event from a resolved turn.
+    pub fn record_turn(&mut self, event_bus: &EventBus) {
+        for event in event_bus.events() {
+            self.record_event(event);
+        }
+    }
+
+    /// Record a single event. Events unrelated to move usage are ignored.
+    pub fn record_event(&mut self, event: &BattleEvent) {
+        match event {
+            BattleEvent::MoveUsed { move_used, .. } => self.entry(*move_used).used += 1,
+            BattleEvent::MoveHit { move_used, .. } => self.entry(*move_used).hit += 1,
+            BattleEvent::MoveMissed { move_used, .. } => self.entry(*move_used).missed += 1,
+            BattleEvent::CriticalHit { move_used, .. } => self.entry(*move_used).critical_hits += 1,
+            _ => {}
+        }
+    }
+
+    /// Tallies for a move, or all zeroes if it never appeared.
+    pub fn usage(&self, move_: Move) -> MoveUsage {
+        self.moves.get(&move_).copied().unwrap_or_default()
+    }
+
+    /// All moves that appeared in the battle with their tallies.
+    pub fn moves(&self) -> &HashMap<Move, MoveUsage> {
+        &self.moves
+    }
+
+    /// Fold another battle's tallies into this one.
+    pub fn merge(&mut self, other: &BattleStats) {
+        for (move_, usage) in &other.moves {
+            let entry = self.entry(*move_);
+            entry.used += usage.used;
+            entry.hit += usage.hit;
+            entry.missed += usage.missed;
+            entry.critical_hits += usage.critical_hits;
+        }
+    }
+
+    fn entry(&mut self, move_: Move) -> &mut MoveUsage {
+        self.moves.entry(move_).or_default()
+    }
+}
@@ -46,6 +46,8 @@ pub enum BattleStateError {
     InvalidPlayerIndex(usize),
     /// Battle state is in an inconsistent or corrupted state
     InconsistentState(String),
+    /// A team has no Pokemon able to battle
+    NoUsablePokemon,
 }
 
 /// Errors related to player actions
@@ -104,6 +106,7 @@ impl fmt::Display for BattleStateError {
             BattleStateError::InconsistentState(details) => {
                 write!(f, "Inconsistent battle state: {}", details)
             }
+            BattleStateError::NoUsablePokemon => write!(f, "Team has no Pokemon able to battle"),
         }
     }
 }
@@ -48,7 +48,10 @@ pub use schema::{
 // --- From this crate's modules (`src/`) ---
 
 // Core battle engine functions and state.
-pub use battle::engine::{collect_npc_actions, ready_for_turn_resolution, resolve_turn};
+pub use battle::engine::{
+    advance_battle, collect_npc_actions, ready_for_turn_resolution, resolve_turn, AdvanceOutcome,
+    AdvanceResult,
+};
 pub use battle::state::{BattleEvent, BattleState, GameState};
 
 // Core runtime types for a battle.
@@ -1,9 +1,8 @@
 use std::io::{self, Write};
 
-use pokemon_adventure::battle::engine::{
-    collect_npc_actions, ready_for_turn_resolution, resolve_turn,
-};
-use pokemon_adventure::battle::state::{BattleState, EventBus, GameState, TurnRng};
+use pokemon_adventure::battle::engine::{advance_battle, AdvanceOutcome};
+use pokemon_adventure::battle::log::format_events;
+use pokemon_adventure::battle::state::{BattleEvent, BattleState, GameState, TurnRng};
 use pokemon_adventure::move_data::get_move_data;
 use pokemon_adventure::player::{PlayerAction, PlayerType};
 use pokemon_adventure::teams;
@@ -39,15 +38,8 @@ fn main() {
     );
 
     println!("\n💥 A wild trainer challenges you to a battle! 💥");
-    println!(
-        "You sent out {}!",
-        battle_state.players[0].active_pokemon().unwrap().name
-    );
-    println!(
-        "{} sends out {}!",
-        battle_state.players[1].player_name,
-        battle_state.players[1].active_pokemon().unwrap().name
-    );
+    let start_events = battle_state.emit_start_events();
+    print_turn_events(start_events.events(), &battle_state);
 
     // --- Main Game Loop ---
     run_game_loop(&mut battle_state);
@@ -61,43 +53,28 @@ fn main() {
 /// Runs the main interactive game loop until the battle concludes.
 fn run_game_loop(battle_state: &mut BattleState) {
     loop {
-        // Check for terminal states first.
-        if matches!(
-            battle_state.game_state,
-            GameState::Player1Win | GameState::Player2Win | GameState::Draw
-        ) {
+        // Let the engine run every turn it can without the human's input.
+        let result = advance_battle(battle_state, TurnRng::new_random);
+        print_turn_events(&result.events, battle_state);
+
+        if result.outcome == AdvanceOutcome::BattleOver {
             break;
         }
 
         // Handle forced player actions, like switching after a faint.
-        if let GameState::WaitingForPlayer1Replacement = battle_state.game_state {
+        let switch_only = matches!(
+            battle_state.game_state,
+            GameState::WaitingForPlayer1Replacement | GameState::WaitingForBothReplacements
+        );
+        if switch_only {
             println!("\nYour Pokémon fainted! You must switch.");
             display_team_status(&battle_state.players[0]);
-            let action = get_player_action(battle_state, true); // `true` forces switch-only actions
-            battle_state.action_queue[0] = Some(action);
-        }
-
-        // Standard turn flow.
-        if ready_for_turn_resolution(battle_state) {
-            // Both players have queued actions, resolve the turn.
-            let rng = TurnRng::new_random();
-            let event_bus = resolve_turn(battle_state, rng);
-            print_turn_events(&event_bus, battle_state);
-        } else if battle_state.action_queue[0].is_none() {
-            // It's the human player's turn to act.
+        } else {
             // Display the entire battle state using our new Display trait.
             display_battle_status(battle_state);
-            let action = get_player_action(battle_state, false);
-            battle_state.action_queue[0] = Some(action);
-        }
-
-        // Let the AI act if its slot is empty.
-        if battle_state.action_queue[1].is_none() {
-            let npc_actions = collect_npc_actions(battle_state);
-            for (player_index, action) in npc_actions {
-                battle_state.action_queue[player_index] = Some(action);
-            }
         }
+        let action = get_player_action(battle_state, switch_only);
+        battle_state.action_queue[0] = Some(action);
     }
 }
 
@@ -213,7 +190,10 @@ fn get_player_action(battle_state: &BattleState, switch_only: bool) -> PlayerAct
                         if index > 0 && index <= 6 {
                             let team_index = index - 1; // Convert to 0-based index
                             let action = PlayerAction::SwitchPokemon { team_index };
-                            if let Err(msg) = battle_state.players[0].validate_action(&action) {
+                            if let Err(msg) = battle_state.players[0].validate_action_in_format(
+                                &action,
+                                battle_state.rules.format_team_size,
+                            ) {
                                 println!("Invalid switch: {}", msg);
                             } else {
                                 return action;
@@ -358,12 +338,13 @@ fn display_benched_pokemon_details(index: usize, player: &BattlePlayer) {
     }
 }
 
-/// Prints formatted events from the event bus.
-fn print_turn_events(event_bus: &EventBus, battle_state: &BattleState) {
+/// Prints formatted battle events.
+fn print_turn_events(events: &[BattleEvent], battle_state: &BattleState) {
+    if events.is_empty() {
+        return;
+    }
     println!();
-    for event in event_bus.events() {
-        if let Some(formatted_event) = event.format(battle_state) {
-            println!("{}", formatted_event);
-        }
+    for line in format_events(events, battle_state) {
+        println!("{}", line);
     }
 }
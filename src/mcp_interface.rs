@@ -3,12 +3,334 @@
 //! This module contains all the display, command handling, and interaction functions
 //! that were originally in main.rs, made available as library functions.
 
+use crate::battle::action_stack::ActionStack;
 use crate::battle::engine::{collect_npc_actions, ready_for_turn_resolution, resolve_turn};
-use crate::battle::state::{BattleState, GameState, TurnRng};
+use crate::battle::log::opening_lines;
+use crate::battle::state::{BattleEvent, BattleState, EventBus, GameState, TurnRng};
+use crate::errors::ActionError;
 use crate::move_data::get_move_data;
-use crate::player::{PlayerAction, PlayerType};
+use crate::player::{EffectsSummary, PlayerAction, PlayerType};
+use crate::pokemon::PokemonInst;
 use crate::teams;
-use crate::{BattlePlayer, Move, Species};
+use crate::{BattlePlayer, Move, PokemonType, Species};
+use serde::{Deserialize, Serialize};
+
+// --- Typed Response DTOs ---
+// Serde-serializable mirrors of the text responses below, so MCP clients can
+// generate bindings instead of scraping strings.
+
+/// A single battle event, carrying both its raw enum tag and its human-readable text.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FormattedEvent {
+    pub kind: String,
+    pub text: Option<String>,
+}
+
+/// A compact view of a single Pokémon.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PokemonSummary {
+    pub name: String,
+    pub species: Species,
+    pub level: u8,
+    pub current_hp: u16,
+    pub max_hp: u16,
+    pub status: Option<String>,
+    pub is_fainted: bool,
+}
+
+/// The battle as seen by the human player (player 1): their whole team,
+/// but only the opponent's active Pokémon and how many they have left.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RedactedBattleState {
+    pub turn_number: u32,
+    pub game_state: String,
+    pub active_pokemon: Option<PokemonSummary>,
+    pub team: Vec<PokemonSummary>,
+    pub opponent_name: String,
+    pub opponent_active_pokemon: Option<PokemonSummary>,
+    pub opponent_remaining: usize,
+    pub effects: EffectsSummary,
+    pub opponent_effects: EffectsSummary,
+}
+
+/// Everything a client needs after submitting an action.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TurnResult {
+    pub events: Vec<FormattedEvent>,
+    pub state: RedactedBattleState,
+    pub game_over: bool,
+    pub winner: Option<usize>,
+}
+
+/// A move the player may select this turn.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MoveOption {
+    pub move_index: usize,
+    pub name: String,
+    pub move_type: PokemonType,
+    pub pp: u8,
+    pub max_pp: u8,
+    /// Descriptions of the move's chance-based effects, e.g. "Has a 30% chance to burn the target."
+    #[serde(default)]
+    pub effects: Vec<String>,
+}
+
+/// A benched Pokémon the player may switch to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SwitchOption {
+    pub team_index: usize,
+    pub name: String,
+    pub current_hp: u16,
+    pub max_hp: u16,
+}
+
+/// The actions currently available to the human player.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActionOptions {
+    pub moves: Vec<MoveOption>,
+    pub switches: Vec<SwitchOption>,
+    pub can_forfeit: bool,
+}
+
+/// What each player has committed to this turn and the order they are expected to act in.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TurnPreview {
+    pub p0_action: Option<PlayerAction>,
+    pub p1_action: Option<PlayerAction>,
+    /// Player indices in predicted execution order.
+    pub predicted_order: Vec<usize>,
+    /// True when priority and speed are exactly tied, so a roll decides who goes first.
+    pub rng_decided: bool,
+}
+
+/// Stable, machine-readable reason an action was turned away before reaching the engine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RejectionCode {
+    /// The chosen move has no PP left while another move is still usable.
+    OutOfPp,
+    /// The chosen move is disabled or cooling down.
+    MoveDisabled,
+    /// The active Pokémon is trapped or switch-locked.
+    Trapped,
+    /// The battle is not accepting this kind of action right now.
+    WrongState,
+    /// The switch target can't be sent in.
+    IllegalSwitch,
+    /// The active Pokémon doesn't know a move by that name.
+    UnknownMove,
+}
+
+impl RejectionCode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RejectionCode::OutOfPp => "OUT_OF_PP",
+            RejectionCode::MoveDisabled => "MOVE_DISABLED",
+            RejectionCode::Trapped => "TRAPPED",
+            RejectionCode::WrongState => "WRONG_STATE",
+            RejectionCode::IllegalSwitch => "ILLEGAL_SWITCH",
+            RejectionCode::UnknownMove => "UNKNOWN_MOVE",
+        }
+    }
+}
+
+impl std::fmt::Display for RejectionCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Why an action was rejected: a code for clients to branch on and a message for people.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionRejection {
+    pub code: RejectionCode,
+    pub message: String,
+}
+
+impl ActionRejection {
+    pub fn new(code: RejectionCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ActionRejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] {}", self.code, self.message)
+    }
+}
+
+impl From<ActionError> for ActionRejection {
+    fn from(err: ActionError) -> Self {
+        let code = match err {
+            ActionError::InvalidMoveIndex(_) => RejectionCode::UnknownMove,
+            ActionError::InvalidPokemonIndex(_) => RejectionCode::IllegalSwitch,
+            ActionError::InvalidAction(_) => RejectionCode::WrongState,
+        };
+        ActionRejection::new(code, err.to_string())
+    }
+}
+
+impl PokemonSummary {
+    pub fn from_pokemon(pokemon: &PokemonInst) -> Self {
+        Self {
+            name: pokemon.name.clone(),
+            species: pokemon.species,
+            level: pokemon.level,
+            current_hp: pokemon.current_hp(),
+            max_hp: pokemon.max_hp(),
+            status: pokemon.status.map(|status| status.to_string()),
+            is_fainted: pokemon.is_fainted(),
+        }
+    }
+}
+
+/// Pairs an event's variant name with its formatted text.
+pub fn format_event(event: &BattleEvent, battle_state: &BattleState) -> FormattedEvent {
+    // Serde tags each variant by name: a plain string for unit variants, otherwise
+    // an object with the name as its single key.
+    let kind = match serde_json::to_value(event) {
+        Ok(serde_json::Value::String(name)) => name,
+        Ok(serde_json::Value::Object(fields)) => fields.keys().next().cloned().unwrap_or_default(),
+        _ => String::new(),
+    };
+
+    FormattedEvent {
+        kind,
+        text: event.format(battle_state),
+    }
+}
+
+/// Builds the player-facing view of the battle state.
+pub fn redacted_battle_state(battle_state: &BattleState) -> RedactedBattleState {
+    let player = &battle_state.players[0];
+    let opponent = &battle_state.players[1];
+
+    RedactedBattleState {
+        turn_number: battle_state.turn_number,
+        game_state: battle_state.game_state.to_string(),
+        active_pokemon: player.active_pokemon().map(PokemonSummary::from_pokemon),
+        team: player
+            .team
+            .iter()
+            .flatten()
+            .map(PokemonSummary::from_pokemon)
+            .collect(),
+        opponent_name: opponent.player_name.clone(),
+        opponent_active_pokemon: opponent.active_pokemon().map(PokemonSummary::from_pokemon),
+        opponent_remaining: opponent
+            .team
+            .iter()
+            .flatten()
+            .filter(|pokemon| !pokemon.is_fainted())
+            .count(),
+        effects: player.active_effects_summary(),
+        opponent_effects: opponent.active_effects_summary(),
+    }
+}
+
+/// Packages a resolved turn's events together with the resulting state.
+pub fn build_turn_result(battle_state: &BattleState, event_bus: &EventBus) -> TurnResult {
+    let winner = battle_state.winner().flatten();
+
+    TurnResult {
+        events: event_bus
+            .events()
+            .iter()
+            .map(|event| format_event(event, battle_state))
+            .collect(),
+        state: redacted_battle_state(battle_state),
+        game_over: is_battle_over(battle_state),
+        winner,
+    }
+}
+
+/// Reports the queued actions and their predicted order without resolving the turn.
+pub fn preview_turn(battle_state: &BattleState) -> TurnPreview {
+    let (order, rng_decided) = ActionStack::predicted_action_order(battle_state);
+
+    TurnPreview {
+        p0_action: battle_state.action_queue[0].clone(),
+        p1_action: battle_state.action_queue[1].clone(),
+        predicted_order: order
+            .into_iter()
+            .map(|(player_index, _)| player_index)
+            .collect(),
+        rng_decided,
+    }
+}
+
+/// Lists the moves and switches currently available to the human player.
+pub fn get_action_options(battle_state: &BattleState) -> ActionOptions {
+    let player = &battle_state.players[0];
+    let game_over = is_battle_over(battle_state);
+
+    // During a forced replacement, only switching is allowed.
+    let moves = if game_over || needs_forced_replacement(battle_state) {
+        Vec::new()
+    } else {
+        player
+            .get_valid_moves()
+            .into_iter()
+            .filter_map(|action| match action {
+                PlayerAction::UseMove { move_index } => {
+                    // With nothing usable left, the one choice on offer is Struggle.
+                    let (move_data, pp, max_pp) = if player.must_struggle() {
+                        (get_move_data(Move::Struggle).ok()?, 0, 0)
+                    } else {
+                        let move_instance = player.active_pokemon()?.moves[move_index].as_ref()?;
+                        (
+                            get_move_data(move_instance.move_).ok()?,
+                            move_instance.pp,
+                            player.move_max_pp(move_instance),
+                        )
+                    };
+                    let effects = move_data
+                        .secondary_effects_summary()
+                        .into_iter()
+                        .filter(|(_, chance)| chance.is_some())
+                        .map(|(effect, _)| effect.to_string())
+                        .collect();
+                    Some(MoveOption {
+                        move_index,
+                        name: move_data.name,
+                        move_type: move_data.move_type,
+                        pp,
+                        max_pp,
+                        effects,
+                    })
+                }
+                _ => None,
+            })
+            .collect()
+    };
+
+    let switches = if game_over {
+        Vec::new()
+    } else {
+        player
+            .legal_switch_targets(battle_state)
+            .into_iter()
+            .filter_map(|team_index| {
+                let pokemon = player.team[team_index].as_ref()?;
+                Some(SwitchOption {
+                    team_index,
+                    name: pokemon.name.clone(),
+                    current_hp: pokemon.current_hp(),
+                    max_hp: pokemon.max_hp(),
+                })
+            })
+            .collect()
+    };
+
+    ActionOptions {
+        moves,
+        switches,
+        can_forfeit: !game_over,
+    }
+}
 
 /// Returns formatted text displaying available demo teams
 pub fn get_available_teams_display() -> String {
@@ -61,17 +383,9 @@ pub fn create_battle(team_choice: usize) -> Result<(BattleState, String), String
     let battle_state = BattleState::new("mcp_battle".to_string(), human_player, npc_player);
 
     let intro_text = format!(
-        "🔥 Welcome to the Pokémon Adventure Battle Engine! 🔥\n\nYou chose the {}!\n\n💥 A wild trainer challenges you to a battle! 💥\nYou sent out {}!\n{} sends out {}!",
+        "🔥 Welcome to the Pokémon Adventure Battle Engine! 🔥\n\nYou chose the {}!\n\n💥 A wild trainer challenges you to a battle! 💥\n{}",
         player_team_info.name,
-        battle_state.players[0]
-            .active_pokemon()
-            .map(|p| p.name.as_str())
-            .unwrap_or("Unknown"),
-        battle_state.players[1].player_name,
-        battle_state.players[1]
-            .active_pokemon()
-            .map(|p| p.name.as_str())
-            .unwrap_or("Unknown")
+        opening_lines(&battle_state).join("\n")
     );
 
     Ok((battle_state, intro_text))
@@ -210,59 +524,146 @@ pub fn handle_lookup_pokemon_command(species_name: &str) -> String {
     }
 }
 
-/// Executes a move action and returns the battle events as formatted text
-pub fn execute_move_action(
-    battle_state: &mut BattleState,
-    move_name: &str,
-) -> Result<String, String> {
+/// Checks whether the human player may submit `action` right now, explaining why not
+/// if they can't. Anything that passes is handed to the engine as-is.
+pub fn check_player_action(
+    battle_state: &BattleState,
+    action: &PlayerAction,
+) -> Result<(), ActionRejection> {
+    if is_battle_over(battle_state) {
+        return Err(ActionRejection::new(
+            RejectionCode::WrongState,
+            "The battle is already over.",
+        ));
+    }
+    let is_switch = matches!(action, PlayerAction::SwitchPokemon { .. });
+    match battle_state.game_state {
+        GameState::WaitingForPlayer1Replacement | GameState::WaitingForBothReplacements
+            if !is_switch && !matches!(action, PlayerAction::Forfeit) =>
+        {
+            return Err(ActionRejection::new(
+                RejectionCode::WrongState,
+                "Your Pokémon fainted! You must switch to a new Pokémon.",
+            ));
+        }
+        GameState::WaitingForPlayer2Replacement => {
+            return Err(ActionRejection::new(
+                RejectionCode::WrongState,
+                "Waiting for the opponent to send out a new Pokémon.",
+            ));
+        }
+        _ => {}
+    }
+
     let player = &battle_state.players[0];
-    if let Some(active_pokemon) = player.active_pokemon() {
-        for (i, move_slot) in active_pokemon.moves.iter().enumerate() {
-            if let Some(move_instance) = move_slot {
-                if let Ok(move_data) = get_move_data(move_instance.move_) {
-                    if move_data.name.eq_ignore_ascii_case(move_name) {
-                        let action = PlayerAction::UseMove { move_index: i };
-                        return execute_player_action(battle_state, action);
-                    }
+    match action {
+        PlayerAction::UseMove { move_index } => {
+            let move_instance = player
+                .active_pokemon()
+                .and_then(|pokemon| pokemon.moves.get(*move_index))
+                .and_then(|slot| slot.as_ref());
+            // With nothing usable left, the engine turns any move choice into Struggle.
+            if let Some(move_instance) = move_instance.filter(|_| !player.must_struggle()) {
+                if player.is_move_blocked(move_instance.move_) {
+                    return Err(ActionRejection::new(
+                        RejectionCode::MoveDisabled,
+                        "This move is currently disabled.",
+                    ));
+                }
+                if move_instance.pp == 0 {
+                    return Err(ActionRejection::new(
+                        RejectionCode::OutOfPp,
+                        "This move has no PP left.",
+                    ));
                 }
             }
         }
+        PlayerAction::SwitchPokemon { .. } if player.is_switch_blocked() => {
+            return Err(ActionRejection::new(
+                RejectionCode::Trapped,
+                "The Pokémon is trapped and cannot switch out!",
+            ));
+        }
+        _ => {}
     }
-    Err(format!(
-        "'{}' is not a valid move for your active Pokémon.",
-        move_name
-    ))
+
+    player
+        .validate_action_in_format(action, battle_state.rules.format_team_size)
+        .map_err(|message| {
+            let code = if is_switch {
+                RejectionCode::IllegalSwitch
+            } else {
+                RejectionCode::WrongState
+            };
+            ActionRejection::new(code, message)
+        })
+}
+
+/// Executes a move action and returns the battle events as formatted text
+pub fn execute_move_action(
+    battle_state: &mut BattleState,
+    move_name: &str,
+) -> Result<String, ActionRejection> {
+    let player = &battle_state.players[0];
+    // Struggle is offered under its own name, and any move slot stands in for it.
+    let is_struggle = player.must_struggle()
+        && get_move_data(Move::Struggle)
+            .is_ok_and(|move_data| move_data.name.eq_ignore_ascii_case(move_name));
+    let move_index = if is_struggle {
+        Some(0)
+    } else {
+        player.active_pokemon().and_then(|active_pokemon| {
+            active_pokemon.moves.iter().position(|move_slot| {
+                move_slot
+                    .as_ref()
+                    .and_then(|move_instance| get_move_data(move_instance.move_).ok())
+                    .is_some_and(|move_data| move_data.name.eq_ignore_ascii_case(move_name))
+            })
+        })
+    };
+    let Some(move_index) = move_index else {
+        return Err(ActionRejection::new(
+            RejectionCode::UnknownMove,
+            format!(
+                "'{}' is not a valid move for your active Pokémon.",
+                move_name
+            ),
+        ));
+    };
+
+    let action = PlayerAction::UseMove { move_index };
+    check_player_action(battle_state, &action)?;
+    Ok(execute_player_action(battle_state, action))
 }
 
 /// Executes a switch action and returns the battle events as formatted text
 pub fn execute_switch_action(
     battle_state: &mut BattleState,
     pokemon_number: usize,
-) -> Result<String, String> {
+) -> Result<String, ActionRejection> {
     if pokemon_number == 0 || pokemon_number > 6 {
-        return Err("Invalid Pokemon number. Use 1-6.".to_string());
-    }
-
-    let team_index = pokemon_number - 1;
-    let action = PlayerAction::SwitchPokemon { team_index };
-
-    if let Err(msg) = battle_state.players[0].validate_action(&action) {
-        return Err(format!("Invalid switch: {}", msg));
+        return Err(ActionRejection::new(
+            RejectionCode::IllegalSwitch,
+            "Invalid Pokemon number. Use 1-6.",
+        ));
     }
 
-    execute_player_action(battle_state, action)
+    let action = PlayerAction::SwitchPokemon {
+        team_index: pokemon_number - 1,
+    };
+    check_player_action(battle_state, &action)?;
+    Ok(execute_player_action(battle_state, action))
 }
 
 /// Executes a forfeit action
-pub fn execute_forfeit_action(battle_state: &mut BattleState) -> Result<String, String> {
-    execute_player_action(battle_state, PlayerAction::Forfeit)
+pub fn execute_forfeit_action(battle_state: &mut BattleState) -> Result<String, ActionRejection> {
+    check_player_action(battle_state, &PlayerAction::Forfeit)?;
+    Ok(execute_player_action(battle_state, PlayerAction::Forfeit))
 }
 
-/// Internal helper to execute a player action and return formatted events
-fn execute_player_action(
-    battle_state: &mut BattleState,
-    action: PlayerAction,
-) -> Result<String, String> {
+/// Executes a player action (letting the AI respond and resolving the turn if ready)
+/// and returns the typed result.
+pub fn resolve_player_action(battle_state: &mut BattleState, action: PlayerAction) -> TurnResult {
     // Set the player action
     battle_state.action_queue[0] = Some(action);
 
@@ -275,16 +676,25 @@ fn execute_player_action(
     }
 
     // Resolve the turn if both players have actions
-    let mut output = String::new();
-    if ready_for_turn_resolution(battle_state) {
+    let event_bus = if ready_for_turn_resolution(battle_state) {
         let rng = TurnRng::new_random();
-        let event_bus = resolve_turn(battle_state, rng);
+        resolve_turn(battle_state, rng)
+    } else {
+        EventBus::new()
+    };
 
-        // Format turn events
-        for event in event_bus.events() {
-            if let Some(formatted_event) = event.format(battle_state) {
-                output.push_str(&format!("{}\n", formatted_event));
-            }
+    build_turn_result(battle_state, &event_bus)
+}
+
+/// Internal helper to execute a player action and return formatted events
+fn execute_player_action(battle_state: &mut BattleState, action: PlayerAction) -> String {
+    let turn_result = resolve_player_action(battle_state, action);
+
+    // Format turn events
+    let mut output = String::new();
+    for event in &turn_result.events {
+        if let Some(formatted_event) = &event.text {
+            output.push_str(&format!("{}\n", formatted_event));
         }
     }
 
@@ -305,15 +715,12 @@ fn execute_player_action(
         _ => {}
     }
 
-    Ok(output)
+    output
 }
 
 /// Checks if the battle is over
 pub fn is_battle_over(battle_state: &BattleState) -> bool {
-    matches!(
-        battle_state.game_state,
-        GameState::Player1Win | GameState::Player2Win | GameState::Draw
-    )
+    battle_state.is_over()
 }
 
 /// Checks if the player needs to make a forced replacement
@@ -345,3 +752,273 @@ pub fn get_battle_status_summary(battle_state: &BattleState) -> String {
     output.push_str(&display_battle_status(battle_state));
     output
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::conditions::PokemonCondition;
+    use serde_json::json;
+
+    #[test]
+    fn test_action_options_json_shape() {
+        let options = ActionOptions {
+            moves: vec![MoveOption {
+                move_index: 0,
+                name: "Thunderbolt".to_string(),
+                move_type: PokemonType::Electric,
+                pp: 15,
+                max_pp: 15,
+                effects: vec!["Has a 10% chance to paralyze the target.".to_string()],
+            }],
+            switches: vec![SwitchOption {
+                team_index: 1,
+                name: "Snorlax".to_string(),
+                current_hp: 100,
+                max_hp: 200,
+            }],
+            can_forfeit: true,
+        };
+
+        assert_eq!(
+            serde_json::to_value(&options).unwrap(),
+            json!({
+                "moves": [{
+                    "move_index": 0,
+                    "name": "Thunderbolt",
+                    "move_type": "Electric",
+                    "pp": 15,
+                    "max_pp": 15,
+                    "effects": ["Has a 10% chance to paralyze the target."]
+                }],
+                "switches": [{
+                    "team_index": 1,
+                    "name": "Snorlax",
+                    "current_hp": 100,
+                    "max_hp": 200
+                }],
+                "can_forfeit": true
+            })
+        );
+    }
+
+    #[test]
+    fn test_turn_result_json_shape() {
+        let (battle_state, _) = create_battle(1).expect("Demo battle should be created");
+        let mut event_bus = EventBus::new();
+        event_bus.push(BattleEvent::TurnStarted { turn_number: 1 });
+        event_bus.push(BattleEvent::TurnEnded);
+
+        let turn_result = build_turn_result(&battle_state, &event_bus);
+        let value = serde_json::to_value(&turn_result).unwrap();
+
+        assert_eq!(value["events"][0]["kind"], json!("TurnStarted"));
+        assert_eq!(value["events"][1]["kind"], json!("TurnEnded"));
+        assert!(value["events"][0]["text"].is_string());
+        assert_eq!(value["game_over"], json!(false));
+        assert_eq!(value["winner"], json!(null));
+        assert_eq!(value["state"]["turn_number"], json!(1));
+        assert_eq!(value["state"]["team"].as_array().unwrap().len(), 6);
+        assert_eq!(value["state"]["opponent_remaining"], json!(6));
+
+        let active = &value["state"]["active_pokemon"];
+        for key in [
+            "name",
+            "species",
+            "level",
+            "current_hp",
+            "max_hp",
+            "status",
+            "is_fainted",
+        ] {
+            assert!(active.get(key).is_some(), "Missing key '{}'", key);
+        }
+    }
+
+    #[test]
+    fn test_get_action_options_for_new_battle() {
+        let (battle_state, _) = create_battle(1).expect("Demo battle should be created");
+        let options = get_action_options(&battle_state);
+
+        assert!(!options.moves.is_empty());
+        assert_eq!(options.switches.len(), 5);
+        assert!(options.can_forfeit);
+    }
+
+    #[test]
+    fn test_preview_turn_reports_queued_actions_and_order() {
+        let (mut battle_state, _) = create_battle(1).expect("Demo battle should be created");
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::SwitchPokemon { team_index: 1 });
+
+        let preview = preview_turn(&battle_state);
+
+        assert_eq!(
+            preview.p0_action,
+            Some(PlayerAction::UseMove { move_index: 0 })
+        );
+        assert_eq!(
+            preview.p1_action,
+            Some(PlayerAction::SwitchPokemon { team_index: 1 })
+        );
+        // Switching always goes before a move, so the order is not left to chance.
+        assert_eq!(preview.predicted_order, vec![1, 0]);
+        assert!(!preview.rng_decided);
+
+        // Mirror the same move on identical Pokemon to force an exact speed tie.
+        battle_state.players[1] = battle_state.players[0].clone();
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+
+        let tied_preview = preview_turn(&battle_state);
+        assert_eq!(tied_preview.predicted_order.len(), 2);
+        assert!(tied_preview.rng_decided);
+    }
+
+    fn first_move_name(battle_state: &BattleState) -> String {
+        let move_ = battle_state.players[0].active_pokemon().unwrap().moves[0]
+            .as_ref()
+            .unwrap()
+            .move_;
+        get_move_data(move_).unwrap().name
+    }
+
+    #[test]
+    fn test_rejects_move_out_of_pp() {
+        let (mut battle_state, _) = create_battle(1).expect("Demo battle should be created");
+        let move_name = first_move_name(&battle_state);
+        battle_state.players[0].active_pokemon_mut().unwrap().moves[0]
+            .as_mut()
+            .unwrap()
+            .pp = 0;
+
+        let rejection = execute_move_action(&mut battle_state, &move_name).unwrap_err();
+
+        assert_eq!(rejection.code, RejectionCode::OutOfPp);
+        assert_eq!(rejection.message, "This move has no PP left.");
+        assert_eq!(
+            battle_state.turn_number, 1,
+            "A rejected action must not resolve a turn"
+        );
+    }
+
+    #[test]
+    fn test_rejects_disabled_move() {
+        let (mut battle_state, _) = create_battle(1).expect("Demo battle should be created");
+        let move_name = first_move_name(&battle_state);
+        let move_ = battle_state.players[0].active_pokemon().unwrap().moves[0]
+            .as_ref()
+            .unwrap()
+            .move_;
+        battle_state.players[0].add_condition(PokemonCondition::Disabled {
+            pokemon_move: move_,
+            turns_remaining: 3,
+        });
+
+        let rejection = execute_move_action(&mut battle_state, &move_name).unwrap_err();
+
+        assert_eq!(rejection.code, RejectionCode::MoveDisabled);
+        assert_eq!(rejection.message, "This move is currently disabled.");
+    }
+
+    #[test]
+    fn test_disabled_move_with_nothing_else_usable_struggles() {
+        let (mut battle_state, _) = create_battle(1).expect("Demo battle should be created");
+        let active = battle_state.players[0].active_pokemon_mut().unwrap();
+        let move_ = active.moves[0].as_ref().unwrap().move_;
+        for move_instance in active.moves.iter_mut().skip(1).flatten() {
+            move_instance.pp = 0;
+        }
+        battle_state.players[0].add_condition(PokemonCondition::Disabled {
+            pokemon_move: move_,
+            turns_remaining: 3,
+        });
+        assert!(battle_state.players[0].must_struggle());
+
+        let action = PlayerAction::UseMove { move_index: 0 };
+        assert_eq!(battle_state.players[0].validate_action(&action), Ok(()));
+        assert!(check_player_action(&battle_state, &action).is_ok());
+
+        let options = get_action_options(&battle_state);
+        assert_eq!(options.moves.len(), 1);
+        assert_eq!(options.moves[0].move_index, 0);
+        assert_eq!(options.moves[0].name, "Struggle");
+
+        battle_state.action_queue[0] = Some(action);
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        let event_bus = resolve_turn(&mut battle_state, TurnRng::new_for_test(vec![50; 100]));
+        assert!(event_bus.events().iter().any(|event| matches!(
+            event,
+            BattleEvent::MoveUsed {
+                player_index: 0,
+                move_used: Move::Struggle,
+                ..
+            }
+        )));
+    }
+
+    #[test]
+    fn test_rejects_switch_while_trapped() {
+        let (mut battle_state, _) = create_battle(1).expect("Demo battle should be created");
+        battle_state.players[0].add_condition(PokemonCondition::Trapped { turns_remaining: 2 });
+
+        let rejection = execute_switch_action(&mut battle_state, 2).unwrap_err();
+
+        assert_eq!(rejection.code, RejectionCode::Trapped);
+        assert_eq!(
+            rejection.message,
+            "The Pokémon is trapped and cannot switch out!"
+        );
+    }
+
+    #[test]
+    fn test_rejects_actions_in_wrong_state() {
+        let (mut battle_state, _) = create_battle(1).expect("Demo battle should be created");
+        let move_name = first_move_name(&battle_state);
+
+        battle_state.game_state = GameState::WaitingForPlayer1Replacement;
+        let rejection = execute_move_action(&mut battle_state, &move_name).unwrap_err();
+        assert_eq!(rejection.code, RejectionCode::WrongState);
+        assert_eq!(
+            rejection.message,
+            "Your Pokémon fainted! You must switch to a new Pokémon."
+        );
+
+        battle_state.game_state = GameState::Player2Win;
+        let rejection = execute_forfeit_action(&mut battle_state).unwrap_err();
+        assert_eq!(rejection.code, RejectionCode::WrongState);
+        assert_eq!(rejection.message, "The battle is already over.");
+    }
+
+    #[test]
+    fn test_rejects_illegal_switches() {
+        let (mut battle_state, _) = create_battle(1).expect("Demo battle should be created");
+
+        let rejection = execute_switch_action(&mut battle_state, 1).unwrap_err();
+        assert_eq!(rejection.code, RejectionCode::IllegalSwitch);
+        assert_eq!(rejection.message, "This Pokémon is already in battle.");
+
+        let rejection = execute_switch_action(&mut battle_state, 7).unwrap_err();
+        assert_eq!(rejection.code, RejectionCode::IllegalSwitch);
+        assert_eq!(rejection.message, "Invalid Pokemon number. Use 1-6.");
+
+        battle_state.players[0].team[1]
+            .as_mut()
+            .unwrap()
+            .take_damage(u16::MAX);
+        let rejection = execute_switch_action(&mut battle_state, 2).unwrap_err();
+        assert_eq!(rejection.code, RejectionCode::IllegalSwitch);
+        assert_eq!(rejection.message, "Cannot switch to a fainted Pokémon.");
+    }
+
+    #[test]
+    fn test_rejection_json_uses_stable_codes() {
+        let rejection = ActionRejection::new(RejectionCode::OutOfPp, "This move has no PP left.");
+        assert_eq!(
+            serde_json::to_value(&rejection).unwrap(),
+            json!({ "code": "OUT_OF_PP", "message": "This move has no PP left." })
+        );
+        assert_eq!(
+            rejection.to_string(),
+            "[OUT_OF_PP] This move has no PP left."
+        );
+    }
+}
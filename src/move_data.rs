@@ -2,13 +2,18 @@
 
 use crate::errors::{MoveDataError, MoveDataResult};
 use schema::{Move, MoveData};
+use std::collections::HashMap;
+use std::sync::LazyLock;
 
 // This line includes the functions generated by `build.rs` (e.g., get_compiled_move_data).
 include!(concat!(env!("OUT_DIR"), "/generated_data.rs"));
 
+// Deserialized once on first use rather than on every lookup
+static MOVE_DATA: LazyLock<HashMap<Move, MoveData>> = LazyLock::new(get_compiled_move_data);
+
 /// Get move data for a specific move. This is the public data access function.
 pub fn get_move_data(move_: Move) -> MoveDataResult<MoveData> {
-    get_compiled_move_data()
+    MOVE_DATA
         .get(&move_)
         .cloned()
         .ok_or(MoveDataError::MoveNotFound(move_))
@@ -19,3 +24,104 @@ pub fn get_move_max_pp(move_: Move) -> MoveDataResult<u8> {
     // This logic is sound and remains unchanged.
     get_move_data(move_).map(|data| data.max_pp)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use schema::{MoveEffect, MoveRange, StatType, StatusType, Target};
+
+    #[test]
+    fn test_priority_move_metadata() {
+        let data = get_move_data(Move::QuickAttack).unwrap();
+        assert_eq!(data.base_power(), Some(60));
+        assert_eq!(data.accuracy(), Some(100));
+        assert_eq!(data.priority(), 1);
+        assert!(!data.is_multi_hit());
+        assert_eq!(data.inflicts_status(), None);
+    }
+
+    #[test]
+    fn test_multi_hit_move_metadata() {
+        let data = get_move_data(Move::FuryAttack).unwrap();
+        assert_eq!(data.priority(), 0);
+        assert!(data.is_multi_hit());
+        assert_eq!(data.inflicts_status(), None);
+    }
+
+    #[test]
+    fn test_status_move_metadata() {
+        let data = get_move_data(Move::Hypnosis).unwrap();
+        assert_eq!(data.base_power(), None);
+        assert_eq!(data.priority(), 0);
+        assert!(!data.is_multi_hit());
+        assert_eq!(data.inflicts_status(), Some(StatusType::Sleep));
+    }
+
+    #[test]
+    fn test_contact_move_flags() {
+        let data = get_move_data(Move::Tackle).unwrap();
+        assert!(data.makes_contact());
+        assert!(!data.is_sound());
+        assert!(!data.is_punch());
+    }
+
+    #[test]
+    fn test_sound_move_flags() {
+        let data = get_move_data(Move::Growl).unwrap();
+        assert!(!data.makes_contact());
+        assert!(data.is_sound());
+        assert!(!data.is_punch());
+    }
+
+    #[test]
+    fn test_punch_move_flags() {
+        let data = get_move_data(Move::FirePunch).unwrap();
+        assert!(data.makes_contact());
+        assert!(!data.is_sound());
+        assert!(data.is_punch());
+    }
+
+    #[test]
+    fn test_move_ranges() {
+        assert_eq!(
+            get_move_data(Move::Tackle).unwrap().range,
+            MoveRange::Single
+        );
+        assert_eq!(
+            get_move_data(Move::Swift).unwrap().range,
+            MoveRange::AllFoes
+        );
+        assert_eq!(
+            get_move_data(Move::Earthquake).unwrap().range,
+            MoveRange::AllOthers
+        );
+        assert!(!get_move_data(Move::Tackle).unwrap().is_spread());
+        assert!(get_move_data(Move::Earthquake).unwrap().is_spread());
+    }
+
+    #[test]
+    fn test_secondary_effects_summary() {
+        let fire_blast = get_move_data(Move::FireBlast).unwrap();
+        let summary = fire_blast.secondary_effects_summary();
+        assert_eq!(summary, vec![(MoveEffect::Burn(30), Some(30))]);
+        assert_eq!(
+            summary[0].0.to_string(),
+            "Has a 30% chance to burn the target."
+        );
+
+        let swords_dance = get_move_data(Move::SwordsDance).unwrap();
+        assert_eq!(
+            swords_dance.secondary_effects_summary(),
+            vec![(
+                MoveEffect::StatChange(Target::User, StatType::Atk, 2, 100),
+                Some(100)
+            )]
+        );
+
+        let quick_attack = get_move_data(Move::QuickAttack).unwrap();
+        assert_eq!(
+            quick_attack.secondary_effects_summary(),
+            vec![(MoveEffect::Priority(1), None)]
+        );
+    }
+}
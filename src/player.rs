@@ -1,6 +1,12 @@
-use crate::battle::conditions::{PokemonCondition, PokemonConditionType};
-use crate::pokemon::PokemonInst;
-use schema::Move;
+use crate::battle::conditions::{PokemonCondition, PokemonConditionType, TRANSFORM_MOVE_PP};
+use crate::battle::state::BattleState;
+use crate::battle::stats::{
+    effective_attack, effective_defense, effective_speed, get_type_effectiveness,
+};
+use crate::errors::{BattleResult, BattleStateError};
+use crate::move_data::get_move_data;
+use crate::pokemon::{MoveInstance, PokemonInst, StatusCondition};
+use schema::{Move, MoveCategory, PokemonType};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
@@ -50,6 +56,100 @@ pub enum PlayerType {
     NPC,
 }
 
+/// A damaging hit taken by a player's active Pokemon.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct DamageRecord {
+    pub move_used: Move,
+    pub category: MoveCategory,
+    pub damage: u16,
+}
+
+/// The outcome of `BattlePlayer::adjust_stat_stage`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StageChangeResult {
+    pub old_stage: i8,
+    pub new_stage: i8,
+    /// The requested change ran past the -6/+6 limit and was cut short.
+    pub clamped: bool,
+}
+
+impl StageChangeResult {
+    /// Whether the stage actually moved. False means "won't go any higher/lower".
+    pub fn changed(&self) -> bool {
+        self.old_stage != self.new_stage
+    }
+}
+
+/// A volatile condition on the active Pokemon, with its remaining turns if it counts down.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct ConditionSummary {
+    pub condition: PokemonConditionType,
+    pub turns_remaining: Option<u8>,
+}
+
+/// Everything currently affecting one side of the battle, flattened for display.
+/// Lists are in a stable order so UIs don't reshuffle between turns.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct EffectsSummary {
+    pub status: Option<StatusCondition>,
+    pub conditions: Vec<ConditionSummary>,
+    pub team_conditions: Vec<(TeamCondition, u8)>,
+    pub stat_stages: Vec<(StatType, i8)>,
+}
+
+/// The active Pokemon's stats as the damage formula sees them right now: after stat
+/// stages, Transform, burn and paralysis. HP is reported as current/max.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct EffectiveStats {
+    pub current_hp: u16,
+    pub max_hp: u16,
+    pub attack: u16,
+    pub defense: u16,
+    pub sp_attack: u16,
+    pub sp_defense: u16,
+    pub speed: u16,
+}
+
+/// One of the active Pokemon's moves, with everything a UI needs to present it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct UsableMove {
+    pub move_index: usize,
+    pub move_: Move,
+    pub name: String,
+    pub pp: u8,
+    pub max_pp: u8,
+    pub move_type: PokemonType,
+    pub base_power: Option<u16>,
+    pub priority: i8,
+    /// Disabled or cooling down, so it can't be selected this turn.
+    pub disabled: bool,
+    /// Type multiplier against the given opponent, if one was given.
+    pub effectiveness: Option<f64>,
+}
+
+/// The parts of a trainer that persist between battles in a campaign.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PlayerSave {
+    pub team: Vec<PokemonInst>,
+    pub bag: HashMap<String, u16>,
+    pub money: u32,
+}
+
+/// Slot 1 of a side in a double battle: the second Pokémon on the field, with its own
+/// copy of the per-Pokémon battle state that `BattlePlayer` keeps for slot 0. Reach it
+/// through `BattlePlayer::battler(1)` rather than directly.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct PartnerSlot {
+    pub pokemon_index: usize,
+    pub conditions: HashMap<PokemonConditionType, PokemonCondition>,
+    pub stat_stages: HashMap<StatType, i8>,
+    pub last_move: Option<Move>,
+    pub last_damage_taken: Option<DamageRecord>,
+    /// Slot 1's counterparts of `BattleState::faint_handled` and `acted_this_turn`.
+    pub faint_handled: bool,
+    pub acted_this_turn: bool,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct BattlePlayer {
     // A unique identifier. For a human, this could be their UserID.
@@ -75,7 +175,19 @@ pub struct BattlePlayer {
     // Money/prize amount accumulated during battle (altered by Pay Day)
     pub ante: u32,
 
+    // Items carried between battles, keyed by item name, value is quantity
+    #[serde(default)]
+    pub bag: HashMap<String, u16>,
+
     pub last_move: Option<Move>,
+
+    // The last damaging hit the active Pokemon took this turn, reset at turn start
+    #[serde(default)]
+    pub last_damage_taken: Option<DamageRecord>,
+
+    // The second active Pokemon in a double battle; always None in a single battle
+    #[serde(default)]
+    pub partner: Option<PartnerSlot>,
 }
 
 impl BattlePlayer {
@@ -84,6 +196,23 @@ impl BattlePlayer {
         // Call the new, more explicit constructor with the default value.
         Self::new_with_player_type(player_id, player_name, team, PlayerType::NPC)
     }
+
+    /// Like `new`, but rejects a team with no Pokemon able to battle, which would
+    /// leave nothing to send out. The first healthy Pokemon leads.
+    pub fn try_new(
+        player_id: String,
+        player_name: String,
+        team: Vec<PokemonInst>,
+    ) -> Result<Self, BattleStateError> {
+        let mut player = Self::new(player_id, player_name, team);
+        player.active_pokemon_index = player
+            .team
+            .iter()
+            .position(|slot| slot.as_ref().is_some_and(|pokemon| !pokemon.is_fainted()))
+            .ok_or(BattleStateError::NoUsablePokemon)?;
+        Ok(player)
+    }
+
     pub fn new_with_player_type(
         player_id: String,
         player_name: String,
@@ -105,9 +234,29 @@ impl BattlePlayer {
             active_pokemon_conditions: HashMap::new(),
             stat_stages: HashMap::new(),
             ante: 0,
+            bag: HashMap::new(),
             last_move: None,
+            last_damage_taken: None,
+            partner: None,
         }
     }
+    /// Snapshot the team, bag and money so they can be stored between battles.
+    pub fn persistent_state(&self) -> PlayerSave {
+        PlayerSave {
+            team: self.team.iter().flatten().cloned().collect(),
+            bag: self.bag.clone(),
+            money: self.ante,
+        }
+    }
+
+    /// Rebuild a player from a stored save. Battle-only state starts fresh.
+    pub fn from_save(save: PlayerSave, player_id: String, player_name: String) -> Self {
+        let mut player = Self::new(player_id, player_name, save.team);
+        player.bag = save.bag;
+        player.ante = save.money;
+        player
+    }
+
     /// Get the currently active Pokemon
     pub fn active_pokemon(&self) -> Option<&PokemonInst> {
         self.team
@@ -115,17 +264,387 @@ impl BattlePlayer {
             .and_then(|slot| slot.as_ref())
     }
 
+    /// The Pokemon in `slot` (0, or 1 in a double battle) with its battle state.
+    pub fn battler(&self, slot: usize) -> Battler<'_> {
+        Battler { player: self, slot }
+    }
+
+    /// Like `battler`, but for changing the slot's Pokemon and battle state.
+    pub fn battler_mut(&mut self, slot: usize) -> BattlerMut<'_> {
+        BattlerMut { player: self, slot }
+    }
+
+    /// Whether the Pokemon at `team_index` is on the field, in either slot.
+    pub fn is_active(&self, team_index: usize) -> bool {
+        team_index == self.active_pokemon_index
+            || self
+                .partner
+                .as_ref()
+                .is_some_and(|partner| partner.pokemon_index == team_index)
+    }
+
     /// Check if a player has any non-fainted Pokemon in their team
     pub fn can_still_battle(&self) -> bool {
-        self.team.iter().any(|pokemon_opt| {
-            pokemon_opt
-                .as_ref()
-                .map_or(false, |pokemon| !pokemon.is_fainted())
-        })
+        self.can_still_battle_in_format(self.team.len() as u8)
+    }
+
+    /// Like `can_still_battle`, but only the first `team_size` slots count.
+    pub fn can_still_battle_in_format(&self, team_size: u8) -> bool {
+        self.team
+            .iter()
+            .take(team_size as usize)
+            .any(|pokemon_opt| {
+                pokemon_opt
+                    .as_ref()
+                    .is_some_and(|pokemon| !pokemon.is_fainted())
+            })
+    }
+
+    /// Share of the total max HP of the first `team_size` slots that is still left, from 0.0 to 1.0.
+    pub fn remaining_hp_fraction_in_format(&self, team_size: u8) -> f64 {
+        let (current, max) = self.team.iter().take(team_size as usize).flatten().fold(
+            (0u32, 0u32),
+            |(current, max), pokemon| {
+                (
+                    current + u32::from(pokemon.current_hp()),
+                    max + u32::from(pokemon.max_hp()),
+                )
+            },
+        );
+        if max == 0 {
+            0.0
+        } else {
+            f64::from(current) / f64::from(max)
+        }
     }
 
     #[allow(dead_code)]
     pub fn validate_action(&self, action: &PlayerAction) -> Result<(), String> {
+        self.validate_action_in_format(action, self.team.len() as u8)
+    }
+
+    /// Validate an action for a format where only the first `team_size` slots take part.
+    pub fn validate_action_in_format(
+        &self,
+        action: &PlayerAction,
+        team_size: u8,
+    ) -> Result<(), String> {
+        self.battler(0).validate_action_in_format(action, team_size)
+    }
+
+    /// Every move the active Pokemon knows, in slot order, with its metadata. Moves out
+    /// of PP are still listed (with `pp` 0). The opponent's base species types are used
+    /// for `effectiveness`.
+    pub fn usable_moves(&self, opponent: Option<&PokemonInst>) -> Vec<UsableMove> {
+        self.battler(0).usable_moves(opponent)
+    }
+
+    /// The max PP of one of the active Pokemon's move slots. Moves copied by
+    /// Transform only ever have `TRANSFORM_MOVE_PP`.
+    pub fn move_max_pp(&self, move_instance: &MoveInstance) -> u8 {
+        self.battler(0).move_max_pp(move_instance)
+    }
+
+    /// Returns true if the active Pokémon has no move it can use normally: every
+    /// known move is either out of PP or disabled. Any move choice becomes Struggle.
+    pub fn must_struggle(&self) -> bool {
+        self.battler(0).must_struggle()
+    }
+
+    /// Whether the active Pokemon can't select this move right now, because it is
+    /// disabled or still cooling down.
+    pub fn is_move_blocked(&self, move_: Move) -> bool {
+        self.battler(0).is_move_blocked(move_)
+    }
+
+    /// This checks for conditions like being fainted, exhausted, or having moves
+    /// that are disabled or out of PP. It will return a `Struggle` action if no
+    /// other moves are available.
+    pub fn get_valid_moves(&self) -> Vec<PlayerAction> {
+        self.battler(0).get_valid_moves()
+    }
+
+    /// Generates a list of valid Pokémon to switch to from the team.
+    /// This checks for conditions like `Trapped` and ensures that switch targets
+    /// are not fainted or already active.
+    pub fn get_valid_switches(&self) -> Vec<PlayerAction> {
+        self.get_valid_switches_in_format(self.team.len() as u8)
+    }
+
+    /// Like `get_valid_switches`, but only the first `team_size` slots are eligible.
+    pub fn get_valid_switches_in_format(&self, team_size: u8) -> Vec<PlayerAction> {
+        self.battler(0).get_valid_switches_in_format(team_size)
+    }
+
+    /// Team indices this player may switch to right now: non-fainted, not already
+    /// active, and within the battle's format. Empty while trapped or switch-locked.
+    pub fn legal_switch_targets(&self, state: &BattleState) -> Vec<usize> {
+        self.battler(0).legal_switch_targets(state)
+    }
+
+    pub fn forced_move(&self) -> Option<Move> {
+        self.battler(0).forced_move()
+    }
+
+    /// Get the currently active Pokemon mutably
+    #[cfg(test)]
+    pub fn active_pokemon_mut(&mut self) -> Option<&mut PokemonInst> {
+        self.team
+            .get_mut(self.active_pokemon_index)
+            .and_then(|slot| slot.as_mut())
+    }
+
+    /// Check if the active Pokemon has a condition of the specified type
+    pub fn has_condition_type(&self, condition_type: PokemonConditionType) -> bool {
+        self.battler(0).has_condition_type(condition_type)
+    }
+
+    /// Whether the active Pokemon is kept from switching out, either by a partial
+    /// trap (on either end of it) or by a plain switch lock.
+    pub fn is_switch_blocked(&self) -> bool {
+        self.battler(0).is_switch_blocked()
+    }
+
+    /// Get the turns remaining on a duration-based condition of the active Pokemon.
+    /// Returns None if the condition isn't present or doesn't count down.
+    pub fn condition_duration(&self, condition_type: PokemonConditionType) -> Option<u8> {
+        self.battler(0).condition_duration(condition_type)
+    }
+
+    /// Check if the active Pokemon has this exact condition (type AND data must match)
+    #[cfg(test)]
+    pub fn has_condition(&self, condition: &PokemonCondition) -> bool {
+        if let Some(existing_condition) = self.active_pokemon_conditions.get(&condition.get_type())
+        {
+            existing_condition == condition
+        } else {
+            false
+        }
+    }
+
+    /// Add or update a condition on the active Pokemon
+    pub fn add_condition(&mut self, condition: PokemonCondition) {
+        self.battler_mut(0).add_condition(condition);
+    }
+
+    /// Remove a condition from the active Pokemon, returning it if it was present.
+    /// Emits no event; callers outside the command layer decide whether to report it.
+    pub fn cure_condition(
+        &mut self,
+        condition_type: PokemonConditionType,
+    ) -> Option<PokemonCondition> {
+        self.battler_mut(0).cure_condition(condition_type)
+    }
+
+    /// Get a condition for reading
+    #[cfg(test)]
+    pub fn get_condition(&self, condition: &PokemonCondition) -> Option<&PokemonCondition> {
+        self.active_pokemon_conditions.get(&condition.get_type())
+    }
+
+    /// Check if the team has a specific condition
+    pub fn has_team_condition(&self, condition: &TeamCondition) -> bool {
+        self.team_conditions.contains_key(condition)
+    }
+
+    /// Add or update a team condition with turns remaining
+    pub fn add_team_condition(&mut self, condition: TeamCondition, turns_remaining: u8) {
+        self.team_conditions.insert(condition, turns_remaining);
+    }
+
+    /// Get turns remaining for a team condition
+    #[cfg(test)]
+    pub fn get_team_condition_turns(&self, condition: &TeamCondition) -> Option<u8> {
+        self.team_conditions.get(condition).copied()
+    }
+
+    #[cfg(test)]
+    /// Decrement all team condition turns and remove expired ones
+    pub fn tick_team_conditions(&mut self) -> Vec<TeamCondition> {
+        let mut expired = Vec::new();
+        self.team_conditions.retain(|condition, turns| {
+            *turns = turns.saturating_sub(1);
+            if *turns == 0 {
+                expired.push(*condition);
+                false // Remove from map
+            } else {
+                true // Keep in map
+            }
+        });
+        expired
+    }
+
+    // === Stat Stage Management ===
+
+    /// Get the current stage for a stat type (0 if not set)
+    pub fn get_stat_stage(&self, stat: StatType) -> i8 {
+        self.battler(0).get_stat_stage(stat)
+    }
+
+    /// Set the stage for a stat type (clamped to -6 to +6)
+    pub fn set_stat_stage(&mut self, stat: StatType, stage: i8) {
+        self.battler_mut(0).set_stat_stage(stat, stage);
+    }
+
+    /// Change a stat stage by `delta`, clamped to -6 to +6, reporting whether the
+    /// limit was hit so callers can narrate it.
+    pub fn adjust_stat_stage(&mut self, stat: StatType, delta: i8) -> StageChangeResult {
+        self.battler_mut(0).adjust_stat_stage(stat, delta)
+    }
+
+    /// The active Pokemon's effective stats, using the same `effective_*` calculations
+    /// as the damage formula. Attack and defense of `for_move`'s category are taken for
+    /// that move, so move-specific modifiers show up; the rest use a plain move.
+    pub fn effective_stats(&self, for_move: Option<Move>) -> BattleResult<EffectiveStats> {
+        self.battler(0).effective_stats(for_move)
+    }
+
+    /// Collect the active Pokemon's status, its volatile conditions, the team's
+    /// screens and any non-zero stat stages into one summary.
+    pub fn active_effects_summary(&self) -> EffectsSummary {
+        self.battler(0).active_effects_summary()
+    }
+
+    pub fn clear_active_pokemon_state(&mut self) {
+        self.battler_mut(0).clear_state();
+    }
+
+    /// Wipe everything that only lasts for one battle (stat stages, conditions,
+    /// team conditions, last move, the double-battle partner) so the player can go
+    /// into the next fight or be healed. The team keeps its HP, status, XP and PP,
+    /// and the bag is untouched. The ante is kept too: it is the same pot Pay Day
+    /// coins and prize money land in, and `persistent_state` saves it as money.
+    pub fn end_battle_cleanup(&mut self) {
+        self.clear_active_pokemon_state();
+        self.team_conditions.clear();
+        self.last_damage_taken = None;
+        self.partner = None;
+    }
+
+    /// Get current ante amount
+    pub fn get_ante(&self) -> u32 {
+        self.ante
+    }
+
+    /// Add to ante amount
+    pub fn add_ante(&mut self, amount: u32) {
+        self.ante = self.ante.saturating_add(amount);
+    }
+}
+
+/// One of a side's Pokémon on the field, addressed by slot. Slot 0 is the Pokémon at
+/// `active_pokemon_index` with the battle state kept on `BattlePlayer` itself; slot 1
+/// is a double battle's `partner`.
+#[derive(Debug, Clone, Copy)]
+pub struct Battler<'a> {
+    pub player: &'a BattlePlayer,
+    pub slot: usize,
+}
+
+impl<'a> Battler<'a> {
+    fn partner(&self) -> &'a PartnerSlot {
+        self.player
+            .partner
+            .as_ref()
+            .expect("slot 1 is only on the field in a double battle")
+    }
+
+    /// The team index of the Pokemon in this slot.
+    pub fn pokemon_index(&self) -> usize {
+        match self.slot {
+            0 => self.player.active_pokemon_index,
+            _ => self.partner().pokemon_index,
+        }
+    }
+
+    /// The Pokemon in this slot, fainted or not.
+    pub fn active_pokemon(&self) -> Option<&'a PokemonInst> {
+        self.player
+            .team
+            .get(self.pokemon_index())
+            .and_then(|slot| slot.as_ref())
+    }
+
+    pub fn conditions(&self) -> &'a HashMap<PokemonConditionType, PokemonCondition> {
+        match self.slot {
+            0 => &self.player.active_pokemon_conditions,
+            _ => &self.partner().conditions,
+        }
+    }
+
+    pub fn stat_stages(&self) -> &'a HashMap<StatType, i8> {
+        match self.slot {
+            0 => &self.player.stat_stages,
+            _ => &self.partner().stat_stages,
+        }
+    }
+
+    pub fn last_move(&self) -> Option<Move> {
+        match self.slot {
+            0 => self.player.last_move,
+            _ => self.partner().last_move,
+        }
+    }
+
+    pub fn last_damage_taken(&self) -> Option<DamageRecord> {
+        match self.slot {
+            0 => self.player.last_damage_taken,
+            _ => self.partner().last_damage_taken,
+        }
+    }
+
+    /// Check if the side this Pokemon is on has a specific team condition
+    pub fn has_team_condition(&self, condition: &TeamCondition) -> bool {
+        self.player.has_team_condition(condition)
+    }
+
+    /// Check if the Pokemon has a condition of the specified type
+    pub fn has_condition_type(&self, condition_type: PokemonConditionType) -> bool {
+        self.conditions().contains_key(&condition_type)
+    }
+
+    /// Whether the Pokemon is kept from switching out, either by a partial trap (on
+    /// either end of it) or by a plain switch lock.
+    pub fn is_switch_blocked(&self) -> bool {
+        self.has_condition_type(PokemonConditionType::Trapped)
+            || self.has_condition_type(PokemonConditionType::Trapping)
+            || self.has_condition_type(PokemonConditionType::SwitchLocked)
+    }
+
+    /// Get the turns remaining on a duration-based condition of the Pokemon.
+    /// Returns None if the condition isn't present or doesn't count down.
+    pub fn condition_duration(&self, condition_type: PokemonConditionType) -> Option<u8> {
+        match self.conditions().get(&condition_type)? {
+            PokemonCondition::Confused { turns_remaining }
+            | PokemonCondition::Disabled {
+                turns_remaining, ..
+            }
+            | PokemonCondition::Cooldown {
+                turns_remaining, ..
+            }
+            | PokemonCondition::Trapped { turns_remaining }
+            | PokemonCondition::SwitchLocked { turns_remaining }
+            | PokemonCondition::Trapping { turns_remaining }
+            | PokemonCondition::Rampaging { turns_remaining }
+            | PokemonCondition::Biding {
+                turns_remaining, ..
+            }
+            | PokemonCondition::Exhausted { turns_remaining } => Some(*turns_remaining),
+            _ => None,
+        }
+    }
+
+    /// Get the current stage for a stat type (0 if not set)
+    pub fn get_stat_stage(&self, stat: StatType) -> i8 {
+        self.stat_stages().get(&stat).copied().unwrap_or(0)
+    }
+
+    /// Validate an action for a format where only the first `team_size` slots take part.
+    pub fn validate_action_in_format(
+        &self,
+        action: &PlayerAction,
+        team_size: u8,
+    ) -> Result<(), String> {
         match action {
             PlayerAction::UseMove { move_index } => {
                 let pokemon = self
@@ -136,12 +655,15 @@ impl BattlePlayer {
                     return Err("Invalid move index.".to_string());
                 }
 
+                // With nothing usable left, any move choice becomes Struggle.
+                if self.must_struggle() {
+                    return Ok(());
+                }
+
                 if let Some(move_instance) = &pokemon.moves[*move_index] {
                     // It's valid to select a move with 0 PP; the engine will convert it to Struggle.
-                    // We only need to check for explicitly disabled moves.
-                    if self.active_pokemon_conditions.values().any(|cond| {
-                        matches!(cond, PokemonCondition::Disabled { pokemon_move, .. } if *pokemon_move == move_instance.move_)
-                    }) {
+                    // We only need to check for explicitly disabled or cooling-down moves.
+                    if self.is_move_blocked(move_instance.move_) {
                         return Err("This move is currently disabled.".to_string());
                     }
                 } else {
@@ -149,19 +671,23 @@ impl BattlePlayer {
                 }
             }
             PlayerAction::SwitchPokemon { team_index } => {
-                if self.has_condition_type(PokemonConditionType::Trapped) {
+                if self.is_switch_blocked() {
                     return Err("The Pokémon is trapped and cannot switch out!".to_string());
                 }
 
-                if *team_index >= self.team.len() {
+                if *team_index >= self.player.team.len() {
                     return Err("Invalid team index for switching.".to_string());
                 }
 
-                if let Some(target_pokemon) = &self.team[*team_index] {
+                if *team_index >= team_size as usize {
+                    return Err("That Pokémon is not part of this battle format.".to_string());
+                }
+
+                if let Some(target_pokemon) = &self.player.team[*team_index] {
                     if target_pokemon.is_fainted() {
                         return Err("Cannot switch to a fainted Pokémon.".to_string());
                     }
-                    if *team_index == self.active_pokemon_index {
+                    if self.player.is_active(*team_index) {
                         return Err("This Pokémon is already in battle.".to_string());
                     }
                 } else {
@@ -180,6 +706,78 @@ impl BattlePlayer {
         Ok(())
     }
 
+    /// Every move the active Pokemon knows, in slot order, with its metadata. Moves out
+    /// of PP are still listed (with `pp` 0). The opponent's base species types are used
+    /// for `effectiveness`.
+    pub fn usable_moves(&self, opponent: Option<&PokemonInst>) -> Vec<UsableMove> {
+        let Some(active_pokemon) = self.active_pokemon() else {
+            return Vec::new();
+        };
+        let opponent_types = opponent
+            .and_then(|pokemon| pokemon.get_species_data().ok())
+            .map(|data| data.types.clone());
+
+        active_pokemon
+            .moves
+            .iter()
+            .enumerate()
+            .filter_map(|(move_index, slot)| {
+                let inst = slot.as_ref()?;
+                let move_data = get_move_data(inst.move_).ok()?;
+                Some(UsableMove {
+                    move_index,
+                    move_: inst.move_,
+                    name: move_data.name.clone(),
+                    pp: inst.pp,
+                    max_pp: self.move_max_pp(inst),
+                    move_type: move_data.move_type,
+                    base_power: move_data.base_power(),
+                    priority: move_data.priority(),
+                    disabled: self.is_move_blocked(inst.move_),
+                    effectiveness: opponent_types
+                        .as_ref()
+                        .map(|types| get_type_effectiveness(move_data.move_type, types)),
+                })
+            })
+            .collect()
+    }
+
+    /// The max PP of one of the active Pokemon's move slots. Moves copied by
+    /// Transform only ever have `TRANSFORM_MOVE_PP`.
+    pub fn move_max_pp(&self, move_instance: &MoveInstance) -> u8 {
+        if self.has_condition_type(PokemonConditionType::Transformed) {
+            TRANSFORM_MOVE_PP.min(move_instance.max_pp())
+        } else {
+            move_instance.max_pp()
+        }
+    }
+
+    /// Returns true if the active Pokémon has no move it can use normally: every
+    /// known move is either out of PP or disabled. Any move choice becomes Struggle.
+    pub fn must_struggle(&self) -> bool {
+        let Some(active_pokemon) = self.active_pokemon() else {
+            return false;
+        };
+        !active_pokemon
+            .moves
+            .iter()
+            .flatten()
+            .any(|inst| inst.pp > 0 && !self.is_move_blocked(inst.move_))
+    }
+
+    /// Whether the active Pokemon can't select this move right now, because it is
+    /// disabled or still cooling down.
+    pub fn is_move_blocked(&self, move_: Move) -> bool {
+        self.conditions().values().any(|cond| match cond {
+            PokemonCondition::Disabled { pokemon_move, .. } => *pokemon_move == move_,
+            PokemonCondition::Cooldown {
+                pokemon_move,
+                turns_remaining,
+            } => *turns_remaining > 0 && *pokemon_move == move_,
+            _ => false,
+        })
+    }
+
     /// This checks for conditions like being fainted, exhausted, or having moves
     /// that are disabled or out of PP. It will return a `Struggle` action if no
     /// other moves are available.
@@ -190,15 +788,23 @@ impl BattlePlayer {
             let can_use_moves = !self.has_condition_type(PokemonConditionType::Exhausted)
                 && !active_pokemon.is_fainted();
 
-            if can_use_moves {
-                let usable_moves: Vec<_> = active_pokemon.moves.iter().enumerate()
+            if can_use_moves && self.must_struggle() {
+                // Nothing usable remains, so the only option is Struggle.
+                moves.push(PlayerAction::UseMove { move_index: 0 });
+            } else if can_use_moves {
+                let usable_moves: Vec<_> = active_pokemon
+                    .moves
+                    .iter()
+                    .enumerate()
                     .filter_map(|(i, slot)| {
                         slot.as_ref().and_then(|inst| {
-                            let is_disabled = self.active_pokemon_conditions.values().any(|cond| {
-                                matches!(cond, PokemonCondition::Disabled { pokemon_move, .. } if *pokemon_move == inst.move_)
-                            });
+                            let is_disabled = self.is_move_blocked(inst.move_);
                             // We allow selecting a move with 0 PP; the engine will turn it into Struggle.
-                            if !is_disabled { Some(PlayerAction::UseMove { move_index: i }) } else { None }
+                            if !is_disabled {
+                                Some(PlayerAction::UseMove { move_index: i })
+                            } else {
+                                None
+                            }
                         })
                     })
                     .collect();
@@ -214,31 +820,42 @@ impl BattlePlayer {
         moves
     }
 
-    /// Generates a list of valid Pokémon to switch to from the team.
-    /// This checks for conditions like `Trapped` and ensures that switch targets
-    /// are not fainted or already active.
-    pub fn get_valid_switches(&self) -> Vec<PlayerAction> {
-        let mut switches = Vec::new();
+    /// Like `get_valid_switches`, but only the first `team_size` slots are eligible.
+    pub fn get_valid_switches_in_format(&self, team_size: u8) -> Vec<PlayerAction> {
+        self.switch_targets_in_format(team_size)
+            .into_iter()
+            .map(|team_index| PlayerAction::SwitchPokemon { team_index })
+            .collect()
+    }
 
-        // If trapped, no switches are possible.
-        if self.has_condition_type(PokemonConditionType::Trapped) {
-            return switches;
-        }
+    /// Team indices this player may switch to right now: non-fainted, not already
+    /// active, and within the battle's format. Empty while trapped or switch-locked.
+    pub fn legal_switch_targets(&self, state: &BattleState) -> Vec<usize> {
+        self.switch_targets_in_format(state.rules.format_team_size)
+    }
 
-        for (i, pokemon_slot) in self.team.iter().enumerate() {
-            if let Some(pokemon) = pokemon_slot {
-                if i != self.active_pokemon_index && !pokemon.is_fainted() {
-                    switches.push(PlayerAction::SwitchPokemon { team_index: i });
-                }
-            }
+    fn switch_targets_in_format(&self, team_size: u8) -> Vec<usize> {
+        // If trapped or switch-locked, no switches are possible.
+        if self.is_switch_blocked() {
+            return Vec::new();
         }
-        switches
+
+        self.player
+            .team
+            .iter()
+            .enumerate()
+            .take(team_size as usize)
+            .filter_map(|(i, pokemon_slot)| match pokemon_slot {
+                Some(pokemon) if !self.player.is_active(i) && !pokemon.is_fainted() => Some(i),
+                _ => None,
+            })
+            .collect()
     }
 
     pub fn forced_move(&self) -> Option<Move> {
         // Check for Biding condition, which forces the Bide move.
         if self
-            .active_pokemon_conditions
+            .conditions()
             .values()
             .any(|c| matches!(c, PokemonCondition::Biding { .. }))
         {
@@ -246,14 +863,15 @@ impl BattlePlayer {
         }
 
         // Check for conditions that force a repeat of the last move.
-        if let Some(last_move) = self.last_move {
-            let is_locked_into_repeating = self.active_pokemon_conditions.values().any(|c| {
+        if let Some(last_move) = self.last_move() {
+            let is_locked_into_repeating = self.conditions().values().any(|c| {
                 matches!(
                     c,
                     PokemonCondition::Charging
                         | PokemonCondition::InAir
                         | PokemonCondition::Underground
                         | PokemonCondition::Rampaging { .. }
+                        | PokemonCondition::Trapping { .. }
                 )
             });
 
@@ -265,105 +883,208 @@ impl BattlePlayer {
         None
     }
 
-    /// Get the currently active Pokemon mutably
-    #[cfg(test)]
-    pub fn active_pokemon_mut(&mut self) -> Option<&mut PokemonInst> {
-        self.team
-            .get_mut(self.active_pokemon_index)
-            .and_then(|slot| slot.as_mut())
+    /// The active Pokemon's effective stats, using the same `effective_*` calculations
+    /// as the damage formula. Attack and defense of `for_move`'s category are taken for
+    /// that move, so move-specific modifiers show up; the rest use a plain move.
+    pub fn effective_stats(&self, for_move: Option<Move>) -> BattleResult<EffectiveStats> {
+        let pokemon = self
+            .active_pokemon()
+            .ok_or(BattleStateError::NoActivePokemon)?;
+
+        let mut physical_move = Move::Tackle;
+        let mut special_move = Move::Ember;
+        if let Some(move_) = for_move {
+            match get_move_data(move_)?.category {
+                MoveCategory::Physical => physical_move = move_,
+                MoveCategory::Special => special_move = move_,
+                MoveCategory::Status | MoveCategory::Other => {}
+            }
+        }
+
+        Ok(EffectiveStats {
+            current_hp: pokemon.current_hp(),
+            max_hp: pokemon.max_hp(),
+            attack: effective_attack(pokemon, *self, physical_move)?,
+            defense: effective_defense(pokemon, *self, physical_move)?,
+            sp_attack: effective_attack(pokemon, *self, special_move)?,
+            sp_defense: effective_defense(pokemon, *self, special_move)?,
+            speed: effective_speed(pokemon, *self),
+        })
     }
 
-    /// Check if the active Pokemon has a condition of the specified type
-    pub fn has_condition_type(&self, condition_type: PokemonConditionType) -> bool {
-        self.active_pokemon_conditions.contains_key(&condition_type)
+    /// Collect the active Pokemon's status, its volatile conditions, the team's
+    /// screens and any non-zero stat stages into one summary.
+    pub fn active_effects_summary(&self) -> EffectsSummary {
+        let mut conditions: Vec<ConditionSummary> = self
+            .conditions()
+            .keys()
+            .map(|condition_type| ConditionSummary {
+                condition: *condition_type,
+                turns_remaining: self.condition_duration(*condition_type),
+            })
+            .collect();
+        conditions.sort_by_key(|summary| summary.condition.to_string());
+
+        let team_conditions = [
+            TeamCondition::Reflect,
+            TeamCondition::LightScreen,
+            TeamCondition::Mist,
+        ]
+        .into_iter()
+        .filter_map(|condition| {
+            self.player
+                .team_conditions
+                .get(&condition)
+                .map(|turns| (condition, *turns))
+        })
+        .collect();
+
+        let stat_stages = [
+            StatType::Atk,
+            StatType::Def,
+            StatType::SpAtk,
+            StatType::SpDef,
+            StatType::Spe,
+            StatType::Acc,
+            StatType::Eva,
+            StatType::Crit,
+        ]
+        .into_iter()
+        .map(|stat| (stat, self.get_stat_stage(stat)))
+        .filter(|(_, stage)| *stage != 0)
+        .collect();
+
+        EffectsSummary {
+            status: self.active_pokemon().and_then(|pokemon| pokemon.status),
+            conditions,
+            team_conditions,
+            stat_stages,
+        }
     }
+}
 
-    /// Check if the active Pokemon has this exact condition (type AND data must match)
-    #[cfg(test)]
-    pub fn has_condition(&self, condition: &PokemonCondition) -> bool {
-        if let Some(existing_condition) = self.active_pokemon_conditions.get(&condition.get_type())
-        {
-            existing_condition == condition
-        } else {
-            false
+/// Like `Battler`, but for changing the slot's Pokemon and battle state.
+#[derive(Debug)]
+pub struct BattlerMut<'a> {
+    pub player: &'a mut BattlePlayer,
+    pub slot: usize,
+}
+
+impl BattlerMut<'_> {
+    fn partner_mut(&mut self) -> &mut PartnerSlot {
+        self.player
+            .partner
+            .as_mut()
+            .expect("slot 1 is only on the field in a double battle")
+    }
+
+    pub fn as_battler(&self) -> Battler<'_> {
+        Battler {
+            player: self.player,
+            slot: self.slot,
         }
     }
 
-    /// Add or update a condition on the active Pokemon
-    pub fn add_condition(&mut self, condition: PokemonCondition) {
-        self.active_pokemon_conditions
-            .insert(condition.get_type(), condition);
+    /// The Pokemon in this slot, mutably
+    pub fn active_pokemon_mut(&mut self) -> Option<&mut PokemonInst> {
+        let team_index = self.as_battler().pokemon_index();
+        self.player
+            .team
+            .get_mut(team_index)
+            .and_then(|slot| slot.as_mut())
     }
 
-    /// Get a condition for reading
-    #[cfg(test)]
-    pub fn get_condition(&self, condition: &PokemonCondition) -> Option<&PokemonCondition> {
-        self.active_pokemon_conditions.get(&condition.get_type())
+    pub fn conditions_mut(&mut self) -> &mut HashMap<PokemonConditionType, PokemonCondition> {
+        match self.slot {
+            0 => &mut self.player.active_pokemon_conditions,
+            _ => &mut self.partner_mut().conditions,
+        }
     }
 
-    /// Check if the team has a specific condition
-    pub fn has_team_condition(&self, condition: &TeamCondition) -> bool {
-        self.team_conditions.contains_key(condition)
+    fn stat_stages_mut(&mut self) -> &mut HashMap<StatType, i8> {
+        match self.slot {
+            0 => &mut self.player.stat_stages,
+            _ => &mut self.partner_mut().stat_stages,
+        }
     }
 
-    /// Add or update a team condition with turns remaining
-    pub fn add_team_condition(&mut self, condition: TeamCondition, turns_remaining: u8) {
-        self.team_conditions.insert(condition, turns_remaining);
+    /// Put the Pokemon at `team_index` in this slot. Its battle state is left as is.
+    pub fn set_pokemon_index(&mut self, team_index: usize) {
+        match self.slot {
+            0 => self.player.active_pokemon_index = team_index,
+            _ => self.partner_mut().pokemon_index = team_index,
+        }
     }
 
-    /// Get turns remaining for a team condition
-    #[cfg(test)]
-    pub fn get_team_condition_turns(&self, condition: &TeamCondition) -> Option<u8> {
-        self.team_conditions.get(condition).copied()
+    pub fn set_last_move(&mut self, last_move: Option<Move>) {
+        match self.slot {
+            0 => self.player.last_move = last_move,
+            _ => self.partner_mut().last_move = last_move,
+        }
     }
 
-    #[cfg(test)]
-    /// Decrement all team condition turns and remove expired ones
-    pub fn tick_team_conditions(&mut self) -> Vec<TeamCondition> {
-        let mut expired = Vec::new();
-        self.team_conditions.retain(|condition, turns| {
-            *turns = turns.saturating_sub(1);
-            if *turns == 0 {
-                expired.push(*condition);
-                false // Remove from map
-            } else {
-                true // Keep in map
-            }
-        });
-        expired
+    pub fn set_last_damage_taken(&mut self, record: Option<DamageRecord>) {
+        match self.slot {
+            0 => self.player.last_damage_taken = record,
+            _ => self.partner_mut().last_damage_taken = record,
+        }
     }
 
-    // === Stat Stage Management ===
+    /// Add or update a condition on the Pokemon
+    pub fn add_condition(&mut self, condition: PokemonCondition) {
+        self.conditions_mut()
+            .insert(condition.get_type(), condition);
+    }
 
-    /// Get the current stage for a stat type (0 if not set)
-    pub fn get_stat_stage(&self, stat: StatType) -> i8 {
-        self.stat_stages.get(&stat).copied().unwrap_or(0)
+    /// Remove a condition from the Pokemon, returning it if it was present.
+    pub fn cure_condition(
+        &mut self,
+        condition_type: PokemonConditionType,
+    ) -> Option<PokemonCondition> {
+        self.conditions_mut().remove(&condition_type)
     }
 
     /// Set the stage for a stat type (clamped to -6 to +6)
     pub fn set_stat_stage(&mut self, stat: StatType, stage: i8) {
         let clamped_stage = stage.clamp(-6, 6);
         if clamped_stage == 0 {
-            self.stat_stages.remove(&stat);
+            self.stat_stages_mut().remove(&stat);
         } else {
-            self.stat_stages.insert(stat, clamped_stage);
+            self.stat_stages_mut().insert(stat, clamped_stage);
         }
     }
 
-    pub fn clear_active_pokemon_state(&mut self) {
-        self.active_pokemon_conditions.clear();
-        self.stat_stages.clear();
-        self.last_move = None;
-    }
-
-    /// Get current ante amount
-    pub fn get_ante(&self) -> u32 {
-        self.ante
+    /// Change a stat stage by `delta`, clamped to -6 to +6, reporting whether the
+    /// limit was hit so callers can narrate it.
+    pub fn adjust_stat_stage(&mut self, stat: StatType, delta: i8) -> StageChangeResult {
+        let old_stage = self.as_battler().get_stat_stage(stat);
+        let requested = old_stage.saturating_add(delta);
+        let new_stage = requested.clamp(-6, 6);
+        self.set_stat_stage(stat, new_stage);
+        StageChangeResult {
+            old_stage,
+            new_stage,
+            clamped: new_stage != requested,
+        }
     }
 
-    /// Add to ante amount
-    pub fn add_ante(&mut self, amount: u32) {
-        self.ante = self.ante.saturating_add(amount);
+    /// Wipe the volatile state the Pokemon in this slot built up while on the field.
+    pub fn clear_state(&mut self) {
+        // A transformed Pokemon gets its own moves back; PP spent on the copies is lost.
+        // Transformed conditions saved before moves were copied carry no moveset, and
+        // the Pokemon still has its own moves, so there is nothing to restore.
+        if let Some(PokemonCondition::Transformed { original_moves, .. }) =
+            self.cure_condition(PokemonConditionType::Transformed)
+        {
+            if original_moves.iter().any(Option::is_some) {
+                if let Some(pokemon) = self.active_pokemon_mut() {
+                    pokemon.moves = original_moves;
+                }
+            }
+        }
+        self.conditions_mut().clear();
+        self.stat_stages_mut().clear();
+        self.set_last_move(None);
     }
 }
 
@@ -444,3 +1165,254 @@ impl fmt::Display for BattlePlayer {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pokemon::get_species_data;
+    use crate::species::Species;
+
+    #[test]
+    fn test_player_save_round_trip() {
+        let pikachu_data = get_species_data(Species::Pikachu).unwrap();
+        let mut pikachu = PokemonInst::new(Species::Pikachu, &pikachu_data, 25, None, None);
+        pikachu.take_damage(10);
+        let onix_data = get_species_data(Species::Onix).unwrap();
+        let onix = PokemonInst::new(Species::Onix, &onix_data, 30, None, None);
+
+        let mut player =
+            BattlePlayer::new("ash".to_string(), "Ash".to_string(), vec![pikachu, onix]);
+        player.bag.insert("Potion".to_string(), 3);
+        player.bag.insert("Poke Ball".to_string(), 5);
+        player.ante = 1200;
+
+        let save = player.persistent_state();
+        let json = serde_json::to_string(&save).unwrap();
+        let restored_save: PlayerSave = serde_json::from_str(&json).unwrap();
+        let restored = BattlePlayer::from_save(restored_save, "ash".to_string(), "Ash".to_string());
+
+        assert_eq!(restored.persistent_state(), save);
+        assert_eq!(restored.team[0], player.team[0]);
+        assert_eq!(restored.team[1], player.team[1]);
+        assert_eq!(restored.bag.get("Potion"), Some(&3));
+        assert_eq!(restored.ante, 1200);
+    }
+
+    #[test]
+    fn test_active_effects_summary_lists_every_effect() {
+        let pikachu_data = get_species_data(Species::Pikachu).unwrap();
+        let mut pikachu = PokemonInst::new(Species::Pikachu, &pikachu_data, 25, None, None);
+        pikachu.status = Some(StatusCondition::Burn);
+
+        let mut player = BattlePlayer::new("ash".to_string(), "Ash".to_string(), vec![pikachu]);
+        player.add_condition(PokemonCondition::Confused { turns_remaining: 3 });
+        player.set_stat_stage(StatType::Atk, 2);
+        player.add_team_condition(TeamCondition::Reflect, 5);
+
+        let summary = player.active_effects_summary();
+
+        assert_eq!(
+            summary,
+            EffectsSummary {
+                status: Some(StatusCondition::Burn),
+                conditions: vec![ConditionSummary {
+                    condition: PokemonConditionType::Confused,
+                    turns_remaining: Some(3),
+                }],
+                team_conditions: vec![(TeamCondition::Reflect, 5)],
+                stat_stages: vec![(StatType::Atk, 2)],
+            }
+        );
+    }
+
+    #[test]
+    fn test_try_new_rejects_team_without_usable_pokemon() {
+        let empty = BattlePlayer::try_new("ash".to_string(), "Ash".to_string(), vec![]);
+        assert_eq!(empty.unwrap_err(), BattleStateError::NoUsablePokemon);
+
+        let pikachu_data = get_species_data(Species::Pikachu).unwrap();
+        let mut pikachu = PokemonInst::new(Species::Pikachu, &pikachu_data, 25, None, None);
+        pikachu.set_current_hp(0);
+        let all_fainted =
+            BattlePlayer::try_new("ash".to_string(), "Ash".to_string(), vec![pikachu]);
+        assert_eq!(all_fainted.unwrap_err(), BattleStateError::NoUsablePokemon);
+    }
+
+    #[test]
+    fn test_try_new_accepts_single_pokemon_team() {
+        use crate::battle::engine::resolve_turn;
+        use crate::battle::state::{GameState, TurnRng};
+
+        let pikachu_data = get_species_data(Species::Pikachu).unwrap();
+        let pikachu = PokemonInst::new(Species::Pikachu, &pikachu_data, 25, None, None);
+        let onix_data = get_species_data(Species::Onix).unwrap();
+        let onix = PokemonInst::new(Species::Onix, &onix_data, 25, None, None);
+
+        let player1 =
+            BattlePlayer::try_new("ash".to_string(), "Ash".to_string(), vec![pikachu]).unwrap();
+        let player2 =
+            BattlePlayer::try_new("brock".to_string(), "Brock".to_string(), vec![onix]).unwrap();
+        assert_eq!(player1.active_pokemon().unwrap().species, Species::Pikachu);
+
+        let mut battle_state = BattleState::new("test".to_string(), player1, player2);
+        battle_state.action_queue[0] = Some(PlayerAction::UseMove { move_index: 0 });
+        battle_state.action_queue[1] = Some(PlayerAction::UseMove { move_index: 0 });
+        resolve_turn(&mut battle_state, TurnRng::new_for_test(vec![50; 100]));
+
+        assert_eq!(battle_state.turn_number, 2);
+        assert_eq!(battle_state.game_state, GameState::WaitingForActions);
+    }
+
+    #[test]
+    fn test_usable_moves_flags_disabled_and_empty_moves() {
+        let pikachu_data = get_species_data(Species::Pikachu).unwrap();
+        let mut pikachu = PokemonInst::new(
+            Species::Pikachu,
+            &pikachu_data,
+            25,
+            None,
+            Some(vec![Move::Tackle, Move::ThunderPunch, Move::Growl]),
+        );
+        pikachu.moves[1].as_mut().unwrap().pp = 0;
+        let onix_data = get_species_data(Species::Onix).unwrap();
+        let onix = PokemonInst::new(Species::Onix, &onix_data, 25, None, None);
+
+        let mut player = BattlePlayer::new("ash".to_string(), "Ash".to_string(), vec![pikachu]);
+        player.add_condition(PokemonCondition::Disabled {
+            pokemon_move: Move::Tackle,
+            turns_remaining: 3,
+        });
+
+        let moves = player.usable_moves(Some(&onix));
+
+        assert_eq!(moves.len(), 3);
+        let tackle = &moves[0];
+        assert_eq!(tackle.name, "Tackle");
+        assert_eq!(tackle.base_power, Some(40));
+        assert_eq!(tackle.priority, 0);
+        assert!(tackle.disabled);
+        assert_eq!(tackle.effectiveness, Some(0.5));
+
+        let thunder_punch = &moves[1];
+        assert_eq!(thunder_punch.move_index, 1);
+        assert_eq!(thunder_punch.pp, 0);
+        assert_eq!(thunder_punch.max_pp, 20);
+        assert_eq!(thunder_punch.move_type, PokemonType::Electric);
+        assert!(!thunder_punch.disabled);
+        assert_eq!(thunder_punch.effectiveness, Some(0.0));
+
+        let growl = &moves[2];
+        assert_eq!(growl.base_power, None);
+        assert!(!growl.disabled);
+
+        assert!(player
+            .usable_moves(None)
+            .iter()
+            .all(|usable| usable.effectiveness.is_none()));
+    }
+
+    #[test]
+    fn test_end_battle_cleanup_clears_volatiles_but_keeps_progress() {
+        let pikachu_data = get_species_data(Species::Pikachu).unwrap();
+        let mut pikachu = PokemonInst::new(Species::Pikachu, &pikachu_data, 25, None, None);
+        pikachu.take_damage(10);
+        pikachu.curr_exp += 50;
+        let hp_after_battle = pikachu.current_hp();
+        let exp_after_battle = pikachu.curr_exp;
+
+        let mut player = BattlePlayer::new("ash".to_string(), "Ash".to_string(), vec![pikachu]);
+        player.bag.insert("Potion".to_string(), 3);
+        player.set_stat_stage(StatType::Atk, 2);
+        player.add_condition(PokemonCondition::Substitute { hp: 20 });
+        player.add_team_condition(TeamCondition::Reflect, 5);
+        player.last_move = Some(Move::Substitute);
+
+        player.end_battle_cleanup();
+
+        assert_eq!(player.get_stat_stage(StatType::Atk), 0);
+        assert!(!player.has_condition_type(PokemonConditionType::Substitute));
+        assert!(!player.has_team_condition(&TeamCondition::Reflect));
+        assert_eq!(player.last_move, None);
+        let pikachu = player.active_pokemon().unwrap();
+        assert_eq!(pikachu.current_hp(), hp_after_battle);
+        assert_eq!(pikachu.curr_exp, exp_after_battle);
+        assert_eq!(player.bag.get("Potion"), Some(&3));
+    }
+
+    #[test]
+    fn test_effective_stats_match_damage_formula_under_burn() {
+        let charmander_data = get_species_data(Species::Charmander).unwrap();
+        let mut charmander =
+            PokemonInst::new(Species::Charmander, &charmander_data, 30, None, None);
+        charmander.status = Some(StatusCondition::Burn);
+        charmander.take_damage(5);
+        let base_attack = charmander.stats.attack;
+        let base_sp_attack = charmander.stats.sp_attack;
+
+        let mut player = BattlePlayer::new("red".to_string(), "Red".to_string(), vec![charmander]);
+        player.set_stat_stage(StatType::Atk, 1);
+
+        let stats = player.effective_stats(Some(Move::Scratch)).unwrap();
+        let pokemon = player.active_pokemon().unwrap();
+
+        // The same number the damage formula feeds in for Scratch.
+        assert_eq!(
+            stats.attack,
+            effective_attack(pokemon, player.battler(0), Move::Scratch).unwrap()
+        );
+        // +1 is x1.5, then burn halves physical attack only.
+        assert_eq!(
+            stats.attack,
+            (f64::from(base_attack) * 1.5).round() as u16 / 2
+        );
+        assert_eq!(stats.sp_attack, base_sp_attack);
+        assert_eq!(stats.current_hp, pokemon.current_hp());
+        assert_eq!(stats.max_hp, pokemon.max_hp());
+    }
+
+    #[test]
+    fn test_cure_condition_returns_removed_condition() {
+        let pikachu_data = get_species_data(Species::Pikachu).unwrap();
+        let pikachu = PokemonInst::new(Species::Pikachu, &pikachu_data, 25, None, None);
+        let mut player = BattlePlayer::new("ash".to_string(), "Ash".to_string(), vec![pikachu]);
+        player.add_condition(PokemonCondition::Confused { turns_remaining: 2 });
+
+        assert_eq!(
+            player.cure_condition(PokemonConditionType::Confused),
+            Some(PokemonCondition::Confused { turns_remaining: 2 })
+        );
+        assert!(!player.has_condition_type(PokemonConditionType::Confused));
+
+        // Nothing left to cure the second time, and absent conditions are untouched.
+        assert_eq!(player.cure_condition(PokemonConditionType::Confused), None);
+        assert_eq!(player.cure_condition(PokemonConditionType::Seeded), None);
+    }
+
+    #[test]
+    fn test_transformed_condition_saved_without_moves_keeps_own_moveset() {
+        let ditto_data = get_species_data(Species::Ditto).unwrap();
+        let ditto = PokemonInst::new(Species::Ditto, &ditto_data, 20, None, None);
+        let own_moves = ditto.moves.clone();
+        let onix_data = get_species_data(Species::Onix).unwrap();
+        let onix = PokemonInst::new(Species::Onix, &onix_data, 20, None, None);
+
+        // A Transformed condition from an older save has no original_moves field.
+        let mut saved = serde_json::to_value(PokemonCondition::Transformed {
+            target: onix,
+            original_moves: Default::default(),
+        })
+        .unwrap();
+        saved["Transformed"]
+            .as_object_mut()
+            .unwrap()
+            .remove("original_moves");
+        let condition: PokemonCondition = serde_json::from_value(saved).unwrap();
+
+        let mut player = BattlePlayer::new("red".to_string(), "Red".to_string(), vec![ditto]);
+        player.add_condition(condition);
+        player.clear_active_pokemon_state();
+
+        assert!(!player.has_condition_type(PokemonConditionType::Transformed));
+        assert_eq!(player.active_pokemon().unwrap().moves, own_moves);
+    }
+}
@@ -1,8 +1,10 @@
 use crate::battle::conditions::PokemonCondition;
+use crate::battle::stats::get_type_effectiveness;
 use crate::errors::{SpeciesDataError, SpeciesDataResult};
 use crate::species::Species;
-use schema::{BaseStats, Learnset, Move, PokemonSpecies, PokemonType};
+use schema::{BaseStats, Item, Learnset, Move, MoveCategory, PokemonSpecies, PokemonType};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt;
 
 // Include the compiled species data
@@ -69,6 +71,12 @@ pub struct PokemonInst {
     pub stats: CurrentStats,
     pub moves: [Option<MoveInstance>; 4], // Up to 4 moves
     pub status: Option<StatusCondition>,  // Status condition with optional parameter
+    #[serde(default)]
+    pub held_item: Option<Item>, // Consumed when it triggers in battle
+    #[serde(default)]
+    pub times_participated: u32, // Lifetime count of battles this Pokemon was sent out in
+    #[serde(default)]
+    pub times_fainted: u32, // Lifetime count of faints across all battles
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Hash, Eq)]
@@ -219,6 +227,9 @@ impl PokemonInst {
             stats, // Assign the new `CurrentStats` struct here
             moves: move_array,
             status: None,
+            held_item: None,
+            times_participated: 0,
+            times_fainted: 0,
         };
 
         // Set HP using the validated setter. If no HP is provided, default to max HP.
@@ -251,6 +262,9 @@ impl PokemonInst {
             stats: curr_stats.into(), // <-- Convert the array into our struct
             moves,
             status,
+            held_item: None,
+            times_participated: 0,
+            times_fainted: 0,
         };
 
         // Set HP using the validated setter.
@@ -288,35 +302,42 @@ impl PokemonInst {
         }
     }
 
+    /// Recompute stats from `base` using this Pokemon's level, IVs, and EVs.
+    /// This is the one place level-up, evolution, and EV gains refresh stats, so
+    /// they all share the same Gen 1 formula. With `preserve_hp_ratio`, current HP
+    /// keeps the same fraction of max HP; otherwise it rises by however much max HP grew.
+    /// Fainted Pokemon stay at 0 HP either way.
+    ///
+    /// Transform deliberately doesn't use this. Gen 1 copies the target's actual stats,
+    /// which already reflect its own level, IVs and EVs, instead of recomputing its base
+    /// stats at the user's level. The `Transformed` condition holds that copy and the
+    /// battle stat lookups read from it, so there is nothing to recompute.
+    pub fn recompute_stats(&mut self, base: &BaseStats, preserve_hp_ratio: bool) {
+        let old_max_hp = self.stats.hp;
+        self.stats = Self::calculate_stats(base, self.level, &self.ivs, &self.evs);
+        let new_max_hp = self.stats.hp;
+
+        if self.is_fainted() {
+            return;
+        }
+
+        let new_curr_hp = if preserve_hp_ratio && old_max_hp > 0 {
+            let scaled = (self.curr_hp as u32 * new_max_hp as u32) / old_max_hp as u32;
+            (scaled as u16).max(1)
+        } else {
+            self.curr_hp
+                .saturating_add(new_max_hp.saturating_sub(old_max_hp))
+        };
+        self.curr_hp = new_curr_hp.min(new_max_hp);
+    }
+
     fn recalculate_stats(&mut self) {
         // Fetch the species data which contains the necessary base stats.
         // If data is unavailable, we cannot proceed, so we simply return.
+        // Failing silently is acceptable as it's a data integrity issue.
         if let Ok(species_data) = get_species_data(self.species) {
-            // Store the old max HP to calculate the difference after the update.
-            let old_max_hp = self.stats.hp;
-
-            // Call the existing pure calculation function with the instance's own data.
-            // This reuses the tested logic without duplicating the formula.
-            let new_stats =
-                Self::calculate_stats(&species_data.base_stats, self.level, &self.ivs, &self.evs);
-
-            // Atomically update the stats field with the new values.
-            self.stats = new_stats;
-
-            // Adjust current HP based on the change in max HP.
-            // This ensures that a Pokémon's health scales correctly when its max HP increases.
-            let new_max_hp = self.stats.hp;
-            let hp_increase = new_max_hp.saturating_sub(old_max_hp);
-
-            // Only add the HP increase if the Pokémon is not fainted.
-            // Fainted Pokémon do not gain HP from stat recalculations.
-            if !self.is_fainted() {
-                // Add the difference to the current HP, ensuring it doesn't exceed the new max.
-                let new_curr_hp = self.curr_hp.saturating_add(hp_increase);
-                self.curr_hp = new_curr_hp.min(new_max_hp);
-            }
+            self.recompute_stats(&species_data.base_stats, false);
         }
-        // Failing silently is acceptable as it's a data integrity issue.
     }
 
     // The `LevelUpPokemon` command handler will call this.
@@ -434,10 +455,52 @@ impl PokemonInst {
         get_species_data(self.species)
     }
 
+    /// For each defending type, the best multiplier any of this Pokemon's damaging moves
+    /// achieves against it. With `include_stab`, moves matching the species' types count 1.5x.
+    /// A Pokemon with no damaging moves covers every type at 0.0.
+    pub fn offensive_coverage(&self, include_stab: bool) -> HashMap<PokemonType, f64> {
+        let own_types = self
+            .get_species_data()
+            .map(|data| data.types.clone())
+            .unwrap_or_default();
+
+        let move_types: Vec<PokemonType> = self
+            .moves
+            .iter()
+            .flatten()
+            .filter_map(|move_instance| get_move_data(move_instance.move_).ok())
+            .filter(|move_data| {
+                matches!(
+                    move_data.category,
+                    MoveCategory::Physical | MoveCategory::Special
+                )
+            })
+            .map(|move_data| move_data.move_type)
+            .collect();
+
+        PokemonType::ALL
+            .iter()
+            .map(|&defending_type| {
+                let best = move_types
+                    .iter()
+                    .map(|&move_type| {
+                        let stab = if include_stab && own_types.contains(&move_type) {
+                            1.5
+                        } else {
+                            1.0
+                        };
+                        get_type_effectiveness(move_type, &[defending_type]) * stab
+                    })
+                    .fold(0.0, f64::max);
+                (defending_type, best)
+            })
+            .collect()
+    }
+
     /// Get the current types, accounting for Transform and Conversion conditions.
-    pub fn get_current_types(&self, player: &crate::player::BattlePlayer) -> Vec<PokemonType> {
-        if let Some(p_cond) = player
-            .active_pokemon_conditions
+    pub fn get_current_types(&self, battler: crate::player::Battler) -> Vec<PokemonType> {
+        if let Some(p_cond) = battler
+            .conditions()
             .values()
             .find(|c| matches!(c, PokemonCondition::Converted { .. }))
         {
@@ -446,12 +509,12 @@ impl PokemonInst {
             }
         }
 
-        if let Some(p_cond) = player
-            .active_pokemon_conditions
+        if let Some(p_cond) = battler
+            .conditions()
             .values()
             .find(|c| matches!(c, PokemonCondition::Transformed { .. }))
         {
-            if let PokemonCondition::Transformed { target } = p_cond {
+            if let PokemonCondition::Transformed { target, .. } = p_cond {
                 if let Ok(target_species_data) = get_species_data(target.species) {
                     return target_species_data.types.clone();
                 }
@@ -528,6 +591,24 @@ impl PokemonInst {
         }
     }
 
+    /// Set current HP directly, clamped to `[0, max_hp]`. Dropping to 0 faints the
+    /// Pokemon; raising a fainted Pokemon above 0 clears the faint status.
+    pub fn set_current_hp(&mut self, value: u16) {
+        self.curr_hp = value.min(self.max_hp());
+        if self.curr_hp == 0 {
+            self.status = Some(StatusCondition::Faint);
+        } else if matches!(self.status, Some(StatusCondition::Faint)) {
+            self.status = None;
+        }
+    }
+
+    /// Set current HP to a percentage (0.0-100.0) of max HP, rounded to the nearest point.
+    pub fn set_hp_percent(&mut self, percent: f64) {
+        let fraction = percent.clamp(0.0, 100.0) / 100.0;
+        let hp = (self.max_hp() as f64 * fraction).round() as u16;
+        self.set_current_hp(hp);
+    }
+
     /// Update status condition counters without dealing damage.
     /// Should be called at the start of turn when Pokemon tries to act.
     /// Returns (should_cure, status_changed).
@@ -622,3 +703,49 @@ impl fmt::Display for PokemonInst {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pikachu() -> PokemonInst {
+        let species_data = get_species_data(Species::Pikachu).unwrap();
+        PokemonInst::new(Species::Pikachu, &species_data, 25, None, None)
+    }
+
+    #[test]
+    fn test_set_current_hp_clamps_to_max() {
+        let mut pokemon = pikachu();
+        pokemon.set_current_hp(u16::MAX);
+        assert_eq!(pokemon.current_hp(), pokemon.max_hp());
+        assert!(!pokemon.is_fainted());
+    }
+
+    #[test]
+    fn test_set_current_hp_to_zero_faints_and_back_revives() {
+        let mut pokemon = pikachu();
+        pokemon.set_current_hp(0);
+        assert!(pokemon.is_fainted());
+        assert_eq!(pokemon.status, Some(StatusCondition::Faint));
+
+        pokemon.set_current_hp(5);
+        assert_eq!(pokemon.current_hp(), 5);
+        assert!(!pokemon.is_fainted());
+        assert_eq!(pokemon.status, None);
+    }
+
+    #[test]
+    fn test_set_hp_percent() {
+        let mut pokemon = pikachu();
+        let max_hp = pokemon.max_hp();
+
+        pokemon.set_hp_percent(50.0);
+        assert_eq!(pokemon.current_hp(), (max_hp as f64 * 0.5).round() as u16);
+
+        pokemon.set_hp_percent(250.0);
+        assert_eq!(pokemon.current_hp(), max_hp);
+
+        pokemon.set_hp_percent(-10.0);
+        assert!(pokemon.is_fainted());
+    }
+}
@@ -49,4 +49,48 @@ mod tests {
             Err(_) => {} // Also OK if species data not available in tests
         }
     }
+
+    #[test]
+    fn test_level_up_and_evolution_match_fresh_stats() {
+        let ivs = Some([10, 12, 8, 15, 9, 11]);
+        let charmander_data = crate::get_species_data(Species::Charmander).unwrap();
+        let charmeleon_data = crate::get_species_data(Species::Charmeleon).unwrap();
+
+        // Charmander levels up to 16 and evolves into Charmeleon.
+        let mut evolved = PokemonInst::new(Species::Charmander, charmander_data, 15, ivs, None);
+        evolved.apply_level_up();
+        evolved.evolve(Species::Charmeleon);
+
+        // A Charmeleon built directly at level 16 must end up with the same stats.
+        let fresh = PokemonInst::new(Species::Charmeleon, charmeleon_data, 16, ivs, None);
+        assert_eq!(evolved.level, 16);
+        assert_eq!(evolved.stats, fresh.stats);
+    }
+
+    #[test]
+    fn test_recompute_stats_hp_handling() {
+        let charmander_data = crate::get_species_data(Species::Charmander).unwrap();
+        let charmeleon_data = crate::get_species_data(Species::Charmeleon).unwrap();
+
+        // Preserving the ratio keeps a half-health Pokemon at half health.
+        let mut pokemon = PokemonInst::new(Species::Charmander, charmander_data, 50, None, None);
+        let old_max_hp = pokemon.max_hp();
+        pokemon.set_hp(old_max_hp / 2);
+        pokemon.recompute_stats(&charmeleon_data.base_stats, true);
+        let new_max_hp = pokemon.max_hp();
+        assert!(new_max_hp > old_max_hp);
+        assert_eq!(
+            pokemon.current_hp(),
+            ((old_max_hp / 2) as u32 * new_max_hp as u32 / old_max_hp as u32) as u16
+        );
+
+        // Without it, current HP only rises by the max HP gained.
+        let mut pokemon = PokemonInst::new(Species::Charmander, charmander_data, 50, None, None);
+        pokemon.set_hp(old_max_hp / 2);
+        pokemon.recompute_stats(&charmeleon_data.base_stats, false);
+        assert_eq!(
+            pokemon.current_hp(),
+            old_max_hp / 2 + (new_max_hp - old_max_hp)
+        );
+    }
 }
@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 
 /// Tracks which Pokemon have faced each other during battle
 /// participation[player][my_pokemon][opponent_pokemon] = true if they faced each other
+/// Records are set as soon as a Pokemon becomes active and are never cleared, so a
+/// participant that faints still counts toward the experience split.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BattleParticipationTracker {
     participation: [[[bool; 6]; 6]; 2],
@@ -39,6 +41,15 @@ impl BattleParticipationTracker {
             })
             .collect()
     }
+
+    /// Whether the specified Pokemon was on the field against anyone this battle
+    pub fn has_participated(&self, player: usize, pokemon_index: usize) -> bool {
+        player < 2
+            && pokemon_index < 6
+            && self.participation[player][pokemon_index]
+                .iter()
+                .any(|&faced| faced)
+    }
 }
 
 #[cfg(test)]
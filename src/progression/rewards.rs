@@ -1,4 +1,5 @@
 use crate::errors::SpeciesDataResult;
+use crate::pokemon::PokemonInst;
 use crate::species::Species;
 use schema::BaseStats;
 
@@ -8,6 +9,9 @@ const EVOLUTION_PENALTY: f32 = -0.1;
 const HIGH_STAT_BONUS: f32 = 0.02;
 const HIGH_STAT_THRESHOLD: u8 = 100;
 
+// Prize money paid per level of the defeated trainer's highest-level Pokemon
+const PRIZE_MONEY_PER_LEVEL: u32 = 20;
+
 // BST thresholds for EV yield
 const BST_LOW_THRESHOLD: u16 = 300;
 const BST_HIGH_THRESHOLD: u16 = 500;
@@ -90,6 +94,78 @@ impl RewardCalculator {
         Ok(self.distribute_evs(total_evs, &highest_stats))
     }
 
+    /// Split the experience from a defeated Pokemon among a party.
+    /// `participants` faced the defeated Pokemon; `eligible` are the party members
+    /// that can still gain experience (not fainted, below level 100).
+    /// Without Exp. Share, only eligible participants gain experience.
+    /// With it, half goes to the participants and the other half is split among
+    /// every eligible party member. Returns (party index, experience) pairs.
+    pub fn distribute_experience(
+        &self,
+        base_exp: u32,
+        participants: &[usize],
+        eligible: &[usize],
+        exp_share: bool,
+    ) -> Vec<(usize, u32)> {
+        let (participant_pool, party_pool) = if exp_share {
+            (base_exp / 2, base_exp - base_exp / 2)
+        } else {
+            (base_exp, 0)
+        };
+
+        let participant_share = if participants.is_empty() {
+            0
+        } else {
+            participant_pool / participants.len() as u32
+        };
+        let party_share = if eligible.is_empty() {
+            0
+        } else {
+            party_pool / eligible.len() as u32
+        };
+
+        eligible
+            .iter()
+            .filter_map(|&index| {
+                let mut exp = party_share;
+                if participants.contains(&index) {
+                    exp += participant_share;
+                }
+                let gains_exp = participants.contains(&index) || exp_share;
+                gains_exp.then_some((index, exp))
+            })
+            .collect()
+    }
+
+    /// Prize money for beating a trainer with this team, as the battle will award it.
+    /// Formula: 20 × the level of the team's highest-level Pokemon.
+    pub fn preview_prize<'a>(
+        &self,
+        defeated_team: impl IntoIterator<Item = &'a PokemonInst>,
+    ) -> u32 {
+        defeated_team
+            .into_iter()
+            .map(|pokemon| pokemon.level as u32)
+            .max()
+            .map_or(0, |level| level * PRIZE_MONEY_PER_LEVEL)
+    }
+
+    /// Experience each of `participants` party members would gain for defeating
+    /// `defeated`, without Exp. Share. The defeated Pokemon's level does not
+    /// factor into the formula, so it isn't taken.
+    pub fn preview_experience(
+        &self,
+        defeated: Species,
+        participants: usize,
+    ) -> SpeciesDataResult<u32> {
+        let base_exp = self.calculate_base_exp(defeated)?;
+        let party: Vec<usize> = (0..participants).collect();
+        Ok(self
+            .distribute_experience(base_exp, &party, &party, false)
+            .first()
+            .map_or(0, |&(_, exp)| exp))
+    }
+
     /// Calculate stat modifier: +0.02 for each base stat >= 100
     fn calculate_stat_modifier(&self, base_stats: &BaseStats) -> f32 {
         let high_stats = [
@@ -226,4 +302,46 @@ mod tests {
         let stat_modifier = calculator.calculate_stat_modifier(&base_stats);
         assert_eq!(stat_modifier, 0.12); // 6 stats >= 100, so 6 * 0.02 = 0.12
     }
+
+    #[test]
+    fn test_exp_share_distribution() {
+        let calculator = RewardCalculator;
+        // Slot 0 fought, slot 1 sat on the bench, slot 2 fought but is not eligible.
+        let participants = [0, 2];
+        let eligible = [0, 1];
+
+        // Off: only the eligible participant gains, and the split counts every participant.
+        let without_share = calculator.distribute_experience(100, &participants, &eligible, false);
+        assert_eq!(without_share, vec![(0, 50)]);
+
+        // On: everyone eligible gains a share of the other half.
+        let with_share = calculator.distribute_experience(100, &participants, &eligible, true);
+        assert_eq!(with_share, vec![(0, 25 + 25), (1, 25)]);
+    }
+
+    #[test]
+    fn test_reward_previews() {
+        let calculator = RewardCalculator;
+
+        // The prize follows the defeated team's highest level.
+        let rattata_data = crate::get_species_data(Species::Rattata).unwrap();
+        let pidgey_data = crate::get_species_data(Species::Pidgey).unwrap();
+        let team = [
+            PokemonInst::new(Species::Rattata, rattata_data, 12, None, None),
+            PokemonInst::new(Species::Pidgey, pidgey_data, 9, None, None),
+        ];
+        assert_eq!(calculator.preview_prize(&team), 240);
+        assert_eq!(calculator.preview_prize(std::iter::empty()), 0);
+
+        // Experience is split evenly among participants.
+        let base_exp = calculator.calculate_base_exp(Species::Rattata).unwrap();
+        assert_eq!(
+            calculator.preview_experience(Species::Rattata, 1).unwrap(),
+            base_exp
+        );
+        assert_eq!(
+            calculator.preview_experience(Species::Rattata, 2).unwrap(),
+            base_exp / 2
+        );
+    }
 }
@@ -4,6 +4,17 @@ use std::fmt;
 pub use schema::Species;
 
 use crate::get_species_data;
+use schema::Move;
+
+/// The moves `species` learns on reaching exactly `level`, read from its compiled learnset.
+/// Returns an empty list if it learns nothing at that level or its data is unavailable.
+pub fn moves_learned_at(species: Species, level: u8) -> Vec<Move> {
+    get_species_data(species)
+        .ok()
+        .and_then(|data| data.learnset.learns_at_level(level))
+        .cloned()
+        .unwrap_or_default()
+}
 
 /// Display detailed information about a species including stats and description
 pub fn display_species_detailed(species: Species, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -1,8 +1,11 @@
+use crate::battle::stats::get_type_effectiveness;
+use crate::move_data::get_move_data;
 use crate::player::BattlePlayer;
 use crate::pokemon::PokemonInst;
 use crate::species::Species;
-use schema::Move;
-use std::collections::HashMap;
+use schema::{Move, MoveCategory, PokemonType};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
 use std::sync::LazyLock;
 
 // Include the generated team data
@@ -112,9 +115,124 @@ pub fn create_battle_player_from_team(
     let team_pokemon = create_team_from_template(team_id)
         .ok_or_else(|| format!("Team '{}' not found", team_id))?;
 
-    if team_pokemon.is_empty() {
-        return Err(format!("Team '{}' has no valid Pokemon", team_id));
+    BattlePlayer::try_new(player_id, player_name, team_pokemon)
+        .map_err(|_| format!("Team '{}' has no valid Pokemon", team_id))
+}
+
+/// Errors raised while loading a user-submitted team.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TeamError {
+    /// The payload could not be deserialized into a team
+    Malformed(String),
+    /// A team must have at least 1 Pokemon and no more than the format allows (up to 6)
+    InvalidTeamSize(usize),
+    /// Levels must be between 1 and 100
+    InvalidLevel { species: Species, level: u8 },
+    /// An explicit move list must contain between 1 and 4 moves
+    InvalidMoveCount { species: Species, count: usize },
+    /// The same move appears more than once in a Pokemon's move list
+    DuplicateMove { species: Species, move_: Move },
+    /// The Pokemon could not be constructed from its template
+    InvalidPokemon(String),
+}
+
+impl fmt::Display for TeamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TeamError::Malformed(details) => write!(f, "Malformed team data: {}", details),
+            TeamError::InvalidTeamSize(size) => {
+                write!(f, "Team size {} is not allowed in this format", size)
+            }
+            TeamError::InvalidLevel { species, level } => {
+                write!(f, "{:?} has an invalid level: {}", species, level)
+            }
+            TeamError::InvalidMoveCount { species, count } => {
+                write!(f, "{:?} must know 1 to 4 moves, found {}", species, count)
+            }
+            TeamError::DuplicateMove { species, move_ } => {
+                write!(f, "{:?} knows {:?} more than once", species, move_)
+            }
+            TeamError::InvalidPokemon(details) => write!(f, "Invalid Pokemon: {}", details),
+        }
     }
+}
+
+impl std::error::Error for TeamError {}
+
+/// Wire formats accepted for user-submitted teams.
+#[derive(Debug, Clone, Copy)]
+pub enum SerializedTeam<'a> {
+    Json(&'a str),
+    Postcard(&'a [u8]),
+}
+
+/// Check that a team template describes a legal team.
+pub fn validate_team(team: &TeamTemplate) -> Result<(), TeamError> {
+    validate_team_for_format(team, 6)
+}
+
+/// Check that a team is legal for a format that allows at most `format_team_size` Pokemon.
+pub fn validate_team_for_format(
+    team: &TeamTemplate,
+    format_team_size: u8,
+) -> Result<(), TeamError> {
+    let max_size = format_team_size.clamp(1, 6) as usize;
+    if team.pokemon.is_empty() || team.pokemon.len() > max_size {
+        return Err(TeamError::InvalidTeamSize(team.pokemon.len()));
+    }
+
+    for template in &team.pokemon {
+        if !(1..=100).contains(&template.level) {
+            return Err(TeamError::InvalidLevel {
+                species: template.species,
+                level: template.level,
+            });
+        }
+
+        if let Some(moves) = &template.moves {
+            if moves.is_empty() || moves.len() > 4 {
+                return Err(TeamError::InvalidMoveCount {
+                    species: template.species,
+                    count: moves.len(),
+                });
+            }
+
+            let mut seen = HashSet::new();
+            if let Some(duplicate) = moves.iter().find(|move_| !seen.insert(**move_)) {
+                return Err(TeamError::DuplicateMove {
+                    species: template.species,
+                    move_: *duplicate,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Build a BattlePlayer from a serialized team, e.g. one submitted to the server.
+/// The team is validated before any Pokemon are created.
+pub fn battle_player_from_serialized(
+    data: SerializedTeam,
+    player_id: String,
+    player_name: String,
+) -> Result<BattlePlayer, TeamError> {
+    let team: TeamTemplate = match data {
+        SerializedTeam::Json(json) => {
+            serde_json::from_str(json).map_err(|e| TeamError::Malformed(e.to_string()))?
+        }
+        SerializedTeam::Postcard(bytes) => {
+            postcard::from_bytes(bytes).map_err(|e| TeamError::Malformed(e.to_string()))?
+        }
+    };
+
+    validate_team(&team)?;
+
+    let team_pokemon = team
+        .pokemon
+        .iter()
+        .map(|template| create_pokemon_from_template(template).map_err(TeamError::InvalidPokemon))
+        .collect::<Result<Vec<_>, _>>()?;
 
     Ok(BattlePlayer::new(player_id, player_name, team_pokemon))
 }
@@ -161,6 +279,74 @@ pub fn create_random_npc_team(selection: &str) -> Result<BattlePlayer, String> {
     )
 }
 
+/// Build a 6x6 offensive matchup grid for team preview.
+/// Cell `[a][b]` is the best type multiplier any damaging move of `team_a[a]` has against
+/// `team_b[b]`. Empty slots, and Pokemon with no damaging moves, are left at 0.0.
+pub fn matchup_matrix(team_a: &[PokemonInst], team_b: &[PokemonInst]) -> [[f64; 6]; 6] {
+    let mut matrix = [[0.0; 6]; 6];
+
+    for (a, attacker) in team_a.iter().take(6).enumerate() {
+        // Only moves that deal damage contribute to offensive coverage.
+        let move_types: Vec<PokemonType> = attacker
+            .moves
+            .iter()
+            .flatten()
+            .filter_map(|move_instance| get_move_data(move_instance.move_).ok())
+            .filter(|move_data| {
+                matches!(
+                    move_data.category,
+                    MoveCategory::Physical | MoveCategory::Special
+                )
+            })
+            .map(|move_data| move_data.move_type)
+            .collect();
+
+        for (b, defender) in team_b.iter().take(6).enumerate() {
+            let defender_types = defender
+                .get_species_data()
+                .map(|data| data.types.clone())
+                .unwrap_or_default();
+
+            matrix[a][b] = move_types
+                .iter()
+                .map(|&move_type| get_type_effectiveness(move_type, &defender_types))
+                .fold(0.0, f64::max);
+        }
+    }
+
+    matrix
+}
+
+/// Heuristic strength rating used to pair similarly-powered teams.
+/// Summed base stat totals are scaled by the team's average level, and each defending
+/// type the team can hit super-effectively adds a flat bonus. An empty team scores 0.
+pub fn team_power(team: &[PokemonInst]) -> u32 {
+    const COVERAGE_BONUS_PER_TYPE: u32 = 25;
+
+    let members: Vec<&PokemonInst> = team.iter().take(6).collect();
+    if members.is_empty() {
+        return 0;
+    }
+
+    let base_stat_sum: u32 = members
+        .iter()
+        .filter_map(|pokemon| pokemon.get_species_data().ok())
+        .map(|data| u32::from(data.base_stats.total()))
+        .sum();
+    let level_sum: u32 = members.iter().map(|pokemon| u32::from(pokemon.level)).sum();
+    let average_level = level_sum / members.len() as u32;
+
+    let super_effective_types: HashSet<PokemonType> = members
+        .iter()
+        .flat_map(|pokemon| pokemon.offensive_coverage(false))
+        .filter(|&(_, multiplier)| multiplier > 1.0)
+        .map(|(defending_type, _)| defending_type)
+        .collect();
+
+    base_stat_sum * average_level / 100
+        + super_effective_types.len() as u32 * COVERAGE_BONUS_PER_TYPE
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -247,4 +433,133 @@ mod tests {
         assert_eq!(charizard_team.len(), 6);
         assert_eq!(charizard_team[0].species, Species::Charizard);
     }
+
+    #[test]
+    fn test_matchup_matrix() {
+        let make = |species: Species, moves: Vec<Move>| {
+            let species_data = crate::get_species_data(species).expect("Species should exist");
+            PokemonInst::new(species, species_data, 30, None, Some(moves))
+        };
+
+        let team_a = vec![make(Species::Pikachu, vec![Move::ThunderPunch])];
+        let team_b = vec![
+            make(Species::Squirtle, vec![Move::Tackle]),
+            make(Species::Diglett, vec![Move::Scratch]),
+        ];
+
+        let matrix = matchup_matrix(&team_a, &team_b);
+
+        assert_eq!(
+            matrix[0][0], 2.0,
+            "Electric should be super effective on Water"
+        );
+        assert_eq!(matrix[0][1], 0.0, "Ground should be immune to Electric");
+        assert_eq!(matrix[1][0], 0.0, "Empty slots should stay at 0.0");
+    }
+
+    #[test]
+    fn test_team_power_ranks_full_team_above_single_low_level_pokemon() {
+        let full_team =
+            create_team_from_template("demo_charizard").expect("Demo team should exist");
+
+        let rattata_data = crate::get_species_data(Species::Rattata).expect("Species should exist");
+        let weak_team = vec![PokemonInst::new(
+            Species::Rattata,
+            rattata_data,
+            5,
+            None,
+            Some(vec![Move::Tackle]),
+        )];
+
+        let strong = team_power(&full_team);
+        let weak = team_power(&weak_team);
+        assert!(
+            strong > weak,
+            "Full level 60 team ({}) should outscore a level 5 Rattata ({})",
+            strong,
+            weak
+        );
+        assert_eq!(team_power(&[]), 0);
+
+        // The rating is a pure function of the team.
+        assert_eq!(team_power(&full_team), strong);
+        assert_eq!(team_power(&weak_team), weak);
+    }
+
+    #[test]
+    fn test_battle_player_from_serialized_round_trip() {
+        let team = get_team_info("demo_venusaur").expect("Demo team should exist");
+
+        let json = serde_json::to_string(team).expect("Team should serialize to JSON");
+        let from_json = battle_player_from_serialized(
+            SerializedTeam::Json(&json),
+            "p1".to_string(),
+            "Player 1".to_string(),
+        )
+        .expect("JSON team should be accepted");
+
+        let bytes = postcard::to_allocvec(team).expect("Team should serialize to postcard");
+        let from_postcard = battle_player_from_serialized(
+            SerializedTeam::Postcard(&bytes),
+            "p1".to_string(),
+            "Player 1".to_string(),
+        )
+        .expect("Postcard team should be accepted");
+
+        for player in [&from_json, &from_postcard] {
+            assert_eq!(player.player_id, "p1");
+            assert_eq!(player.team.iter().flatten().count(), 6);
+            let lead = player.team[0].as_ref().unwrap();
+            assert_eq!(lead.species, Species::Venusaur);
+            assert_eq!(lead.level, 60);
+        }
+    }
+
+    #[test]
+    fn test_battle_player_from_serialized_rejects_illegal_team() {
+        let mut team = get_team_info("demo_venusaur").unwrap().clone();
+        team.pokemon[0].moves = Some(vec![
+            Move::Tackle,
+            Move::Growl,
+            Move::VineWhip,
+            Move::RazorLeaf,
+            Move::SleepPowder,
+        ]);
+        let json = serde_json::to_string(&team).unwrap();
+
+        let result =
+            battle_player_from_serialized(SerializedTeam::Json(&json), "p1".into(), "P1".into());
+        assert_eq!(
+            result.err(),
+            Some(TeamError::InvalidMoveCount {
+                species: Species::Venusaur,
+                count: 5
+            })
+        );
+
+        let result = battle_player_from_serialized(
+            SerializedTeam::Json("{ not a team }"),
+            "p1".into(),
+            "P1".into(),
+        );
+        assert!(matches!(result, Err(TeamError::Malformed(_))));
+    }
+
+    #[test]
+    fn test_validate_team_for_format_limits_size() {
+        let team = get_team_info("demo_venusaur").unwrap();
+        assert_eq!(validate_team_for_format(team, 6), Ok(()));
+        assert_eq!(
+            validate_team_for_format(team, 3),
+            Err(TeamError::InvalidTeamSize(6))
+        );
+    }
+
+    #[test]
+    fn test_demo_teams_pass_validation() {
+        for team_id in get_demo_team_ids() {
+            let team = get_team_info(&team_id).unwrap();
+            assert_eq!(validate_team(team), Ok(()), "{} should be legal", team_id);
+        }
+    }
 }